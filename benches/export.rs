@@ -0,0 +1,63 @@
+// Export path benchmarks: `vk_to_snarkjs` over growing IC sizes and `f_to_dec`
+
+use ark_ec::{AffineRepr, CurveGroup, pairing::Pairing};
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::VerifyingKey;
+use ark_snarkjs::snarkjs_common::{AsFp2, CurveTag, f_to_dec};
+use ark_snarkjs::vk_to_snarkjs;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+
+const IC_SIZES: [usize; 3] = [10, 1_000, 10_000];
+
+/// Random verifying key with `n_ic` IC points (content is irrelevant for export).
+fn random_vk<E: Pairing>(n_ic: usize, rng: &mut StdRng) -> VerifyingKey<E> {
+    let ic: Vec<E::G1> = (0..n_ic).map(|_| E::G1::rand(rng)).collect();
+    VerifyingKey {
+        alpha_g1: E::G1Affine::rand(rng),
+        beta_g2: E::G2Affine::rand(rng),
+        gamma_g2: E::G2Affine::rand(rng),
+        delta_g2: E::G2Affine::rand(rng),
+        gamma_abc_g1: E::G1::normalize_batch(&ic),
+    }
+}
+
+fn bench_vk_for_curve<E>(c: &mut Criterion, label: &str)
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut group = c.benchmark_group(format!("vk_to_snarkjs/{label}"));
+    for n_ic in IC_SIZES {
+        let vk = random_vk::<E>(n_ic, &mut rng);
+        group.bench_with_input(BenchmarkId::from_parameter(n_ic), &vk, |b, vk| {
+            b.iter(|| vk_to_snarkjs::<E>(black_box(vk), n_ic - 1).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_vk_to_snarkjs(c: &mut Criterion) {
+    bench_vk_for_curve::<Bn254>(c, "Bn254");
+    bench_vk_for_curve::<Bls12_381>(c, "Bls12-381");
+}
+
+fn bench_f_to_dec(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0);
+    let scalars: Vec<ark_bn254::Fr> = (0..1_000).map(|_| ark_bn254::Fr::rand(&mut rng)).collect();
+    c.bench_function("f_to_dec/Bn254/1000", |b| {
+        b.iter(|| {
+            for s in &scalars {
+                black_box(f_to_dec(black_box(s)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_vk_to_snarkjs, bench_f_to_dec);
+criterion_main!(benches);
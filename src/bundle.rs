@@ -0,0 +1,290 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, VerifyingKey};
+use serde::{Deserialize, Serialize, Serializer, ser::SerializeStruct};
+use std::{fs, path::Path};
+
+use crate::error::SnarkjsError;
+use crate::export_proof::{ProofJson, proof_to_snarkjs};
+use crate::export_vk::{VkJson, vk_to_snarkjs};
+use crate::import_proof::{ProofFile, parse_public_signals, proof_from_file};
+use crate::import_vk::{VkFile, vk_from_file};
+use crate::public_signals::check_public_len;
+use crate::snarkjs_common::{AsFp2, CurveId, CurveTag, FromXy, write_json_pretty};
+#[cfg(feature = "flate2")]
+use crate::snarkjs_common::{create_parent_dir, with_path};
+
+/// Single-file transport format holding a proof, its public signals and the
+/// verifying key: `{ "proof": {...}, "publicSignals": [...], "vk": {...} }`.
+///
+/// `proof` and `vk` have the same layout as `proof.json` and
+/// `verification_key.json`; the public signals live only at the top level.
+pub struct BundleJson {
+    pub proof: ProofJson,
+    pub vk: VkJson,
+}
+
+/// snarkjs `proof.json` layout, without the public signals.
+#[derive(Serialize)]
+pub(crate) struct ProofPoints<'a> {
+    pi_a: &'a [String; 3],
+    pi_b: &'a [[String; 2]; 3],
+    pi_c: &'a [String; 3],
+    protocol: &'a str,
+    curve: CurveId,
+}
+
+impl<'a> From<&'a ProofJson> for ProofPoints<'a> {
+    fn from(p: &'a ProofJson) -> Self {
+        ProofPoints {
+            pi_a: &p.pi_a,
+            pi_b: &p.pi_b,
+            pi_c: &p.pi_c,
+            protocol: p.protocol,
+            curve: p.curve,
+        }
+    }
+}
+
+impl Serialize for BundleJson {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("BundleJson", 3)?;
+        s.serialize_field("proof", &ProofPoints::from(&self.proof))?;
+        s.serialize_field("publicSignals", &self.proof.publicSignals)?;
+        s.serialize_field("vk", &self.vk)?;
+        s.end()
+    }
+}
+
+/// JSON structure of a bundle as read from disk.
+#[derive(Deserialize)]
+struct BundleFile {
+    proof: ProofFile,
+    #[serde(rename = "publicSignals")]
+    public_signals: Vec<String>,
+    vk: VkFile,
+}
+
+/// Export a proof, its public signals and the verifying key into a single
+/// bundle file (see `BundleJson`). `n_public` is taken from `public.len()`,
+/// which must match `vk` or this fails with
+/// `SnarkjsError::PublicCountMismatch` before anything is written.
+/// Writes the file to `out_path` and returns the in-memory `BundleJson`.
+pub fn export_bundle<E, P>(
+    proof: &Proof<E>,          // Groth16 proof from arkworks
+    public: &[E::ScalarField], // list of public inputs
+    vk: &VerifyingKey<E>,      // Groth16 verifying key from arkworks
+    out_path: P,               // output path for JSON file
+) -> Result<BundleJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    check_public_len(vk, public.len())?;
+    let json = BundleJson {
+        proof: proof_to_snarkjs::<E>(proof, public)?,
+        vk: vk_to_snarkjs::<E>(vk, public.len())?,
+    };
+
+    write_json_pretty(out_path, &json)?;
+
+    Ok(json)
+}
+
+/// Import a bundle written by `export_bundle`.
+/// Returns the proof, the public signals and the verifying key. Fails with
+/// `SnarkjsError::PublicCountMismatch` if the number of public signals does
+/// not match the key's `nPublic`.
+#[allow(clippy::type_complexity)]
+pub fn import_bundle<E, P>(
+    path: P,
+) -> Result<(Proof<E>, Vec<E::ScalarField>, VerifyingKey<E>), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let file: BundleFile = serde_json::from_slice(&fs::read(path)?)?;
+    let (proof, _) = proof_from_file::<E>(&file.proof)?;
+    let public = parse_public_signals(&file.public_signals)?;
+    let vk = vk_from_file::<E>(&file.vk)?;
+    check_public_len(&vk, public.len())?;
+    Ok((proof, public, vk))
+}
+
+/// Upper bound on the decompressed size of a bundle read by
+/// `import_bundle_tar_gz`, so a small gzip stream cannot expand without
+/// limit (a verifying key with `MAX_N_PUBLIC` inputs is well below it for
+/// the circuits bundles are meant for).
+#[cfg(feature = "flate2")]
+pub const MAX_BUNDLE_SIZE: u64 = 256 << 20;
+
+/// Export the three canonical snarkjs files (`proof.json`, `public.json`,
+/// `verification_key.json`) as a gzip-compressed tarball at `out_path`.
+/// `public` is checked against `vk` as in `export_bundle`.
+#[cfg(feature = "flate2")]
+pub fn export_bundle_tar_gz<E, P>(
+    proof: &Proof<E>,          // Groth16 proof from arkworks
+    public: &[E::ScalarField], // list of public inputs
+    vk: &VerifyingKey<E>,      // Groth16 verifying key from arkworks
+    out_path: P,               // output path for .tar.gz file
+) -> Result<BundleJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    use flate2::{Compression, write::GzEncoder};
+
+    check_public_len(vk, public.len())?;
+    let json = BundleJson {
+        proof: proof_to_snarkjs::<E>(proof, public)?,
+        vk: vk_to_snarkjs::<E>(vk, public.len())?,
+    };
+
+    let out_path = out_path.as_ref();
+    create_parent_dir(out_path)?;
+    let write = || -> Result<(), SnarkjsError> {
+        let gz = GzEncoder::new(fs::File::create(out_path)?, Compression::default());
+        let mut archive = tar::Builder::new(gz);
+        for (name, bytes) in [
+            (
+                "proof.json",
+                serde_json::to_vec_pretty(&ProofPoints::from(&json.proof))?,
+            ),
+            (
+                "public.json",
+                serde_json::to_vec_pretty(&json.proof.publicSignals)?,
+            ),
+            (
+                "verification_key.json",
+                serde_json::to_vec_pretty(&json.vk)?,
+            ),
+        ] {
+            let mut header = tar::Header::new_ustar();
+            header.set_path(name)?;
+            header.set_size(bytes.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(0);
+            header.set_cksum();
+            archive.append(&header, bytes.as_slice())?;
+        }
+        archive.into_inner()?.finish()?;
+        Ok(())
+    };
+    write().map_err(|e| with_path(e, out_path))?;
+
+    Ok(json)
+}
+
+/// Import a tarball written by `export_bundle_tar_gz`.
+/// Returns the proof, the public signals and the verifying key, checked
+/// against each other as in `import_bundle`.
+/// Fails with an `InvalidData` I/O error if the archive decompresses to more
+/// than `MAX_BUNDLE_SIZE` bytes.
+#[cfg(feature = "flate2")]
+#[allow(clippy::type_complexity)]
+pub fn import_bundle_tar_gz<E, P>(
+    path: P,
+) -> Result<(Proof<E>, Vec<E::ScalarField>, VerifyingKey<E>), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_bundle_tar_gz_with_limit::<E, _>(path, MAX_BUNDLE_SIZE)
+}
+
+/// Same as `import_bundle_tar_gz`, but allows archives that decompress to at
+/// most `max_size` bytes instead of `MAX_BUNDLE_SIZE`.
+#[cfg(feature = "flate2")]
+#[allow(clippy::type_complexity)]
+pub fn import_bundle_tar_gz_with_limit<E, P>(
+    path: P,
+    max_size: u64,
+) -> Result<(Proof<E>, Vec<E::ScalarField>, VerifyingKey<E>), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let path = path.as_ref();
+    let files = read_tar_gz(path, max_size).map_err(|e| with_path(e, path))?;
+    let entry = |name: &str| {
+        files
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, data)| data.as_slice())
+            .ok_or_else(|| {
+                with_path(
+                    std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        format!("{name} missing from bundle"),
+                    )
+                    .into(),
+                    path,
+                )
+            })
+    };
+
+    let proof_file: ProofFile = serde_json::from_slice(entry("proof.json")?)?;
+    let public: Vec<String> = serde_json::from_slice(entry("public.json")?)?;
+    let vk_file: VkFile = serde_json::from_slice(entry("verification_key.json")?)?;
+
+    let (proof, _) = proof_from_file::<E>(&proof_file)?;
+    let public = parse_public_signals(&public)?;
+    let vk = vk_from_file::<E>(&vk_file)?;
+    check_public_len(&vk, public.len())?;
+    Ok((proof, public, vk))
+}
+
+/// Decompress the tarball at `path` (at most `max_size` bytes) and return
+/// its `(name, contents)` entries. Every entry must be a regular file; the
+/// `tar` crate rejects headers with a bad checksum.
+#[cfg(feature = "flate2")]
+fn read_tar_gz(path: &Path, max_size: u64) -> Result<Vec<(String, Vec<u8>)>, SnarkjsError> {
+    use std::io::{Error, ErrorKind, Read};
+
+    let mut bytes = Vec::new();
+    flate2::read::GzDecoder::new(fs::File::open(path)?)
+        .take(max_size.saturating_add(1))
+        .read_to_end(&mut bytes)?;
+    if bytes.len() as u64 > max_size {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("bundle decompresses to more than {max_size} bytes"),
+        )
+        .into());
+    }
+
+    let mut files = Vec::new();
+    for entry in tar::Archive::new(bytes.as_slice()).entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            return Err(
+                Error::new(ErrorKind::InvalidData, "bundle entry is not a regular file").into(),
+            );
+        }
+        let name = String::from_utf8_lossy(&entry.path_bytes()).into_owned();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+        files.push((name, data));
+    }
+    Ok(files)
+}
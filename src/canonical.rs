@@ -0,0 +1,155 @@
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate,
+};
+use std::{fs, path::Path};
+
+use crate::error::SnarkjsError;
+use crate::export_proof::{ProofJson, export_proof};
+use crate::import_proof::import_proof;
+use crate::public_signals::PublicSignals;
+use crate::snarkjs_common::{AsFp2, CurveTag, FromXy, create_parent_dir, with_path};
+
+/// Read an arkworks `CanonicalSerialize` file, accepting compressed or
+/// uncompressed form. Points are validated on deserialization, and the whole
+/// file must be consumed: trailing bytes fail with `SnarkjsError::Canonical`.
+pub fn read_canonical<T, P>(path: P) -> Result<T, SnarkjsError>
+where
+    T: CanonicalDeserialize,
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    let bytes = fs::read(path)?;
+    let parse = |compress| {
+        let mut reader = &bytes[..];
+        let value = T::deserialize_with_mode(&mut reader, compress, Validate::Yes)?;
+        if !reader.is_empty() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(value)
+    };
+    Ok(parse(Compress::Yes).or_else(|_| parse(Compress::No))?)
+}
+
+/// Read a Groth16 verifying key saved with `CanonicalSerialize`, ready for
+/// `export_vk` (its `n_public` is `vk.gamma_abc_g1.len() - 1`).
+pub fn read_vk_canonical<E, P>(path: P) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    E: Pairing,
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    read_canonical(path)
+}
+
+/// Read a Groth16 proof saved with `CanonicalSerialize`, ready for
+/// `export_proof`.
+pub fn read_proof_canonical<E, P>(path: P) -> Result<Proof<E>, SnarkjsError>
+where
+    E: Pairing,
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    read_canonical(path)
+}
+
+/// Read public inputs saved as a `CanonicalSerialize`d `Vec` of scalars.
+pub fn read_public_canonical<E, P>(path: P) -> Result<Vec<E::ScalarField>, SnarkjsError>
+where
+    E: Pairing,
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    read_canonical(path)
+}
+
+/// Decode a Groth16 proof serialized with `CanonicalSerialize`, compressed
+/// or uncompressed (told apart by length, trailing bytes are rejected).
+///
+/// Every point is checked explicitly, so a corrupted blob fails here rather
+/// than producing a `proof.json` that never verifies:
+/// `SnarkjsError::NotOnCurve` for coordinates off the curve,
+/// `SnarkjsError::NotInSubgroup` outside the prime-order subgroup. Other
+/// malformed input (wrong length, a compressed x without a matching y)
+/// fails with `SnarkjsError::Canonical`.
+pub fn proof_from_bytes<E>(bytes: &[u8]) -> Result<Proof<E>, SnarkjsError>
+where
+    E: Pairing,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+{
+    let compress = if bytes.len() == Proof::<E>::default().serialized_size(Compress::Yes) {
+        Compress::Yes
+    } else if bytes.len() == Proof::<E>::default().serialized_size(Compress::No) {
+        Compress::No
+    } else {
+        return Err(SerializationError::InvalidData.into());
+    };
+    // Points are validated below, with this crate's errors
+    let proof = Proof::<E>::deserialize_with_mode(bytes, compress, Validate::No)?;
+    check_point(&proof.a)?;
+    check_point(&proof.b)?;
+    check_point(&proof.c)?;
+    Ok(proof)
+}
+
+/// Decode a binary proof with `proof_from_bytes` and export it like
+/// `export_proof`. Nothing is written if the blob is rejected.
+pub fn export_proof_from_bytes<E, P>(
+    bytes: &[u8],                                     // CanonicalSerialize'd proof
+    public: impl Into<PublicSignals<E::ScalarField>>, // public inputs, in circuit order
+    out_path: P,                                      // output path for JSON file
+) -> Result<ProofJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let proof = proof_from_bytes::<E>(bytes)?;
+    export_proof::<E, _>(&proof, public, out_path)
+}
+
+/// Reject a deserialized point off the curve or outside the prime-order
+/// subgroup (the point at infinity passes both).
+fn check_point<G: FromXy>(p: &G) -> Result<(), SnarkjsError> {
+    if !p.is_on_curve() {
+        return Err(SnarkjsError::NotOnCurve);
+    }
+    if !p.is_in_correct_subgroup() {
+        return Err(SnarkjsError::NotInSubgroup);
+    }
+    Ok(())
+}
+
+/// Import a snarkjs `proof.json` (same checks as `import_proof`) and write
+/// the reconstructed `Proof<E>` to `out_bin_path` with `CanonicalSerialize`,
+/// compressed (`Compress::Yes`) or not (`Compress::No`), for arkworks-native
+/// tooling. The inverse of `read_proof_canonical` followed by `export_proof`.
+///
+/// Only the proof is written; the public signals are returned alongside it.
+pub fn snarkjs_to_canonical<E, P, Q>(
+    proof_json_path: P, // snarkjs proof.json to read
+    out_bin_path: Q,    // output path for the binary proof
+    compress: Compress, // compressed or uncompressed points
+) -> Result<(Proof<E>, Vec<E::ScalarField>), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    Q: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let (proof, public) = import_proof::<E, _>(proof_json_path)?;
+
+    let mut bytes = Vec::with_capacity(proof.serialized_size(compress));
+    proof.serialize_with_mode(&mut bytes, compress)?;
+    let out_bin_path = out_bin_path.as_ref();
+    create_parent_dir(out_bin_path)?;
+    fs::write(out_bin_path, bytes).map_err(|e| with_path(e.into(), out_bin_path))?;
+
+    Ok((proof, public))
+}
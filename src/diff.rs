@@ -0,0 +1,188 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use core::fmt;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::SnarkjsError;
+use crate::export_proof::proof_to_snarkjs;
+use crate::export_vk::vk_to_snarkjs;
+use crate::import_proof::import_proof;
+use crate::import_vk::import_vk;
+use crate::public_signals::PublicSignals;
+use crate::snarkjs_common::{AsFp2, CurveTag, FromXy, f_to_dec};
+
+/// One semantic difference between two export directories, as reported by
+/// `diff_exports`. `file` is `"proof.json"`, `"public.json"` or
+/// `"verification_key.json"`; `path` locates a value inside it, e.g.
+/// `"pi_b[1][0]"`, `"IC[3]"`, or `"[2]"` for a public signal (empty for
+/// the whole `public.json` array).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Difference {
+    /// The file exists in only one of the directories.
+    Missing { file: &'static str, in_a: bool },
+    /// Arrays of different lengths (`IC`, the public signals).
+    Count {
+        file: &'static str,
+        path: String,
+        a: usize,
+        b: usize,
+    },
+    /// The same coordinate or signal holds different values.
+    Value {
+        file: &'static str,
+        path: String,
+        a: String,
+        b: String,
+    },
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difference::Missing { file, in_a } => {
+                let (has, lacks) = if *in_a { ("a", "b") } else { ("b", "a") };
+                write!(f, "{file}: only in {has}, missing in {lacks}")
+            }
+            Difference::Count { file, path, a, b } => {
+                write!(f, "{}: {a} entries != {b} entries", location(file, path))
+            }
+            Difference::Value { file, path, a, b } => {
+                write!(f, "{}: {a} != {b}", location(file, path))
+            }
+        }
+    }
+}
+
+/// `file path`, or just `file` for an empty path.
+fn location(file: &str, path: &str) -> String {
+    if path.is_empty() {
+        file.into()
+    } else {
+        format!("{file} {path}")
+    }
+}
+
+/// Compare the `proof.json`, `public.json` and `verification_key.json` of
+/// two export directories (e.g. written by `export_verify_manifest`) for
+/// regression tests across prover versions.
+///
+/// Each file is imported with the usual checks and converted back to
+/// snarkjs' canonical decimal form, so formatting, key order, affine vs.
+/// projective points and leading zeros play no role: only values that
+/// differ are reported, coordinate by coordinate. A file missing from both
+/// directories is skipped; one that fails to import is an error. An empty
+/// result means the exports agree.
+pub fn diff_exports<E, P, Q>(dir_a: P, dir_b: Q) -> Result<Vec<Difference>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    Q: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let (dir_a, dir_b) = (dir_a.as_ref(), dir_b.as_ref());
+    let mut out = Vec::new();
+
+    let file = "proof.json";
+    if let Some((a, b)) = both(&mut out, file, dir_a, dir_b) {
+        let canonical = |path: &Path| -> Result<Value, SnarkjsError> {
+            let (proof, _) = import_proof::<E, _>(path)?;
+            Ok(serde_json::to_value(proof_to_snarkjs::<E>(&proof, &[])?)?)
+        };
+        let (a, b) = (canonical(&a)?, canonical(&b)?);
+        for key in ["pi_a", "pi_b", "pi_c"] {
+            diff_value(&mut out, file, key.into(), &a[key], &b[key]);
+        }
+    }
+
+    let file = "public.json";
+    if let Some((a, b)) = both(&mut out, file, dir_a, dir_b) {
+        let canonical = |path: &Path| -> Result<Value, SnarkjsError> {
+            let public: PublicSignals<E::ScalarField> =
+                serde_json::from_str(&fs::read_to_string(path)?)?;
+            Ok(public.iter().map(f_to_dec).collect())
+        };
+        diff_value(
+            &mut out,
+            file,
+            String::new(),
+            &canonical(&a)?,
+            &canonical(&b)?,
+        );
+    }
+
+    let file = "verification_key.json";
+    if let Some((a, b)) = both(&mut out, file, dir_a, dir_b) {
+        let canonical = |path: &Path| -> Result<Value, SnarkjsError> {
+            let vk = import_vk::<E, _>(path)?;
+            let n_public = vk.gamma_abc_g1.len().saturating_sub(1);
+            Ok(serde_json::to_value(vk_to_snarkjs::<E>(&vk, n_public)?)?)
+        };
+        let (a, b) = (canonical(&a)?, canonical(&b)?);
+        for key in ["vk_alpha_1", "vk_beta_2", "vk_gamma_2", "vk_delta_2", "IC"] {
+            diff_value(&mut out, file, key.into(), &a[key], &b[key]);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Paths of `file` in both directories, or `None` after recording a
+/// `Difference::Missing` (nothing is recorded if neither has it).
+fn both(
+    out: &mut Vec<Difference>,
+    file: &'static str,
+    dir_a: &Path,
+    dir_b: &Path,
+) -> Option<(PathBuf, PathBuf)> {
+    let (a, b) = (dir_a.join(file), dir_b.join(file));
+    match (a.is_file(), b.is_file()) {
+        (true, true) => Some((a, b)),
+        (false, false) => None,
+        (in_a, _) => {
+            out.push(Difference::Missing { file, in_a });
+            None
+        }
+    }
+}
+
+/// Walk two canonical JSON values (nested arrays of decimal strings) side
+/// by side and record where they differ.
+fn diff_value(out: &mut Vec<Difference>, file: &'static str, path: String, a: &Value, b: &Value) {
+    match (a, b) {
+        (Value::Array(a), Value::Array(b)) => {
+            if a.len() != b.len() {
+                out.push(Difference::Count {
+                    file,
+                    path: path.clone(),
+                    a: a.len(),
+                    b: b.len(),
+                });
+            }
+            for (i, (a, b)) in a.iter().zip(b).enumerate() {
+                diff_value(out, file, format!("{path}[{i}]"), a, b);
+            }
+        }
+        _ if a != b => out.push(Difference::Value {
+            file,
+            path,
+            a: text(a),
+            b: text(b),
+        }),
+        _ => {}
+    }
+}
+
+/// A leaf value as plain text (decimal strings without their quotes).
+fn text(v: &Value) -> String {
+    match v {
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
@@ -0,0 +1,68 @@
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::Proof;
+
+use ark_bls12_381::{Fr, G1Affine, G2Affine};
+
+use crate::error::SnarkjsError;
+use crate::export_proof::check_public_range;
+use crate::snarkjs_common::CurveTag;
+
+/// Encode a Bls12_381 Groth16 proof and its public inputs in the layout of
+/// the EIP-2537 BLS12-381 precompiles, for verifiers built on them.
+///
+/// Every base field element takes 64 bytes: 16 zero bytes followed by its
+/// 48-byte big-endian value. G1 points are `x, y` (128 bytes); G2 points are
+/// `x.c0, x.c1, y.c0, y.c1` (256 bytes), real part first, unlike the Bn254
+/// precompiles. The point at infinity is all zeros. The order is
+/// `a, b, c, input[0..N]`, each public input a 32-byte big-endian word.
+///
+/// Only available for Bls12_381.
+pub fn to_eip2537_bytes<E>(
+    proof: &Proof<E>,          // Groth16 proof from arkworks
+    public: &[E::ScalarField], // list of public inputs
+) -> Result<Vec<u8>, SnarkjsError>
+where
+    E: Pairing<G1Affine = G1Affine, G2Affine = G2Affine, ScalarField = Fr> + CurveTag,
+{
+    check_public_range(public, E::NAME)?;
+    let mut out = Vec::with_capacity(128 + 256 + 128 + 32 * public.len());
+
+    push_g1(&mut out, &proof.a);
+    push_g2(&mut out, &proof.b);
+    push_g1(&mut out, &proof.c);
+    for f in public {
+        out.extend_from_slice(&f.into_bigint().to_bytes_be());
+    }
+
+    Ok(out)
+}
+
+/// Append a 48-byte base field element left-padded to 64 bytes.
+fn push_fp<F: PrimeField>(out: &mut Vec<u8>, f: &F) {
+    let bytes = f.into_bigint().to_bytes_be();
+    out.extend(core::iter::repeat_n(0u8, 64 - bytes.len()));
+    out.extend_from_slice(&bytes);
+}
+
+fn push_g1(out: &mut Vec<u8>, p: &G1Affine) {
+    match p.xy() {
+        Some((x, y)) => {
+            push_fp(out, &x);
+            push_fp(out, &y);
+        }
+        None => out.extend_from_slice(&[0u8; 128]),
+    }
+}
+
+fn push_g2(out: &mut Vec<u8>, p: &G2Affine) {
+    match p.xy() {
+        Some((x, y)) => {
+            for f in [&x.c0, &x.c1, &y.c0, &y.c1] {
+                push_fp(out, f);
+            }
+        }
+        None => out.extend_from_slice(&[0u8; 256]),
+    }
+}
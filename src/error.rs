@@ -0,0 +1,192 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Errors returned by the snarkjs export and import helpers.
+#[derive(Debug)]
+pub enum SnarkjsError {
+    /// Failure while creating directories or writing the output file.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    /// Failure while serializing the JSON structure.
+    Serde(serde_json::Error),
+    /// An arkworks `CanonicalSerialize` file could not be deserialized.
+    Canonical(ark_serialize::SerializationError),
+    /// A public signal is not a canonical element of the curve's scalar field.
+    ScalarOutOfRange { index: usize, curve: &'static str },
+    /// A decimal string is not a canonical field element.
+    InvalidFieldElement(String),
+    /// Element `index` of a bulk parse is not a canonical field element.
+    InvalidFieldElementAt { index: usize, value: String },
+    /// Parsed coordinates do not satisfy the curve equation.
+    NotOnCurve,
+    /// A parsed point is on the curve but outside the prime-order subgroup.
+    NotInSubgroup,
+    /// G2 coordinates are not in Fp2 (e.g. MNT6) and cannot be written in
+    /// snarkjs' `[[x0, x1], [y0, y1]]` shape.
+    UnsupportedG2,
+    /// The pairing target group is not Fp12 (e.g. MNT4/MNT6) and has no
+    /// snarkjs `vk_alphabeta_12` form.
+    UnsupportedGt,
+    /// A verifying key's stored `vk_alphabeta_12` is not `e(vk_alpha_1, vk_beta_2)`.
+    AlphaBetaMismatch,
+    /// A point to export is the point at infinity, which has no affine form.
+    PointAtInfinity,
+    /// A JSON field has the wrong number of elements.
+    InvalidShape { field: &'static str },
+    /// `ExportOptions::public_order` is not a permutation of `0..len`.
+    InvalidPublicOrder { len: usize },
+    /// A verifying key's `IC` does not hold `nPublic + 1` points.
+    IcLengthMismatch { n_public: usize, ic_len: usize },
+    /// A verifying key's `nPublic` is above the importer's sanity limit
+    /// (`MAX_N_PUBLIC` unless configured).
+    TooManyPublicInputs { n_public: usize, max: usize },
+    /// The verifying key, the proof's `publicSignals` (`None` if the proof
+    /// has none) and the public signals checked against them (e.g. from
+    /// `public.json`) disagree on the number of public inputs.
+    InconsistentPublicCount {
+        vk: usize,
+        proof: Option<usize>,
+        public: usize,
+    },
+    /// The public inputs passed in do not hold one value per public input of
+    /// the verifying key (`expected` is the key's `nPublic`).
+    PublicCountMismatch { expected: usize, found: usize },
+    /// The file was produced for a different curve.
+    CurveMismatch {
+        expected: &'static str,
+        found: String,
+    },
+    /// The file's sha256 differs from the expected checksum (truncated or
+    /// corrupted in transit).
+    ChecksumMismatch { expected: String, found: String },
+    /// A binary `.wtns` witness file is malformed or for another field.
+    InvalidWtns(&'static str),
+    /// A curve name that is neither a snarkjs name nor a known alias.
+    UnknownCurve(String),
+    /// The file describes a protocol other than Groth16.
+    ProtocolMismatch(String),
+    /// The proof was rejected by Groth16 verification before export.
+    ProofDoesNotVerify,
+}
+
+impl fmt::Display for SnarkjsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            SnarkjsError::Io(e) => write!(f, "I/O error: {e}"),
+            SnarkjsError::Serde(e) => write!(f, "JSON error: {e}"),
+            SnarkjsError::ScalarOutOfRange { index, curve } => write!(
+                f,
+                "public signal #{index} does not fit the {curve} scalar field modulus"
+            ),
+            SnarkjsError::InvalidFieldElement(s) => {
+                write!(f, "invalid field element: {s:?}")
+            }
+            SnarkjsError::InvalidFieldElementAt { index, value } => {
+                write!(f, "invalid field element #{index}: {value:?}")
+            }
+            SnarkjsError::Canonical(e) => write!(f, "arkworks deserialization failed: {e}"),
+            SnarkjsError::NotOnCurve => write!(f, "point is not on the curve"),
+            SnarkjsError::NotInSubgroup => write!(f, "point is not in the prime-order subgroup"),
+            SnarkjsError::UnsupportedG2 => {
+                write!(f, "G2 coordinates are not in Fp2 and have no snarkjs form")
+            }
+            SnarkjsError::UnsupportedGt => {
+                write!(
+                    f,
+                    "pairing target field is not Fp12 and has no snarkjs form"
+                )
+            }
+            SnarkjsError::AlphaBetaMismatch => {
+                write!(f, "vk_alphabeta_12 does not match e(vk_alpha_1, vk_beta_2)")
+            }
+            SnarkjsError::PointAtInfinity => write!(f, "point at infinity has no affine form"),
+            SnarkjsError::InvalidShape { field } => {
+                write!(f, "field {field:?} has an unexpected number of elements")
+            }
+            SnarkjsError::InvalidPublicOrder { len } => write!(
+                f,
+                "public_order is not a permutation of the {len} public inputs"
+            ),
+            SnarkjsError::IcLengthMismatch { n_public, ic_len } => write!(
+                f,
+                "IC has {ic_len} points, but nPublic = {n_public} requires {}",
+                n_public.saturating_add(1)
+            ),
+            SnarkjsError::TooManyPublicInputs { n_public, max } => write!(
+                f,
+                "nPublic = {n_public} exceeds the limit of {max} public inputs"
+            ),
+            SnarkjsError::InconsistentPublicCount { vk, proof, public } => {
+                write!(
+                    f,
+                    "inconsistent number of public inputs: vk nPublic = {vk}, "
+                )?;
+                match proof {
+                    Some(n) => write!(f, "proof publicSignals = {n}, ")?,
+                    None => write!(f, "proof has no publicSignals, ")?,
+                }
+                write!(f, "public signals = {public}")
+            }
+            SnarkjsError::PublicCountMismatch { expected, found } => write!(
+                f,
+                "verifying key has {expected} public inputs, but {found} were given"
+            ),
+            SnarkjsError::CurveMismatch { expected, found } => {
+                write!(f, "curve mismatch: expected {expected:?}, found {found:?}")
+            }
+            SnarkjsError::ChecksumMismatch { expected, found } => {
+                write!(f, "sha256 mismatch: expected {expected}, found {found}")
+            }
+            SnarkjsError::InvalidWtns(reason) => write!(f, "invalid .wtns file: {reason}"),
+            SnarkjsError::UnknownCurve(c) => write!(f, "unknown curve {c:?}"),
+            SnarkjsError::ProtocolMismatch(p) => {
+                write!(f, "unsupported protocol {p:?}, expected \"groth16\"")
+            }
+            SnarkjsError::ProofDoesNotVerify => {
+                write!(f, "proof does not verify against the verifying key")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SnarkjsError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            SnarkjsError::Io(e) => Some(e),
+            SnarkjsError::Serde(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for SnarkjsError {
+    fn from(e: std::io::Error) -> Self {
+        SnarkjsError::Io(e)
+    }
+}
+
+impl From<ark_serialize::SerializationError> for SnarkjsError {
+    fn from(e: ark_serialize::SerializationError) -> Self {
+        SnarkjsError::Canonical(e)
+    }
+}
+
+impl From<serde_json::Error> for SnarkjsError {
+    fn from(e: serde_json::Error) -> Self {
+        SnarkjsError::Serde(e)
+    }
+}
+
+/// Lets callers that work with `std::io::Result` keep using `?`.
+#[cfg(feature = "std")]
+impl From<SnarkjsError> for std::io::Error {
+    fn from(e: SnarkjsError) -> Self {
+        match e {
+            SnarkjsError::Io(e) => e,
+            other => std::io::Error::other(other),
+        }
+    }
+}
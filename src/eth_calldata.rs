@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::Proof;
+
+use ark_bn254::{Fq, Fr, G1Affine, G2Affine};
+
+use crate::error::SnarkjsError;
+use crate::export_proof::check_public_range;
+use crate::options::ExportOptions;
+use crate::snarkjs_common::{CurveTag, negate};
+
+/// ABI-encode a Bn254 Groth16 proof and its public inputs as the arguments of
+/// the snarkjs Solidity verifier,
+/// `verifyProof(uint[2] a, uint[2][2] b, uint[2] c, uint[N] input)`.
+///
+/// Every value is a 32-byte big-endian word, in the order
+/// `a.x, a.y, b.x.c1, b.x.c0, b.y.c1, b.y.c0, c.x, c.y, input[0..N]`:
+/// the Fp2 limbs of `b` are reversed, as expected by the alt_bn128 pairing
+/// precompile. The point at infinity is encoded as all-zero coordinates.
+/// The 4-byte function selector is not included.
+///
+/// Only available for Bn254, the one curve with EVM precompiles.
+pub fn to_eth_calldata_bytes<E>(
+    proof: &Proof<E>,          // Groth16 proof from arkworks
+    public: &[E::ScalarField], // list of public inputs
+) -> Result<Vec<u8>, SnarkjsError>
+where
+    E: Pairing<G1Affine = G1Affine, G2Affine = G2Affine, ScalarField = Fr> + CurveTag,
+{
+    to_eth_calldata_bytes_with_options::<E>(proof, public, &ExportOptions::default())
+}
+
+/// Same as `to_eth_calldata_bytes`; with `options.negate_a` the first point
+/// is written as `-pi_a`, for verifier templates that expect it negated, and
+/// `options.public_order` reorders the inputs like the JSON exporters do.
+pub fn to_eth_calldata_bytes_with_options<E>(
+    proof: &Proof<E>,          // Groth16 proof from arkworks
+    public: &[E::ScalarField], // list of public inputs
+    options: &ExportOptions,   // calldata conventions
+) -> Result<Vec<u8>, SnarkjsError>
+where
+    E: Pairing<G1Affine = G1Affine, G2Affine = G2Affine, ScalarField = Fr> + CurveTag,
+{
+    let public = options.reorder_public(public)?;
+    check_public_range(&public, E::NAME)?;
+    let mut out = Vec::with_capacity(32 * (8 + public.len()));
+
+    let a = if options.negate_a {
+        negate(&proof.a)
+    } else {
+        proof.a
+    };
+    push_g1(&mut out, &a);
+    push_g2(&mut out, &proof.b);
+    push_g1(&mut out, &proof.c);
+    for f in &public {
+        push_word(&mut out, f);
+    }
+
+    Ok(out)
+}
+
+/// Append a field element as a 32-byte big-endian word.
+fn push_word<F: PrimeField>(out: &mut Vec<u8>, f: &F) {
+    let bytes = f.into_bigint().to_bytes_be();
+    out.extend(core::iter::repeat_n(0u8, 32 - bytes.len()));
+    out.extend_from_slice(&bytes);
+}
+
+fn push_g1(out: &mut Vec<u8>, p: &G1Affine) {
+    let (x, y) = p.xy().unwrap_or((Fq::from(0u64), Fq::from(0u64)));
+    push_word(out, &x);
+    push_word(out, &y);
+}
+
+fn push_g2(out: &mut Vec<u8>, p: &G2Affine) {
+    match p.xy() {
+        // EVM order: imaginary part first
+        Some((x, y)) => {
+            for f in [&x.c1, &x.c0, &y.c1, &y.c0] {
+                push_word(out, f);
+            }
+        }
+        None => out.extend_from_slice(&[0u8; 128]),
+    }
+}
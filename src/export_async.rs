@@ -0,0 +1,64 @@
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, VerifyingKey};
+use std::path::Path;
+
+use crate::error::SnarkjsError;
+use crate::export_proof::{ProofJson, proof_to_snarkjs};
+use crate::export_vk::{VkJson, vk_to_snarkjs};
+use crate::public_signals::PublicSignals;
+use crate::snarkjs_common::{AsFp2, CurveTag, with_path};
+
+/// Async variant of `export_proof`: the JSON is built synchronously in
+/// memory, only the file I/O goes through `tokio::fs`.
+pub async fn export_proof_async<E, P>(
+    proof: &Proof<E>,                                 // Groth16 proof from arkworks
+    public: impl Into<PublicSignals<E::ScalarField>>, // public inputs, in circuit order
+    out_path: P,                                      // output path for JSON file
+) -> Result<ProofJson, SnarkjsError>
+where
+    P: AsRef<Path>,        // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag, // curve type with snarkjs "NAME"
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    let json = proof_to_snarkjs::<E>(proof, &public.into())?;
+    write_async(out_path, serde_json::to_vec_pretty(&json)?).await?;
+    Ok(json)
+}
+
+/// Async variant of `export_vk`: the JSON is built synchronously in memory,
+/// only the file I/O goes through `tokio::fs`.
+pub async fn export_vk_async<E, P>(
+    vk: &VerifyingKey<E>, // Groth16 verifying key from arkworks
+    n_public: usize,      // number of public inputs
+    out_path: P,          // output path for JSON file
+) -> Result<VkJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let json = vk_to_snarkjs::<E>(vk, n_public)?;
+    write_async(out_path, serde_json::to_vec_pretty(&json)?).await?;
+    Ok(json)
+}
+
+/// Write `bytes` to `out_path`, creating parent directories as needed.
+async fn write_async<P: AsRef<Path>>(out_path: P, bytes: Vec<u8>) -> Result<(), SnarkjsError> {
+    let out_path = out_path.as_ref();
+    if let Some(parent) = out_path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| with_path(e.into(), parent))?;
+    }
+    tokio::fs::write(out_path, bytes)
+        .await
+        .map_err(|e| with_path(e.into(), out_path))?;
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, VerifyingKey};
+use serde::{Deserialize, Serialize, Serializer, ser::SerializeStruct};
+use std::{fs, path::Path};
+
+use crate::bundle::ProofPoints;
+use crate::error::SnarkjsError;
+use crate::export_proof::{ProofJson, proof_to_snarkjs};
+use crate::export_vk::vk_to_snarkjs;
+use crate::import_proof::{ProofFile, parse_public_signals, proof_from_file};
+use crate::public_signals::{PublicSignals, check_public_len};
+use crate::snarkjs_common::{
+    AsFp2, CurveTag, FromXy, with_path, write_json_file, write_json_pretty,
+};
+
+/// Export many proofs that share one verifying key into `dir`.
+///
+/// Layout: `dir/verification_key.json` plus `dir/proof_0.json`,
+/// `dir/proof_1.json`, ... in the order of `proofs`. The directory is created
+/// once up front. Returns the in-memory `ProofJson` for every proof.
+pub fn export_proof_batch<E, P>(
+    vk: &VerifyingKey<E>,                       // shared verifying key
+    proofs: &[(Proof<E>, Vec<E::ScalarField>)], // proofs with their public inputs
+    dir: P,                                     // output directory
+) -> Result<Vec<ProofJson>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir).map_err(|e| with_path(e.into(), dir))?;
+
+    // One verifying key for the whole batch (IC holds n_public + 1 points)
+    let n_public = vk.gamma_abc_g1.len().saturating_sub(1);
+    write_json_file(
+        dir.join("verification_key.json"),
+        &vk_to_snarkjs::<E>(vk, n_public)?,
+    )?;
+
+    proofs
+        .iter()
+        .enumerate()
+        .map(|(i, (proof, public))| {
+            let json = proof_to_snarkjs::<E>(proof, public)?;
+            write_json_file(dir.join(format!("proof_{i}.json")), &json)?;
+            Ok(json)
+        })
+        .collect()
+}
+
+/// Export N proofs of the same statement (one set of public inputs,
+/// different witnesses) into `dir`, writing the public signals only once.
+///
+/// Layout: `dir/verification_key.json`, `dir/public.json` and
+/// `dir/proof_0.json`, `dir/proof_1.json`, ... in the order of `proofs`.
+/// Like snarkjs' own `proof.json`, the proof files carry no
+/// `publicSignals`: each is verified against the shared `public.json`
+/// (e.g. with `verify_snarkjs`). `public` must hold `nPublic` values of
+/// `vk`, or this fails with `SnarkjsError::PublicCountMismatch` before
+/// anything is written. Returns the in-memory `ProofJson` for every proof.
+pub fn export_shared_public_batch<E, P>(
+    vk: &VerifyingKey<E>,      // shared verifying key
+    proofs: &[Proof<E>],       // proofs of the same statement
+    public: &[E::ScalarField], // their common public inputs
+    dir: P,                    // output directory
+) -> Result<Vec<ProofJson>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    check_public_len(vk, public.len())?;
+    let jsons = proofs
+        .iter()
+        .map(|proof| proof_to_snarkjs::<E>(proof, public))
+        .collect::<Result<Vec<_>, _>>()?;
+    let vk_json = vk_to_snarkjs::<E>(vk, public.len())?;
+
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir).map_err(|e| with_path(e.into(), dir))?;
+    write_json_file(dir.join("verification_key.json"), &vk_json)?;
+    write_json_file(dir.join("public.json"), &PublicSignals::from(public))?;
+    for (i, json) in jsons.iter().enumerate() {
+        write_json_file(
+            dir.join(format!("proof_{i}.json")),
+            &ProofPoints::from(json),
+        )?;
+    }
+
+    Ok(jsons)
+}
+
+/// One element of `proofs.json`: `{ "proof": {...}, "publicSignals": [...] }`,
+/// with `proof` in the `proof.json` layout minus its public signals.
+struct ProofEntry<'a>(&'a ProofJson);
+
+impl Serialize for ProofEntry<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("ProofEntry", 2)?;
+        s.serialize_field("proof", &ProofPoints::from(self.0))?;
+        s.serialize_field("publicSignals", &self.0.publicSignals)?;
+        s.end()
+    }
+}
+
+/// JSON structure of a `proofs.json` element as read from disk.
+#[derive(Deserialize)]
+struct ProofEntryFile {
+    proof: ProofFile,
+    #[serde(rename = "publicSignals")]
+    public_signals: Vec<String>,
+}
+
+/// Export many proofs into a single file holding a JSON array of
+/// `{ "proof": {...}, "publicSignals": [...] }` objects, in the order of
+/// `proofs`. Unlike `export_proof_batch` no verifying key is written.
+/// Returns the in-memory `ProofJson` for every proof.
+pub fn export_proofs_array<E, P>(
+    proofs: &[(Proof<E>, Vec<E::ScalarField>)], // proofs with their public inputs
+    out_path: P,                                // output path for JSON file
+) -> Result<Vec<ProofJson>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    let jsons = proofs
+        .iter()
+        .map(|(proof, public)| proof_to_snarkjs::<E>(proof, public))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let entries: Vec<ProofEntry> = jsons.iter().map(ProofEntry).collect();
+    write_json_pretty(out_path, &entries)?;
+
+    Ok(jsons)
+}
+
+/// Import a file written by `export_proofs_array`.
+/// Returns every proof with its public signals, in file order.
+#[allow(clippy::type_complexity)]
+pub fn import_proofs_array<E, P>(
+    path: P,
+) -> Result<Vec<(Proof<E>, Vec<E::ScalarField>)>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let entries: Vec<ProofEntryFile> = serde_json::from_slice(&fs::read(path)?)?;
+    entries
+        .iter()
+        .map(|entry| {
+            let (proof, _) = proof_from_file::<E>(&entry.proof)?;
+            Ok((proof, parse_public_signals(&entry.public_signals)?))
+        })
+        .collect()
+}
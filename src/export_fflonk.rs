@@ -0,0 +1,208 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+use serde::Serialize;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use crate::error::SnarkjsError;
+use crate::export_proof::public_to_dec;
+#[cfg(feature = "std")]
+use crate::snarkjs_common::write_json_pretty;
+use crate::snarkjs_common::{CurveId, CurveTag, f_to_dec, g1_xyz, g2_xyz};
+
+/// Components of an fflonk proof in the layout used by snarkjs.
+///
+/// arkworks has no fflonk prover, so the commitments and evaluations are
+/// passed in explicitly. snarkjs only implements fflonk on BN254.
+pub struct FflonkProof {
+    pub c1: G1Affine, // commitment [C1]
+    pub c2: G1Affine, // commitment [C2]
+    pub w1: G1Affine, // opening proof [W1]
+    pub w2: G1Affine, // opening proof [W']
+    pub ql: Fr,
+    pub qr: Fr,
+    pub qm: Fr,
+    pub qo: Fr,
+    pub qc: Fr,
+    pub s1: Fr,
+    pub s2: Fr,
+    pub s3: Fr,
+    pub a: Fr,
+    pub b: Fr,
+    pub c: Fr,
+    pub z: Fr,
+    pub zw: Fr,
+    pub t1w: Fr,
+    pub t2w: Fr,
+    pub inv: Fr, // batched inverse used by the verifier
+}
+
+/// Polynomial commitments of an fflonk `proof.json`.
+#[derive(Serialize)]
+#[allow(non_snake_case)] // keys mirror the snarkjs names
+pub struct FflonkPolynomialsJson {
+    pub C1: [String; 3], // G1 points [x, y, z]
+    pub C2: [String; 3],
+    pub W1: [String; 3],
+    pub W2: [String; 3],
+}
+
+/// Evaluations of an fflonk `proof.json` (decimal-encoded).
+#[derive(Serialize)]
+pub struct FflonkEvaluationsJson {
+    pub ql: String,
+    pub qr: String,
+    pub qm: String,
+    pub qo: String,
+    pub qc: String,
+    pub s1: String,
+    pub s2: String,
+    pub s3: String,
+    pub a: String,
+    pub b: String,
+    pub c: String,
+    pub z: String,
+    pub zw: String,
+    pub t1w: String,
+    pub t2w: String,
+    pub inv: String,
+}
+
+/// JSON structure for fflonk proof in `snarkjs`-compatible format.
+#[derive(Serialize)]
+#[allow(non_snake_case)] // keys mirror the snarkjs names
+pub struct FflonkProofJson {
+    pub polynomials: FflonkPolynomialsJson,
+    pub evaluations: FflonkEvaluationsJson,
+    pub protocol: &'static str,     // always "fflonk"
+    pub curve: CurveId,             // always "bn128"
+    pub publicSignals: Vec<String>, // array of decimal-encoded public inputs
+}
+
+/// Preprocessed fflonk verifying key components in the layout used by snarkjs.
+pub struct FflonkVk {
+    pub n_public: usize, // number of public inputs
+    pub power: u32,      // log2 of the domain size
+    pub k1: Fr,          // coset shift for the second wire
+    pub k2: Fr,          // coset shift for the third wire
+    pub w: Fr,           // generator of the evaluation domain
+    pub w3: Fr,          // cube root of unity
+    pub w4: Fr,          // 4th root of unity
+    pub w8: Fr,          // 8th root of unity
+    pub wr: Fr,          // root of unity for the quotient openings
+    pub x_2: G2Affine,   // [x]_2 from the SRS
+    pub c0: G1Affine,    // commitment to the preprocessed polynomials
+}
+
+/// JSON structure for fflonk verifying key in `snarkjs`-compatible format.
+#[derive(Serialize)]
+#[allow(non_snake_case)] // keys mirror the snarkjs names
+pub struct FflonkVkJson {
+    pub protocol: &'static str, // always "fflonk"
+    pub curve: CurveId,         // always "bn128"
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    pub power: u32,
+    pub k1: String, // decimal-encoded scalars
+    pub k2: String,
+    pub w: String,
+    pub w3: String,
+    pub w4: String,
+    pub w8: String,
+    pub wr: String,
+    pub X_2: [[String; 2]; 3], // G2 point [x, y, z]
+    pub C0: [String; 3],       // G1 point [x, y, z]
+}
+
+/// Convert fflonk proof components and their public signals to `snarkjs`
+/// JSON format (in-memory only).
+pub fn fflonk_proof_to_snarkjs(
+    proof: &FflonkProof, // fflonk proof components
+    public: &[Fr],       // list of public inputs
+) -> Result<FflonkProofJson, SnarkjsError> {
+    Ok(FflonkProofJson {
+        polynomials: FflonkPolynomialsJson {
+            C1: g1_xyz(&proof.c1)?,
+            C2: g1_xyz(&proof.c2)?,
+            W1: g1_xyz(&proof.w1)?,
+            W2: g1_xyz(&proof.w2)?,
+        },
+        evaluations: FflonkEvaluationsJson {
+            ql: f_to_dec(&proof.ql),
+            qr: f_to_dec(&proof.qr),
+            qm: f_to_dec(&proof.qm),
+            qo: f_to_dec(&proof.qo),
+            qc: f_to_dec(&proof.qc),
+            s1: f_to_dec(&proof.s1),
+            s2: f_to_dec(&proof.s2),
+            s3: f_to_dec(&proof.s3),
+            a: f_to_dec(&proof.a),
+            b: f_to_dec(&proof.b),
+            c: f_to_dec(&proof.c),
+            z: f_to_dec(&proof.z),
+            zw: f_to_dec(&proof.zw),
+            t1w: f_to_dec(&proof.t1w),
+            t2w: f_to_dec(&proof.t2w),
+            inv: f_to_dec(&proof.inv),
+        },
+        protocol: "fflonk",
+        curve: Bn254::ID,
+        publicSignals: public_to_dec(public, Bn254::NAME)?,
+    })
+}
+
+/// Convert fflonk verifying key components to `snarkjs` JSON format
+/// (in-memory only).
+pub fn fflonk_vk_to_snarkjs(vk: &FflonkVk) -> Result<FflonkVkJson, SnarkjsError> {
+    Ok(FflonkVkJson {
+        protocol: "fflonk",
+        curve: Bn254::ID,
+        n_public: vk.n_public,
+        power: vk.power,
+        k1: f_to_dec(&vk.k1),
+        k2: f_to_dec(&vk.k2),
+        w: f_to_dec(&vk.w),
+        w3: f_to_dec(&vk.w3),
+        w4: f_to_dec(&vk.w4),
+        w8: f_to_dec(&vk.w8),
+        wr: f_to_dec(&vk.wr),
+        X_2: g2_xyz(&vk.x_2)?,
+        C0: g1_xyz(&vk.c0)?,
+    })
+}
+
+/// Export fflonk proof components to `snarkjs` JSON format.
+/// Writes the file to `out_path` and returns the in-memory `FflonkProofJson`.
+#[cfg(feature = "std")]
+pub fn export_fflonk_proof<P>(
+    proof: &FflonkProof, // fflonk proof components
+    public: &[Fr],       // list of public inputs
+    out_path: P,         // output path for JSON file
+) -> Result<FflonkProofJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    let json = fflonk_proof_to_snarkjs(proof, public)?;
+
+    write_json_pretty(out_path, &json)?;
+
+    Ok(json)
+}
+
+/// Export fflonk verifying key components to `snarkjs` JSON format.
+/// Writes the file to `out_path` and returns the in-memory `FflonkVkJson`.
+#[cfg(feature = "std")]
+pub fn export_fflonk_vk<P>(
+    vk: &FflonkVk, // fflonk verifying key components
+    out_path: P,   // output path for JSON file
+) -> Result<FflonkVkJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    let json = fflonk_vk_to_snarkjs(vk)?;
+
+    write_json_pretty(out_path, &json)?;
+
+    Ok(json)
+}
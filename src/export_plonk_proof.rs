@@ -0,0 +1,113 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use serde::Serialize;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use crate::error::SnarkjsError;
+use crate::export_proof::public_to_dec;
+#[cfg(feature = "std")]
+use crate::snarkjs_common::write_json_pretty;
+use crate::snarkjs_common::{CurveId, CurveTag, f_to_dec, g1_xyz};
+
+/// Components of a Plonk proof in the layout used by snarkjs.
+///
+/// arkworks has no canonical Plonk proof type, so the prover's commitments
+/// and evaluations are passed in explicitly.
+pub struct PlonkProof<E: Pairing> {
+    pub a: E::G1Affine,    // wire commitment [a]
+    pub b: E::G1Affine,    // wire commitment [b]
+    pub c: E::G1Affine,    // wire commitment [c]
+    pub z: E::G1Affine,    // permutation commitment [z]
+    pub t1: E::G1Affine,   // quotient commitment [t_lo]
+    pub t2: E::G1Affine,   // quotient commitment [t_mid]
+    pub t3: E::G1Affine,   // quotient commitment [t_hi]
+    pub wxi: E::G1Affine,  // opening proof at xi
+    pub wxiw: E::G1Affine, // opening proof at xi * omega
+    pub eval_a: E::ScalarField,
+    pub eval_b: E::ScalarField,
+    pub eval_c: E::ScalarField,
+    pub eval_s1: E::ScalarField,
+    pub eval_s2: E::ScalarField,
+    pub eval_zw: E::ScalarField,
+}
+
+/// JSON structure for Plonk proof in `snarkjs`-compatible format.
+#[derive(Serialize)]
+#[allow(non_snake_case)] // keys mirror the snarkjs names
+pub struct PlonkProofJson {
+    pub A: [String; 3], // G1 points [x, y, z]
+    pub B: [String; 3],
+    pub C: [String; 3],
+    pub Z: [String; 3],
+    pub T1: [String; 3],
+    pub T2: [String; 3],
+    pub T3: [String; 3],
+    pub Wxi: [String; 3],
+    pub Wxiw: [String; 3],
+    pub eval_a: String, // decimal-encoded evaluations
+    pub eval_b: String,
+    pub eval_c: String,
+    pub eval_s1: String,
+    pub eval_s2: String,
+    pub eval_zw: String,
+    pub protocol: &'static str,     // always "plonk"
+    pub curve: CurveId,             // serialized as "bn128", "bls12381", ...
+    pub publicSignals: Vec<String>, // array of decimal-encoded public inputs
+}
+
+/// Convert Plonk proof components and their public signals to `snarkjs`
+/// JSON format (in-memory only).
+pub fn plonk_proof_to_snarkjs<E>(
+    proof: &PlonkProof<E>,     // Plonk proof components
+    public: &[E::ScalarField], // list of public inputs
+) -> Result<PlonkProofJson, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    E::ScalarField: PrimeField,
+{
+    Ok(PlonkProofJson {
+        A: g1_xyz(&proof.a)?,
+        B: g1_xyz(&proof.b)?,
+        C: g1_xyz(&proof.c)?,
+        Z: g1_xyz(&proof.z)?,
+        T1: g1_xyz(&proof.t1)?,
+        T2: g1_xyz(&proof.t2)?,
+        T3: g1_xyz(&proof.t3)?,
+        Wxi: g1_xyz(&proof.wxi)?,
+        Wxiw: g1_xyz(&proof.wxiw)?,
+        eval_a: f_to_dec(&proof.eval_a),
+        eval_b: f_to_dec(&proof.eval_b),
+        eval_c: f_to_dec(&proof.eval_c),
+        eval_s1: f_to_dec(&proof.eval_s1),
+        eval_s2: f_to_dec(&proof.eval_s2),
+        eval_zw: f_to_dec(&proof.eval_zw),
+        protocol: "plonk",
+        curve: E::ID,
+        publicSignals: public_to_dec(public, E::NAME)?,
+    })
+}
+
+/// Export Plonk proof components to `snarkjs` JSON format.
+/// Writes the file to `out_path` and returns the in-memory `PlonkProofJson`.
+#[cfg(feature = "std")]
+pub fn export_plonk_proof<E, P>(
+    proof: &PlonkProof<E>,     // Plonk proof components
+    public: &[E::ScalarField], // list of public inputs
+    out_path: P,               // output path for JSON file
+) -> Result<PlonkProofJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    E::ScalarField: PrimeField,
+{
+    let json = plonk_proof_to_snarkjs::<E>(proof, public)?;
+
+    write_json_pretty(out_path, &json)?;
+
+    Ok(json)
+}
@@ -1,69 +1,315 @@
-use ark_ec::pairing::Pairing;
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
 use ark_ff::PrimeField;
 use ark_groth16::Proof;
+#[cfg(feature = "std")]
+use ark_groth16::{Groth16, PreparedVerifyingKey};
+use core::fmt;
 use serde::Serialize;
-use serde_json::to_writer_pretty;
-use std::{fs, fs::File, path::Path};
+#[cfg(feature = "std")]
+use std::path::Path;
 
-use crate::snarkjs_common::{AsFp2, CurveTag, f_to_dec, g1_xy, g2_xyxy};
+use crate::error::SnarkjsError;
+#[cfg(feature = "std")]
+use crate::metadata::CRATE_VERSION;
+#[cfg(feature = "std")]
+use crate::options::ExportOptions;
+#[cfg(feature = "std")]
+use crate::public_signals::PublicSignals;
+use crate::snarkjs_common::{AsFp2, CurveId, CurveTag, ExportCurve, curve_name_from_str, f_to_dec};
+#[cfg(feature = "std")]
+use crate::snarkjs_common::{parse_field_checked, write_json_pretty, write_json_pretty_with};
 
 /// JSON structure for Groth16 proof in `snarkjs`-compatible format.
+/// Fields are declared in the key order of snarkjs' `proof.json`.
+///
+/// `G1` is the form of `pi_a` / `pi_c`: snarkjs' projective `[x, y, z]` by
+/// default, or affine `[x, y]` when written with `g1_projective: false`
+/// (see `ExportOptions::g1_projective`).
 #[derive(Serialize)]
-pub struct ProofJson {
-    pub protocol: &'static str,     // always "groth16"
-    pub curve: &'static str,        // "bn128" or "bls12381"
-    pub pi_a: [String; 3],          // G1 point [x, y, 1]
-    pub pi_b: [[String; 2]; 3],     // G2 point [[x0, x1], [y0, y1], [1, 0]]
-    pub pi_c: [String; 3],          // G1 point [x, y, 1]
+#[allow(non_snake_case)] // `publicSignals` mirrors the snarkjs key
+pub struct ProofJson<G1 = [String; 3]> {
+    pub pi_a: G1,                   // G1 point [x, y, z], z = 0 for infinity
+    pub pi_b: [[String; 2]; 3],     // G2 point [[x0, x1], [y0, y1], [z0, z1]]
+    pub pi_c: G1,                   // G1 point [x, y, z], z = 0 for infinity
+    pub protocol: &'static str,     // "groth16" (see `ExportOptions`)
+    pub curve: CurveId,             // serialized as "bn128", "bls12381", ...
     pub publicSignals: Vec<String>, // array of decimal-encoded public inputs
+    #[serde(rename = "arkSnarkjsVersion", skip_serializing_if = "Option::is_none")]
+    pub ark_snarkjs_version: Option<&'static str>, // optional crate version (see `ExportOptions`)
 }
 
-/// Export a Groth16 proof and its public signals to `snarkjs` JSON format.
-/// Writes the file to `out_path` and returns the in-memory `ProofJson`.
-pub fn export_proof<E, P>(
+/// Pretty-printed JSON, as `serde_json::to_string_pretty` would produce.
+impl<G1: Serialize> fmt::Display for ProofJson<G1> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => f.write_str(&json),
+            Err(_) => f.write_str("<unserializable ProofJson>"),
+        }
+    }
+}
+
+/// Convert a Groth16 proof and its public signals to `snarkjs` JSON format
+/// (in-memory only).
+///
+/// Fails with `SnarkjsError::ScalarOutOfRange` if a public value is not
+/// below the scalar field modulus of `E`.
+pub fn proof_to_snarkjs<E>(
     proof: &Proof<E>,          // Groth16 proof from arkworks
     public: &[E::ScalarField], // list of public inputs
-    out_path: P,               // output path for JSON file
-) -> std::io::Result<ProofJson>
+) -> Result<ProofJson, SnarkjsError>
 where
-    P: AsRef<Path>,        // accepts &str, String, Path, PathBuf
     E: Pairing + CurveTag, // curve type with snarkjs "NAME"
-    <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
-    <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
     E::ScalarField: PrimeField,
 {
-    // Extract affine coordinates for proof points
-    let a = g1_xy(&proof.a);
-    let b = g2_xyxy(&proof.b);
-    let c = g1_xy(&proof.c);
+    proof_to_snarkjs_dyn::<E>(proof, public)
+}
+
+/// Same as `proof_to_snarkjs`, for any curve implementing `ExportCurve`
+/// (including curves defined outside this crate). A curve unknown to
+/// `CurveId` is recorded as `CurveId::Other(E::NAME)`.
+pub fn proof_to_snarkjs_dyn<E: ExportCurve>(
+    proof: &Proof<E>,          // Groth16 proof from arkworks
+    public: &[E::ScalarField], // list of public inputs
+) -> Result<ProofJson, SnarkjsError> {
+    // Projective coordinates for proof points
+    let pi_a = E::g1_to_snarkjs(&proof.a)?;
+    let pi_b = E::g2_to_snarkjs(&proof.b)?;
+    let pi_c = E::g1_to_snarkjs(&proof.c)?;
 
     // Convert public signals to decimal strings
-    let public_signals = public.iter().map(f_to_dec::<E::ScalarField>).collect();
+    let public_signals = public_to_dec(public, E::NAME)?;
 
     // Build the JSON structure
-    let json = ProofJson {
+    Ok(ProofJson {
+        pi_a,
+        pi_b,
+        pi_c,
         protocol: "groth16",
-        curve: E::NAME,
-        pi_a: [a[0].clone(), a[1].clone(), "1".to_string()],
-        pi_b: [
-            [b[0][0].clone(), b[0][1].clone()],
-            [b[1][0].clone(), b[1][1].clone()],
-            ["1".to_string(), "0".to_string()],
-        ],
-        pi_c: [c[0].clone(), c[1].clone(), "1".to_string()],
+        curve: curve_name_from_str(E::NAME).unwrap_or(CurveId::Other(E::NAME)),
         publicSignals: public_signals,
-    };
+        ark_snarkjs_version: None,
+    })
+}
+
+/// Convert a Groth16 proof and its public signals to a `snarkjs` JSON value,
+/// ready to be embedded in a larger JSON document.
+pub fn proof_to_snarkjs_value<E>(
+    proof: &Proof<E>,
+    public: &[E::ScalarField],
+) -> Result<serde_json::Value, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    Ok(serde_json::to_value(proof_to_snarkjs::<E>(proof, public)?)?)
+}
+
+/// Check that `export_proof` would succeed for this proof without writing
+/// anything: runs the same conversion and drops the result, failing with the
+/// same errors (`ScalarOutOfRange`, `UnsupportedG2`). Proof points at
+/// infinity are valid: they are written as `z = 0`, like snarkjs does.
+pub fn validate_proof<E>(
+    proof: &Proof<E>,          // Groth16 proof from arkworks
+    public: &[E::ScalarField], // list of public inputs
+) -> Result<(), SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    proof_to_snarkjs::<E>(proof, public).map(drop)
+}
+
+/// Export a Groth16 proof and its public signals to `snarkjs` JSON format.
+/// Writes the file to `out_path` and returns the in-memory `ProofJson`.
+///
+/// Fails with `SnarkjsError::ScalarOutOfRange` if a public value is not
+/// below the scalar field modulus of `E`.
+#[cfg(feature = "std")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(n_public = tracing::field::Empty, path = %out_path.as_ref().display())
+    )
+)]
+pub fn export_proof<E, P>(
+    proof: &Proof<E>,                                 // Groth16 proof from arkworks
+    public: impl Into<PublicSignals<E::ScalarField>>, // public inputs, in circuit order
+    out_path: P,                                      // output path for JSON file
+) -> Result<ProofJson, SnarkjsError>
+where
+    P: AsRef<Path>,        // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag, // curve type with snarkjs "NAME"
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    let public = public.into();
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("n_public", public.len());
+
+    export_proof_with_options::<E, _>(proof, public, out_path, &ExportOptions::default())
+}
 
-    // Ensure parent directories exist
-    if let Some(parent) = out_path.as_ref().parent()
-        && !parent.as_os_str().is_empty()
-    {
-        fs::create_dir_all(parent)?;
+/// Same as `export_proof`, with the output formatted according to `options`.
+#[cfg(feature = "std")]
+pub fn export_proof_with_options<E, P>(
+    proof: &Proof<E>,                                 // Groth16 proof from arkworks
+    public: impl Into<PublicSignals<E::ScalarField>>, // public inputs, in circuit order
+    out_path: P,                                      // output path for JSON file
+    options: &ExportOptions,                          // output formatting
+) -> Result<ProofJson, SnarkjsError>
+where
+    P: AsRef<Path>,        // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag, // curve type with snarkjs "NAME"
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    // Build JSON structure in memory
+    let public = options.reorder_public(&public.into())?;
+    let mut json = proof_to_snarkjs::<E>(proof, &public)?;
+    json.protocol = options.protocol;
+    json.curve = options.curve_name_style.apply(json.curve);
+    if options.version_tag {
+        json.ark_snarkjs_version = Some(CRATE_VERSION);
     }
 
     // Write pretty-printed JSON to file
-    let file = File::create(out_path)?;
-    to_writer_pretty(file, &json).map_err(std::io::Error::other)?;
+    if options.g1_projective {
+        write_json_pretty_with(out_path, &json, options)?;
+    } else {
+        write_json_pretty_with(out_path, &json.with_affine_g1(), options)?;
+    }
+
+    Ok(json)
+}
+
+#[cfg(feature = "std")]
+impl ProofJson {
+    /// The same proof with `pi_a` / `pi_c` as 2-element affine pairs (see
+    /// `ExportOptions::g1_projective`); every other field as is.
+    fn with_affine_g1(&self) -> ProofJson<[&str; 2]> {
+        ProofJson {
+            pi_a: affine_xy(&self.pi_a),
+            pi_b: self.pi_b.clone(),
+            pi_c: affine_xy(&self.pi_c),
+            protocol: self.protocol,
+            curve: self.curve,
+            publicSignals: self.publicSignals.clone(),
+            ark_snarkjs_version: self.ark_snarkjs_version,
+        }
+    }
+}
+
+/// Affine `[x, y]` of a projective snarkjs G1 point (`z` is `"1"`, or `"0"`
+/// for infinity, which becomes `["0", "0"]` as in snarkjs' affine form).
+#[cfg(feature = "std")]
+fn affine_xy(point: &[String; 3]) -> [&str; 2] {
+    let [x, y, z] = point;
+    if z == "0" { ["0", "0"] } else { [x, y] }
+}
+
+/// Same as `export_proof`, with the public inputs given as decimal strings
+/// (e.g. from a config file or an API). Each string is parsed with
+/// `parse_field_checked`, so values outside the scalar field fail with
+/// `SnarkjsError::InvalidFieldElement` before anything is written.
+#[cfg(feature = "std")]
+pub fn export_proof_from_strings<E, P, S>(
+    proof: &Proof<E>, // Groth16 proof from arkworks
+    public: &[S],     // decimal public inputs, in circuit order
+    out_path: P,      // output path for JSON file
+) -> Result<ProofJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    S: AsRef<str>,  // accepts String, &str
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    let public = public
+        .iter()
+        .map(|s| parse_field_checked(s.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    export_proof::<E, _>(proof, public, out_path)
+}
+
+/// Same as `export_proof`, for any curve implementing `ExportCurve`.
+#[cfg(feature = "std")]
+pub fn export_proof_dyn<E, P>(
+    proof: &Proof<E>,                                 // Groth16 proof from arkworks
+    public: impl Into<PublicSignals<E::ScalarField>>, // public inputs, in circuit order
+    out_path: P,                                      // output path for JSON file
+) -> Result<ProofJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: ExportCurve,
+{
+    let json = proof_to_snarkjs_dyn::<E>(proof, &public.into())?;
+
+    write_json_pretty(out_path, &json)?;
 
     Ok(json)
 }
+
+/// Verify a Groth16 proof against `pvk`, then export it like `export_proof`.
+/// Nothing is written if verification fails: the error is
+/// `SnarkjsError::ProofDoesNotVerify`.
+///
+/// From a proving key, use `Groth16::<E>::process_vk(&pk.vk)` to get `pvk`.
+#[cfg(feature = "std")]
+pub fn export_proof_verified<E, P>(
+    pvk: &PreparedVerifyingKey<E>, // processed verifying key
+    proof: &Proof<E>,              // Groth16 proof from arkworks
+    public: impl Into<PublicSignals<E::ScalarField>>, // public inputs, in circuit order
+    out_path: P,                   // output path for JSON file
+) -> Result<ProofJson, SnarkjsError>
+where
+    P: AsRef<Path>,        // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag, // curve type with snarkjs "NAME"
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    // A malformed key (e.g. wrong number of inputs) counts as a failed check
+    let public = public.into();
+    if !matches!(Groth16::<E>::verify_proof(pvk, proof, &public), Ok(true)) {
+        return Err(SnarkjsError::ProofDoesNotVerify);
+    }
+
+    export_proof::<E, _>(proof, public, out_path)
+}
+
+/// Convert public signals to decimal strings, checking each one against the
+/// scalar field modulus. `F` already pins the field, so this only catches
+/// non-canonical elements (e.g. built with `new_unchecked` from raw limbs),
+/// which would otherwise be written as a decimal at or above the modulus.
+/// `curve` names the curve in `SnarkjsError::ScalarOutOfRange`.
+pub(crate) fn public_to_dec<F: PrimeField>(
+    public: &[F],
+    curve: &'static str,
+) -> Result<Vec<String>, SnarkjsError> {
+    check_public_range(public, curve)?;
+    Ok(public.iter().map(f_to_dec).collect())
+}
+
+/// The range check of `public_to_dec` on its own, for encoders that write
+/// public inputs as bytes rather than decimal strings.
+pub(crate) fn check_public_range<F: PrimeField>(
+    public: &[F],
+    curve: &'static str,
+) -> Result<(), SnarkjsError> {
+    match public.iter().position(|f| f.into_bigint() >= F::MODULUS) {
+        Some(index) => Err(SnarkjsError::ScalarOutOfRange { index, curve }),
+        None => Ok(()),
+    }
+}
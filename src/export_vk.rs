@@ -1,18 +1,45 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use ark_ec::AffineRepr;
 use ark_ec::pairing::Pairing;
+#[cfg(feature = "std")]
+use ark_ff::BigInteger;
 use ark_ff::PrimeField;
+#[cfg(feature = "std")]
+use ark_groth16::ProvingKey;
 use ark_groth16::VerifyingKey;
+use core::fmt;
+#[cfg(feature = "std")]
+use num_bigint::BigUint;
 use serde::Serialize;
-use serde_json::to_writer_pretty;
-use std::{fs, fs::File, path::Path};
+#[cfg(feature = "std")]
+use serde::ser::SerializeSeq;
+#[cfg(feature = "std")]
+use std::path::Path;
 
-use crate::snarkjs_common::{AsFp2, CurveTag, g1_xy, g2_xyxy};
+use crate::error::SnarkjsError;
+#[cfg(feature = "std")]
+use crate::metadata::CRATE_VERSION;
+#[cfg(feature = "std")]
+use crate::options::ExportOptions;
+#[cfg(feature = "std")]
+use crate::public_signals::check_labels;
+use crate::snarkjs_common::{AsFp2, CurveId, CurveTag, g1_xy, g2_xyxy, ic_xy};
+#[cfg(feature = "std")]
+use crate::snarkjs_common::{gt_to_dec, write_json_pretty, write_json_pretty_with};
+#[cfg(feature = "std")]
+use crate::vk_hash::vk_hash_hex;
 
 /// JSON structure for Groth16 verifying key in `snarkjs`-compatible format.
+/// Fields are declared in the key order of snarkjs'
+/// `zkey export verificationkey` output.
 #[derive(Serialize)]
 pub struct VkJson {
-    pub protocol: &'static str, // always "groth16"
-    pub curve: &'static str,    // "bn128" or "bls12381"
-    pub n_public: usize,        // number of public inputs
+    pub protocol: &'static str, // "groth16" (see `ExportOptions`)
+    pub curve: CurveId,         // serialized as "bn128", "bls12381", ...
+    #[serde(rename = "nPublic")]
+    pub n_public: usize, // number of public inputs
 
     #[serde(rename = "vk_alpha_1")]
     pub vk_alpha_1: [String; 2], // G1 point
@@ -22,55 +49,300 @@ pub struct VkJson {
     pub vk_gamma_2: [[String; 2]; 2], // G2 point
     #[serde(rename = "vk_delta_2")]
     pub vk_delta_2: [[String; 2]; 2], // G2 point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vk_alphabeta_12: Option<[[[String; 2]; 3]; 2]>, // optional e(alpha, beta) (see `ExportOptions`)
     #[serde(rename = "IC")]
     pub ic: Vec<[String; 2]>, // list of G1 points for input coefficients
+
+    #[serde(rename = "vkHash", skip_serializing_if = "Option::is_none")]
+    pub vk_hash: Option<String>, // optional keccak256 of the key (see `vk_hash`)
+    #[serde(rename = "fieldModulus", skip_serializing_if = "Option::is_none")]
+    pub field_modulus: Option<String>, // optional scalar field modulus (see `ExportOptions`)
+    #[serde(rename = "arkSnarkjsVersion", skip_serializing_if = "Option::is_none")]
+    pub ark_snarkjs_version: Option<&'static str>, // optional crate version (see `ExportOptions`)
+}
+
+/// Pretty-printed JSON, as `serde_json::to_string_pretty` would produce.
+impl fmt::Display for VkJson {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => f.write_str(&json),
+            Err(_) => f.write_str("<unserializable VkJson>"),
+        }
+    }
 }
 
 /// Convert a Groth16 verifying key to `snarkjs` JSON format (in-memory only).
-pub fn vk_to_snarkjs<E>(vk: &VerifyingKey<E>, n_public: usize) -> VkJson
+///
+/// Fails with `SnarkjsError::UnsupportedG2` if the G2 coordinates are not in
+/// Fp2 (e.g. MNT6) and with `SnarkjsError::PointAtInfinity` if `alpha`,
+/// `beta`, `gamma` or `delta` is the identity. Identity `IC` entries (inputs
+/// no constraint uses) are written as `["0", "0"]`, which snarkjs reads as
+/// the point at infinity.
+pub fn vk_to_snarkjs<E>(vk: &VerifyingKey<E>, n_public: usize) -> Result<VkJson, SnarkjsError>
 where
     E: Pairing + CurveTag,
     <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
     <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
 {
-    VkJson {
+    // Sized up front: collecting through `Result` would grow it step by step
+    let mut ic = Vec::with_capacity(vk.gamma_abc_g1.len());
+    for p in &vk.gamma_abc_g1 {
+        ic.push(ic_xy(p)?);
+    }
+    Ok(VkJson {
         protocol: "groth16",
-        curve: E::NAME,
+        curve: E::ID,
         n_public,
-        vk_alpha_1: g1_xy(&vk.alpha_g1),
-        vk_beta_2: g2_xyxy(&vk.beta_g2),
-        vk_gamma_2: g2_xyxy(&vk.gamma_g2),
-        vk_delta_2: g2_xyxy(&vk.delta_g2),
-        ic: vk.gamma_abc_g1.iter().map(g1_xy).collect(),
-    }
+        vk_alpha_1: g1_xy(&vk.alpha_g1)?,
+        vk_beta_2: g2_xyxy(&vk.beta_g2)?,
+        vk_gamma_2: g2_xyxy(&vk.gamma_g2)?,
+        vk_delta_2: g2_xyxy(&vk.delta_g2)?,
+        ic,
+        vk_alphabeta_12: None,
+        vk_hash: None,
+        field_modulus: None,
+        ark_snarkjs_version: None,
+    })
+}
+
+/// Convert a Groth16 verifying key to a `snarkjs` JSON value, ready to be
+/// embedded in a larger JSON document.
+pub fn vk_to_snarkjs_value<E>(
+    vk: &VerifyingKey<E>,
+    n_public: usize,
+) -> Result<serde_json::Value, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
+{
+    Ok(serde_json::to_value(vk_to_snarkjs::<E>(vk, n_public)?)?)
+}
+
+/// Check that `export_vk` would succeed for this key without writing
+/// anything: runs the same conversion and drops the result, failing with the
+/// same errors (`PointAtInfinity`, `UnsupportedG2`).
+pub fn validate_vk<E>(vk: &VerifyingKey<E>, n_public: usize) -> Result<(), SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
+{
+    vk_to_snarkjs::<E>(vk, n_public).map(drop)
 }
 
 /// Export a Groth16 verifying key to `snarkjs` JSON format.
 /// Writes the file to `out_path` and returns the in-memory `VkJson`.
+#[cfg(feature = "std")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(n_public = n_public, curve = E::NAME))
+)]
 pub fn export_vk<E, P>(
     vk: &VerifyingKey<E>, // Groth16 verifying key from arkworks
     n_public: usize,      // number of public inputs
     out_path: P,          // output path for JSON file
-) -> std::io::Result<VkJson>
+) -> Result<VkJson, SnarkjsError>
 where
     P: AsRef<Path>, // accepts &str, String, Path, PathBuf
     E: Pairing + CurveTag,
     <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
     <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
 {
-    // Build JSON structure in memory
-    let json = vk_to_snarkjs::<E>(vk, n_public);
+    export_vk_with_options::<E, _>(vk, n_public, out_path, &ExportOptions::default())
+}
 
-    // Ensure parent directories exist
-    if let Some(parent) = out_path.as_ref().parent()
-        && !parent.as_os_str().is_empty()
-    {
-        fs::create_dir_all(parent)?;
+/// Same as `export_vk`, with the output formatted according to `options`.
+#[cfg(feature = "std")]
+pub fn export_vk_with_options<E, P>(
+    vk: &VerifyingKey<E>,    // Groth16 verifying key from arkworks
+    n_public: usize,         // number of public inputs
+    out_path: P,             // output path for JSON file
+    options: &ExportOptions, // output formatting
+) -> Result<VkJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
+{
+    // Build JSON structure in memory
+    let mut json = vk_to_snarkjs::<E>(vk, n_public)?;
+    json.protocol = options.protocol;
+    json.curve = options.curve_name_style.apply(json.curve);
+    if let Some((constant, inputs)) = json.ic.split_first() {
+        let inputs = options.reorder_public(inputs)?;
+        json.ic = core::iter::once(constant.clone()).chain(inputs).collect();
+    }
+    if options.field_modulus {
+        let modulus = E::ScalarField::MODULUS.to_bytes_be();
+        json.field_modulus = Some(BigUint::from_bytes_be(&modulus).to_str_radix(10));
+    }
+    if options.alphabeta {
+        let gt = E::pairing(vk.alpha_g1, vk.beta_g2);
+        json.vk_alphabeta_12 = Some(gt_to_dec(&gt.0)?);
+    }
+    if options.version_tag {
+        json.ark_snarkjs_version = Some(CRATE_VERSION);
     }
 
     // Write pretty-printed JSON to file
-    let file = File::create(out_path)?;
-    to_writer_pretty(file, &json).map_err(std::io::Error::other)?;
+    write_json_pretty_with(out_path, &json, options)?;
+
+    Ok(json)
+}
+
+/// Export a Groth16 verifying key like `export_vk`, plus a companion
+/// `ic_labels.json` next to it mapping each `IC` index to a name, e.g.
+/// `{ "0": "one", "1": "out", "2": "nullifier" }`, to trace which point
+/// belongs to which signal when wiring arkworks and circom together.
+///
+/// `labels[i]` names `IC[i]`, so `labels[0]` is the constant term (circom
+/// calls it `"one"`). Fails with `SnarkjsError::InvalidShape` before writing
+/// anything unless there is one distinct label per `IC` entry. The key
+/// itself is unchanged snarkjs output; `ic_labels.json` is not a snarkjs
+/// format.
+#[cfg(feature = "std")]
+pub fn export_vk_with_labels<E, P>(
+    vk: &VerifyingKey<E>, // Groth16 verifying key from arkworks
+    n_public: usize,      // number of public inputs
+    out_path: P,          // output path for JSON file
+    labels: &[&str],      // one label per IC entry
+) -> Result<VkJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
+{
+    check_labels(labels, vk.gamma_abc_g1.len())?;
+    let out_path = out_path.as_ref();
+    let json = export_vk::<E, _>(vk, n_public, out_path)?;
+    write_json_pretty(out_path.with_file_name("ic_labels.json"), &IcLabels(labels))?;
+    Ok(json)
+}
+
+/// `IC index -> name` object written by `export_vk_with_labels`, in index
+/// order.
+#[cfg(feature = "std")]
+struct IcLabels<'a>(&'a [&'a str]);
+
+#[cfg(feature = "std")]
+impl Serialize for IcLabels<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.0
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (i.to_string(), name)),
+        )
+    }
+}
+
+/// Export the verifying key embedded in a Groth16 proving key (`pk.vk`), for
+/// callers that only keep the proving key around. Same output as `export_vk`.
+#[cfg(feature = "std")]
+pub fn export_vk_from_pk<E, P>(
+    pk: &ProvingKey<E>, // Groth16 proving key from arkworks
+    n_public: usize,    // number of public inputs
+    out_path: P,        // output path for JSON file
+) -> Result<VkJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
+{
+    export_vk::<E, _>(&pk.vk, n_public, out_path)
+}
+
+/// `VkJson` layout with `IC` converted point by point during serialization.
+#[cfg(feature = "std")]
+#[derive(Serialize)]
+#[serde(bound = "")]
+struct VkStream<'a, G: AffineRepr>
+where
+    G::BaseField: PrimeField,
+{
+    protocol: &'static str,
+    curve: CurveId,
+    #[serde(rename = "nPublic")]
+    n_public: usize,
+    vk_alpha_1: [String; 2],
+    vk_beta_2: [[String; 2]; 2],
+    vk_gamma_2: [[String; 2]; 2],
+    vk_delta_2: [[String; 2]; 2],
+    #[serde(rename = "IC")]
+    ic: IcSeq<'a, G>,
+}
+
+/// `IC` points, written as `[x, y]` decimal pairs without an intermediate `Vec`.
+#[cfg(feature = "std")]
+struct IcSeq<'a, G>(&'a [G]);
+
+#[cfg(feature = "std")]
+impl<G: AffineRepr> Serialize for IcSeq<'_, G>
+where
+    G::BaseField: PrimeField,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for p in self.0 {
+            seq.serialize_element(&ic_xy(p).map_err(serde::ser::Error::custom)?)?;
+        }
+        seq.end()
+    }
+}
+
+/// Export a Groth16 verifying key like `export_vk`, but convert the `IC`
+/// points one at a time while writing instead of collecting them into a
+/// `VkJson` first. The file is identical; peak memory no longer grows with
+/// the decimal form of `IC`, which matters for keys with millions of inputs.
+#[cfg(feature = "std")]
+pub fn export_vk_streaming<E, P>(
+    vk: &VerifyingKey<E>, // Groth16 verifying key from arkworks
+    n_public: usize,      // number of public inputs
+    out_path: P,          // output path for JSON file
+) -> Result<(), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
+{
+    let json = VkStream {
+        protocol: "groth16",
+        curve: E::ID,
+        n_public,
+        vk_alpha_1: g1_xy(&vk.alpha_g1)?,
+        vk_beta_2: g2_xyxy(&vk.beta_g2)?,
+        vk_gamma_2: g2_xyxy(&vk.gamma_g2)?,
+        vk_delta_2: g2_xyxy(&vk.delta_g2)?,
+        ic: IcSeq(&vk.gamma_abc_g1),
+    };
+    write_json_pretty(out_path, &json)
+}
+
+/// Export a Groth16 verifying key to `snarkjs` JSON format with an extra
+/// `"vkHash"` field holding `vk_hash_hex` of the key.
+/// Writes the file to `out_path` and returns the in-memory `VkJson`.
+#[cfg(feature = "std")]
+pub fn export_vk_with_hash<E, P>(
+    vk: &VerifyingKey<E>, // Groth16 verifying key from arkworks
+    n_public: usize,      // number of public inputs
+    out_path: P,          // output path for JSON file
+) -> Result<VkJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
+{
+    let mut json = vk_to_snarkjs::<E>(vk, n_public)?;
+    json.vk_hash = Some(vk_hash_hex::<E>(vk, n_public)?);
+
+    write_json_pretty(out_path, &json)?;
 
     Ok(json)
 }
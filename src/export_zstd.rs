@@ -0,0 +1,104 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, VerifyingKey};
+use serde::Serialize;
+use std::{fs::File, io::Read, path::Path};
+
+use crate::error::SnarkjsError;
+use crate::export_proof::{ProofJson, proof_to_snarkjs};
+use crate::export_vk::{VkJson, vk_to_snarkjs};
+use crate::import_proof::import_proof_from_str;
+use crate::import_vk::import_vk_from_str;
+use crate::options::ExportOptions;
+use crate::public_signals::PublicSignals;
+use crate::snarkjs_common::{AsFp2, CurveTag, FromXy, create_parent_dir, with_path, write_json_to};
+
+/// Same as `export_proof`, with the JSON compressed by zstd (default level)
+/// as it is written. By convention `out_path` ends in `.json.zst`.
+pub fn export_proof_zst<E, P>(
+    proof: &Proof<E>,                                 // Groth16 proof from arkworks
+    public: impl Into<PublicSignals<E::ScalarField>>, // public inputs, in circuit order
+    out_path: P,                                      // output path for .json.zst file
+) -> Result<ProofJson, SnarkjsError>
+where
+    P: AsRef<Path>,        // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag, // curve type with snarkjs "NAME"
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    let json = proof_to_snarkjs::<E>(proof, &public.into())?;
+    write_zst(out_path.as_ref(), &json)?;
+    Ok(json)
+}
+
+/// Same as `export_vk`, with the JSON compressed by zstd (default level) as
+/// it is written. By convention `out_path` ends in `.json.zst`.
+pub fn export_vk_zst<E, P>(
+    vk: &VerifyingKey<E>, // Groth16 verifying key from arkworks
+    n_public: usize,      // number of public inputs
+    out_path: P,          // output path for .json.zst file
+) -> Result<VkJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let json = vk_to_snarkjs::<E>(vk, n_public)?;
+    write_zst(out_path.as_ref(), &json)?;
+    Ok(json)
+}
+
+/// Import a proof written by `export_proof_zst` (or any zstd-compressed
+/// snarkjs `proof.json`), with the same checks as `import_proof`.
+pub fn import_proof_zst<E, P>(path: P) -> Result<(Proof<E>, Vec<E::ScalarField>), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_proof_from_str::<E>(&read_zst(path.as_ref())?)
+}
+
+/// Import a verifying key written by `export_vk_zst` (or any
+/// zstd-compressed snarkjs `verification_key.json`), with the same checks
+/// as `import_vk`.
+pub fn import_vk_zst<E, P>(path: P) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_vk_from_str::<E>(&read_zst(path.as_ref())?)
+}
+
+/// Serialize `value` through a zstd encoder into `out_path`, creating
+/// parent directories as needed.
+fn write_zst<T: Serialize>(out_path: &Path, value: &T) -> Result<(), SnarkjsError> {
+    create_parent_dir(out_path)?;
+    let write = || -> Result<(), SnarkjsError> {
+        let encoder = zstd::Encoder::new(File::create(out_path)?, 0)?;
+        write_json_to(encoder, value, &ExportOptions::default())?.finish()?;
+        Ok(())
+    };
+    write().map_err(|e| with_path(e, out_path))
+}
+
+/// Decompress `path` into a string.
+fn read_zst(path: &Path) -> Result<String, SnarkjsError> {
+    let read = || -> Result<String, SnarkjsError> {
+        let mut text = String::new();
+        zstd::Decoder::new(File::open(path)?)?.read_to_string(&mut text)?;
+        Ok(text)
+    };
+    read().map_err(|e| with_path(e, path))
+}
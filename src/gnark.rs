@@ -0,0 +1,213 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, ProvingKey};
+use serde::{Serialize, Serializer};
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use crate::error::SnarkjsError;
+#[cfg(feature = "std")]
+use crate::snarkjs_common::write_json_pretty;
+use crate::snarkjs_common::{AsFp2, g1_xy, g2_xyxy};
+
+/// Field element as gnark-crypto writes it: a JSON number when it has at most
+/// 15 digits, a decimal string otherwise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GnarkElement(pub String);
+
+impl Serialize for GnarkElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0.parse::<u64>() {
+            Ok(n) if self.0.len() <= 15 => serializer.serialize_u64(n),
+            _ => serializer.serialize_str(&self.0),
+        }
+    }
+}
+
+/// gnark `G1Affine`: `{"X": .., "Y": ..}`, with the point at infinity as `(0, 0)`.
+#[derive(Serialize)]
+pub struct GnarkG1 {
+    #[serde(rename = "X")]
+    pub x: GnarkElement,
+    #[serde(rename = "Y")]
+    pub y: GnarkElement,
+}
+
+/// gnark `E2`: `{"A0": c0, "A1": c1}`.
+#[derive(Serialize)]
+pub struct GnarkE2 {
+    #[serde(rename = "A0")]
+    pub a0: GnarkElement,
+    #[serde(rename = "A1")]
+    pub a1: GnarkElement,
+}
+
+/// gnark `G2Affine`: `{"X": E2, "Y": E2}`.
+#[derive(Serialize)]
+pub struct GnarkG2 {
+    #[serde(rename = "X")]
+    pub x: GnarkE2,
+    #[serde(rename = "Y")]
+    pub y: GnarkE2,
+}
+
+/// JSON structure of a gnark Groth16 proof (`encoding/json` of `groth16.Proof`).
+#[derive(Serialize)]
+#[allow(non_snake_case)] // keys mirror the gnark field names
+pub struct GnarkProofJson {
+    pub Ar: GnarkG1,               // arkworks `a`
+    pub Krs: GnarkG1,              // arkworks `c`
+    pub Bs: GnarkG2,               // arkworks `b`
+    pub Commitments: Vec<GnarkG1>, // always empty (no commitments in arkworks)
+    pub CommitmentPok: GnarkG1,    // always the point at infinity
+}
+
+/// `G1` part of a gnark verifying key.
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+pub struct GnarkVkG1 {
+    pub Alpha: GnarkG1,
+    pub Beta: GnarkG1,
+    pub Delta: GnarkG1,
+    pub K: Vec<GnarkG1>, // arkworks `gamma_abc_g1`
+}
+
+/// `G2` part of a gnark verifying key.
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+pub struct GnarkVkG2 {
+    pub Beta: GnarkG2,
+    pub Delta: GnarkG2,
+    pub Gamma: GnarkG2,
+}
+
+/// JSON structure of a gnark Groth16 verifying key
+/// (`encoding/json` of `groth16.VerifyingKey`).
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+pub struct GnarkVkJson {
+    pub G1: GnarkVkG1,
+    pub G2: GnarkVkG2,
+    pub PublicAndCommitmentCommitted: Vec<Vec<usize>>, // always empty
+    pub CommitmentKeys: Vec<GnarkG2>,                  // always empty
+}
+
+fn gnark_g1<G>(p: &G) -> Result<GnarkG1, SnarkjsError>
+where
+    G: AffineRepr,
+    G::BaseField: PrimeField,
+{
+    let [x, y] = if p.is_zero() {
+        ["0".into(), "0".into()]
+    } else {
+        g1_xy(p)?
+    };
+    Ok(GnarkG1 {
+        x: GnarkElement(x),
+        y: GnarkElement(y),
+    })
+}
+
+fn gnark_g2<G>(p: &G) -> Result<GnarkG2, SnarkjsError>
+where
+    G: AffineRepr,
+    G::BaseField: AsFp2,
+{
+    let [[x0, x1], [y0, y1]] = g2_xyxy(p)?;
+    Ok(GnarkG2 {
+        x: GnarkE2 {
+            a0: GnarkElement(x0),
+            a1: GnarkElement(x1),
+        },
+        y: GnarkE2 {
+            a0: GnarkElement(y0),
+            a1: GnarkElement(y1),
+        },
+    })
+}
+
+/// Convert a Groth16 proof to gnark's JSON layout (in-memory only).
+pub fn gnark_proof<E>(proof: &Proof<E>) -> Result<GnarkProofJson, SnarkjsError>
+where
+    E: Pairing,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    Ok(GnarkProofJson {
+        Ar: gnark_g1(&proof.a)?,
+        Krs: gnark_g1(&proof.c)?,
+        Bs: gnark_g2(&proof.b)?,
+        Commitments: Vec::new(),
+        CommitmentPok: gnark_g1(&E::G1Affine::zero())?,
+    })
+}
+
+/// Convert a Groth16 verifying key to gnark's JSON layout (in-memory only).
+///
+/// gnark's key also holds `[β]₁` and `[δ]₁`, which arkworks keeps in the
+/// proving key, hence `pk` rather than `vk`.
+pub fn gnark_vk<E>(pk: &ProvingKey<E>) -> Result<GnarkVkJson, SnarkjsError>
+where
+    E: Pairing,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let vk = &pk.vk;
+    Ok(GnarkVkJson {
+        G1: GnarkVkG1 {
+            Alpha: gnark_g1(&vk.alpha_g1)?,
+            Beta: gnark_g1(&pk.beta_g1)?,
+            Delta: gnark_g1(&pk.delta_g1)?,
+            K: vk
+                .gamma_abc_g1
+                .iter()
+                .map(gnark_g1)
+                .collect::<Result<_, _>>()?,
+        },
+        G2: GnarkVkG2 {
+            Beta: gnark_g2(&vk.beta_g2)?,
+            Delta: gnark_g2(&vk.delta_g2)?,
+            Gamma: gnark_g2(&vk.gamma_g2)?,
+        },
+        PublicAndCommitmentCommitted: Vec::new(),
+        CommitmentKeys: Vec::new(),
+    })
+}
+
+/// Export a Groth16 proof in gnark's JSON layout.
+/// Writes the file to `out_path` and returns the in-memory `GnarkProofJson`.
+#[cfg(feature = "std")]
+pub fn export_gnark_proof<E, P>(
+    proof: &Proof<E>, // Groth16 proof from arkworks
+    out_path: P,      // output path for JSON file
+) -> Result<GnarkProofJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let json = gnark_proof::<E>(proof)?;
+    write_json_pretty(out_path, &json)?;
+    Ok(json)
+}
+
+/// Export the verifying key of `pk` in gnark's JSON layout.
+/// Writes the file to `out_path` and returns the in-memory `GnarkVkJson`.
+#[cfg(feature = "std")]
+pub fn export_gnark_vk<E, P>(
+    pk: &ProvingKey<E>, // Groth16 proving key from arkworks (see `gnark_vk`)
+    out_path: P,        // output path for JSON file
+) -> Result<GnarkVkJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let json = gnark_vk::<E>(pk)?;
+    write_json_pretty(out_path, &json)?;
+    Ok(json)
+}
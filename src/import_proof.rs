@@ -0,0 +1,159 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::Proof;
+use serde::Deserialize;
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+use crate::error::SnarkjsError;
+#[cfg(feature = "std")]
+use crate::metadata::{FileVersion, file_version_from_str};
+use crate::snarkjs_common::{
+    AsFp2, CurveId, CurveTag, FromXy, g1_from_coords, g2_from_coords, parse_signal_checked,
+};
+#[cfg(feature = "std")]
+use crate::vk_hash::check_sha256;
+
+/// JSON structure of a `snarkjs` Groth16 proof as read from disk.
+/// Point arrays are kept as vectors so malformed files yield typed errors.
+#[derive(Deserialize)]
+pub(crate) struct ProofFile {
+    protocol: Option<String>,
+    curve: Option<String>,
+    pi_a: Vec<String>,      // G1 point [x, y, z]
+    pi_b: Vec<Vec<String>>, // G2 point [[x0, x1], [y0, y1], [z0, z1]]
+    pi_c: Vec<String>,      // G1 point [x, y, z]
+    #[serde(rename = "publicSignals")]
+    public_signals: Option<Vec<String>>, // optional, snarkjs keeps them in public.json
+}
+
+impl ProofFile {
+    /// Number of entries in `publicSignals`, `None` if the file has no such key.
+    pub(crate) fn n_public_signals(&self) -> Option<usize> {
+        self.public_signals.as_ref().map(Vec::len)
+    }
+}
+
+/// Import a `snarkjs` Groth16 proof from `path`.
+/// Returns the arkworks proof and the public signals stored in the file
+/// (empty if the file has no `publicSignals` key). Signals may be decimal or
+/// `0x`-prefixed hex; the two can be mixed in one file.
+///
+/// Points are read as Jacobian `[x, y, z]`: `z = 1` is the usual affine form,
+/// `z = 0` is the point at infinity, any other `z` is normalized to affine.
+/// Affine `[x, y]` points are accepted as well.
+#[cfg(feature = "std")]
+pub fn import_proof<E, P>(path: P) -> Result<(Proof<E>, Vec<E::ScalarField>), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_proof_from_str::<E>(&fs::read_to_string(path)?)
+}
+
+/// Import a `snarkjs` Groth16 proof from `path` after checking the file's
+/// sha256 against `expected_sha256` (hex, optional `0x`). A mismatch fails
+/// with `SnarkjsError::ChecksumMismatch` before any parsing.
+#[cfg(feature = "std")]
+pub fn import_proof_with_checksum<E, P>(
+    path: P,
+    expected_sha256: &str,
+) -> Result<(Proof<E>, Vec<E::ScalarField>), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let bytes = fs::read(path)?;
+    check_sha256(&bytes, expected_sha256)?;
+    import_proof_from_str::<E>(
+        core::str::from_utf8(&bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?,
+    )
+}
+
+/// Import a `snarkjs` Groth16 proof from `path` like `import_proof`,
+/// together with the `FileVersion` recorded in it (see
+/// `import_vk_with_version`).
+#[cfg(feature = "std")]
+#[allow(clippy::type_complexity)]
+pub fn import_proof_with_version<E, P>(
+    path: P,
+) -> Result<(Proof<E>, Vec<E::ScalarField>, FileVersion), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let text = fs::read_to_string(path)?;
+    let (proof, public) = import_proof_from_str::<E>(&text)?;
+    Ok((proof, public, file_version_from_str(&text)?))
+}
+
+/// Import a `snarkjs` Groth16 proof from its JSON text (same rules as
+/// `import_proof`), for callers that receive the proof without a filesystem.
+pub fn import_proof_from_str<E>(s: &str) -> Result<(Proof<E>, Vec<E::ScalarField>), SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let file: ProofFile = serde_json::from_str(s)?;
+    proof_from_file::<E>(&file)
+}
+
+pub(crate) fn proof_from_file<E>(
+    file: &ProofFile,
+) -> Result<(Proof<E>, Vec<E::ScalarField>), SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    if let Some(protocol) = &file.protocol
+        && protocol != "groth16"
+    {
+        return Err(SnarkjsError::ProtocolMismatch(protocol.clone()));
+    }
+    if let Some(curve) = &file.curve
+        && curve.parse::<CurveId>().ok() != Some(E::ID)
+    {
+        return Err(SnarkjsError::CurveMismatch {
+            expected: E::NAME,
+            found: curve.clone(),
+        });
+    }
+
+    let proof = Proof {
+        a: g1_from_coords(&file.pi_a, "pi_a")?,
+        b: g2_from_coords(&file.pi_b, "pi_b")?,
+        c: g1_from_coords(&file.pi_c, "pi_c")?,
+    };
+    let public = parse_public_signals(file.public_signals.as_deref().unwrap_or_default())?;
+
+    Ok((proof, public))
+}
+
+/// Parse public signals (decimal, or `0x`-prefixed hex) into scalar field
+/// elements.
+pub(crate) fn parse_public_signals<F: PrimeField>(
+    signals: &[String],
+) -> Result<Vec<F>, SnarkjsError> {
+    signals.iter().map(|s| parse_signal_checked(s)).collect()
+}
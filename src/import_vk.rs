@@ -0,0 +1,721 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::{Field, One, PrimeField};
+use ark_groth16::{PreparedVerifyingKey, VerifyingKey, prepare_verifying_key};
+#[cfg(feature = "std")]
+use core::{fmt, marker::PhantomData};
+use serde::Deserialize;
+#[cfg(feature = "std")]
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+use crate::error::SnarkjsError;
+#[cfg(feature = "std")]
+use crate::metadata::{FileVersion, file_version_from_str};
+use crate::snarkjs_common::{
+    AsFp2, CurveId, CurveTag, FromXy, g1_from_coords, g2_from_coords, gt_from_dec,
+};
+#[cfg(feature = "std")]
+use crate::vk_hash::check_sha256;
+
+/// Largest `nPublic` the importers accept by default (2^24, an `IC` of a
+/// few gigabytes of JSON). Keys above it fail with
+/// `SnarkjsError::TooManyPublicInputs` before any point is parsed; use
+/// `import_vk_with_limit` / `import_vk_from_str_with_limit` to raise or
+/// lower it.
+pub const MAX_N_PUBLIC: usize = 1 << 24;
+
+/// JSON structure of a `snarkjs` Groth16 verifying key as read from disk.
+/// Point arrays are kept as vectors so malformed files yield typed errors.
+#[derive(Deserialize)]
+pub(crate) struct VkFile {
+    protocol: Option<String>,
+    curve: Option<String>,
+    #[serde(rename = "nPublic", alias = "n_public")]
+    n_public: usize,
+    vk_alpha_1: Vec<String>,
+    vk_beta_2: Vec<Vec<String>>,
+    vk_gamma_2: Vec<Vec<String>>,
+    vk_delta_2: Vec<Vec<String>>,
+    vk_alphabeta_12: Option<Vec<Vec<Vec<String>>>>,
+    #[serde(rename = "IC")]
+    ic: Vec<Vec<String>>,
+}
+
+/// Import a `snarkjs` Groth16 verifying key from `path`.
+///
+/// Accepts both affine (`[x, y]`) and Jacobian (`[x, y, z]`) points, and
+/// checks that `IC` holds exactly `nPublic + 1` points.
+#[cfg(feature = "std")]
+pub fn import_vk<E, P>(path: P) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_vk_from_str::<E>(&fs::read_to_string(path)?)
+}
+
+/// Import a `snarkjs` Groth16 verifying key from `path` after checking the
+/// file's sha256 against `expected_sha256` (hex, optional `0x`). A mismatch
+/// fails with `SnarkjsError::ChecksumMismatch` before any parsing.
+#[cfg(feature = "std")]
+pub fn import_vk_with_checksum<E, P>(
+    path: P,
+    expected_sha256: &str,
+) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let bytes = fs::read(path)?;
+    check_sha256(&bytes, expected_sha256)?;
+    import_vk_from_str::<E>(
+        core::str::from_utf8(&bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?,
+    )
+}
+
+/// Import a `snarkjs` Groth16 verifying key from `path` like `import_vk`,
+/// together with the `FileVersion` recorded in it. Check `newer` to catch
+/// files from a newer release of this crate (version skew between tools).
+#[cfg(feature = "std")]
+pub fn import_vk_with_version<E, P>(path: P) -> Result<(VerifyingKey<E>, FileVersion), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let text = fs::read_to_string(path)?;
+    Ok((
+        import_vk_from_str::<E>(&text)?,
+        file_version_from_str(&text)?,
+    ))
+}
+
+/// Import a `snarkjs` Groth16 verifying key from its JSON text (same checks
+/// as `import_vk`).
+pub fn import_vk_from_str<E>(s: &str) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_vk_from_str_with_limit::<E>(s, MAX_N_PUBLIC)
+}
+
+/// Same as `import_vk`, accepting keys with at most `max_n_public` public
+/// inputs instead of `MAX_N_PUBLIC`.
+#[cfg(feature = "std")]
+pub fn import_vk_with_limit<E, P>(
+    path: P,
+    max_n_public: usize,
+) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_vk_from_str_with_limit::<E>(&fs::read_to_string(path)?, max_n_public)
+}
+
+/// Same as `import_vk_from_str`, accepting keys with at most
+/// `max_n_public` public inputs instead of `MAX_N_PUBLIC`.
+pub fn import_vk_from_str_with_limit<E>(
+    s: &str,
+    max_n_public: usize,
+) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let file: VkFile = serde_json::from_str(s)?;
+    vk_from_file_with_limit::<E>(&file, max_n_public)
+}
+
+/// Import a `snarkjs` Groth16 verifying key from `path` like `import_vk`,
+/// and also recompute `e(vk_alpha_1, vk_beta_2)` and compare it with the
+/// file's `vk_alphabeta_12`.
+///
+/// Recommended for keys from untrusted sources: a tampered or inconsistent
+/// key fails with `SnarkjsError::AlphaBetaMismatch`, and a key without
+/// `vk_alphabeta_12` with `SnarkjsError::InvalidShape`. Costs one pairing,
+/// which is why `import_vk` skips it.
+#[cfg(feature = "std")]
+pub fn import_vk_verified<E, P>(path: P) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_vk_from_str_verified::<E>(&fs::read_to_string(path)?)
+}
+
+/// Import a `snarkjs` Groth16 verifying key from its JSON text with the
+/// `vk_alphabeta_12` check of `import_vk_verified`.
+pub fn import_vk_from_str_verified<E>(s: &str) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let file: VkFile = serde_json::from_str(s)?;
+    let (vk, _) = vk_from_file_verified::<E>(&file)?;
+    Ok(vk)
+}
+
+/// Parse `file` and check its `vk_alphabeta_12` against a freshly computed
+/// `e(vk_alpha_1, vk_beta_2)`, which is returned along with the key.
+fn vk_from_file_verified<E>(
+    file: &VkFile,
+) -> Result<(VerifyingKey<E>, E::TargetField), SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let vk = vk_from_file::<E>(file)?;
+
+    let field = "vk_alphabeta_12";
+    let stored = file
+        .vk_alphabeta_12
+        .as_deref()
+        .ok_or(SnarkjsError::InvalidShape { field })?;
+    let alpha_g1_beta_g2 = E::pairing(vk.alpha_g1, vk.beta_g2).0;
+    if gt_from_dec::<E::TargetField>(stored, field)? != alpha_g1_beta_g2 {
+        return Err(SnarkjsError::AlphaBetaMismatch);
+    }
+
+    Ok((vk, alpha_g1_beta_g2))
+}
+
+/// `PreparedVerifyingKey` of `vk` with a known `e(alpha, beta)`.
+fn prepared<E: Pairing>(
+    vk: VerifyingKey<E>,
+    alpha_g1_beta_g2: E::TargetField,
+) -> PreparedVerifyingKey<E> {
+    PreparedVerifyingKey {
+        gamma_g2_neg_pc: E::G2Prepared::from(-vk.gamma_g2.into_group()),
+        delta_g2_neg_pc: E::G2Prepared::from(-vk.delta_g2.into_group()),
+        alpha_g1_beta_g2,
+        vk,
+    }
+}
+
+/// Import a `snarkjs` Groth16 verifying key from its JSON text and prepare it
+/// for verification (same checks as `import_vk`).
+///
+/// `e(alpha, beta)` is always computed from the key's points (one pairing,
+/// like `prepare_verifying_key`). If the key also carries `vk_alphabeta_12`,
+/// it must equal that pairing, or the import fails with
+/// `SnarkjsError::AlphaBetaMismatch`.
+pub fn import_pvk_from_str<E>(s: &str) -> Result<PreparedVerifyingKey<E>, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let file: VkFile = serde_json::from_str(s)?;
+    let vk = vk_from_file::<E>(&file)?;
+    let stored = file
+        .vk_alphabeta_12
+        .as_deref()
+        .map(|s| gt_from_dec::<E::TargetField>(s, "vk_alphabeta_12"))
+        .transpose()?;
+    let pvk = prepare_verifying_key(&vk);
+    if stored.is_some_and(|gt| gt != pvk.alpha_g1_beta_g2) {
+        return Err(SnarkjsError::AlphaBetaMismatch);
+    }
+    Ok(pvk)
+}
+
+/// Import a `snarkjs` Groth16 verifying key from its JSON text and prepare it
+/// for verification, taking a stored `vk_alphabeta_12` as `e(alpha, beta)`
+/// instead of computing the pairing (without one, the pairing is computed).
+///
+/// The stored value must lie in the order-r subgroup of the target group, or
+/// the import fails with `SnarkjsError::AlphaBetaMismatch`, but it is **not**
+/// checked against `e(alpha, beta)`: proofs are then verified against
+/// whatever the file holds. Only for keys you produced yourself; use
+/// `import_pvk_from_str` for anything else.
+pub fn import_pvk_from_str_trusting_alphabeta<E>(
+    s: &str,
+) -> Result<PreparedVerifyingKey<E>, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let file: VkFile = serde_json::from_str(s)?;
+    let vk = vk_from_file::<E>(&file)?;
+    let Some(stored) = file.vk_alphabeta_12.as_deref() else {
+        return Ok(prepare_verifying_key(&vk));
+    };
+
+    // Every pairing output has order r; anything else (zero included) cannot
+    // be e(alpha, beta) of any key
+    let alpha_g1_beta_g2 = gt_from_dec::<E::TargetField>(stored, "vk_alphabeta_12")?;
+    if !alpha_g1_beta_g2.pow(E::ScalarField::MODULUS).is_one() {
+        return Err(SnarkjsError::AlphaBetaMismatch);
+    }
+    Ok(prepared(vk, alpha_g1_beta_g2))
+}
+
+/// Import a `snarkjs` Groth16 verifying key from its JSON text with the
+/// `vk_alphabeta_12` check of `import_vk_verified`, and prepare it for
+/// verification with the checked value. Costs one pairing, like
+/// `prepare_verifying_key`. Unlike `import_pvk_from_str`, a key without
+/// `vk_alphabeta_12` is rejected.
+pub fn import_pvk_from_str_verified<E>(s: &str) -> Result<PreparedVerifyingKey<E>, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let file: VkFile = serde_json::from_str(s)?;
+    let (vk, alpha_g1_beta_g2) = vk_from_file_verified::<E>(&file)?;
+    Ok(prepared(vk, alpha_g1_beta_g2))
+}
+
+/// Import a `snarkjs` Groth16 verifying key from `path` and prepare it for
+/// verification (see `import_pvk_from_str`).
+#[cfg(feature = "std")]
+pub fn import_pvk<E, P>(path: P) -> Result<PreparedVerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_pvk_from_str::<E>(&fs::read_to_string(path)?)
+}
+
+/// Import a `snarkjs` Groth16 verifying key from `path` and prepare it for
+/// verification with its stored `vk_alphabeta_12` taken on trust (see
+/// `import_pvk_from_str_trusting_alphabeta`).
+#[cfg(feature = "std")]
+pub fn import_pvk_trusting_alphabeta<E, P>(path: P) -> Result<PreparedVerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_pvk_from_str_trusting_alphabeta::<E>(&fs::read_to_string(path)?)
+}
+
+/// Import a `snarkjs` Groth16 verifying key from `path` and prepare it for
+/// verification with the `vk_alphabeta_12` check of `import_vk_verified`
+/// (see `import_pvk_from_str_verified`).
+#[cfg(feature = "std")]
+pub fn import_pvk_verified<E, P>(path: P) -> Result<PreparedVerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_pvk_from_str_verified::<E>(&fs::read_to_string(path)?)
+}
+
+pub(crate) fn vk_from_file<E>(file: &VkFile) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    vk_from_file_with_limit::<E>(file, MAX_N_PUBLIC)
+}
+
+pub(crate) fn vk_from_file_with_limit<E>(
+    file: &VkFile,
+    max_n_public: usize,
+) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    check_vk_header::<E>(
+        file.protocol.as_deref(),
+        file.curve.as_deref(),
+        file.n_public,
+        file.ic.len(),
+        max_n_public,
+    )?;
+
+    let mut gamma_abc_g1 = Vec::with_capacity(file.ic.len());
+    for p in &file.ic {
+        gamma_abc_g1.push(g1_from_coords(p, "IC")?);
+    }
+    Ok(VerifyingKey {
+        alpha_g1: g1_from_coords(&file.vk_alpha_1, "vk_alpha_1")?,
+        beta_g2: g2_from_coords(&file.vk_beta_2, "vk_beta_2")?,
+        gamma_g2: g2_from_coords(&file.vk_gamma_2, "vk_gamma_2")?,
+        delta_g2: g2_from_coords(&file.vk_delta_2, "vk_delta_2")?,
+        gamma_abc_g1,
+    })
+}
+
+/// Checks shared by every verifying key importer, before any point is
+/// parsed: protocol, curve, the `nPublic` limit and the length of `IC`.
+fn check_vk_header<E: CurveTag>(
+    protocol: Option<&str>,
+    curve: Option<&str>,
+    n_public: usize,
+    ic_len: usize,
+    max_n_public: usize,
+) -> Result<(), SnarkjsError> {
+    check_protocol(protocol)?;
+    check_curve::<E>(curve)?;
+
+    if n_public > max_n_public {
+        return Err(SnarkjsError::TooManyPublicInputs {
+            n_public,
+            max: max_n_public,
+        });
+    }
+    // IC holds one point per public input plus the constant term
+    if ic_len.checked_sub(1) != Some(n_public) {
+        return Err(SnarkjsError::IcLengthMismatch { n_public, ic_len });
+    }
+    Ok(())
+}
+
+/// `protocol`, if present, must be `"groth16"`.
+fn check_protocol(protocol: Option<&str>) -> Result<(), SnarkjsError> {
+    if let Some(protocol) = protocol
+        && protocol != "groth16"
+    {
+        return Err(SnarkjsError::ProtocolMismatch(protocol.into()));
+    }
+    Ok(())
+}
+
+/// `curve`, if present, must name `E` (snarkjs or arkworks spelling).
+fn check_curve<E: CurveTag>(curve: Option<&str>) -> Result<(), SnarkjsError> {
+    if let Some(curve) = curve
+        && curve.parse::<CurveId>().ok() != Some(E::ID)
+    {
+        return Err(SnarkjsError::CurveMismatch {
+            expected: E::NAME,
+            found: curve.into(),
+        });
+    }
+    Ok(())
+}
+
+/// Import a `snarkjs` Groth16 verifying key from `path` like `import_vk`,
+/// reading the file through a buffer and converting each `IC` entry to a
+/// point as soon as it is parsed. Neither the whole text nor the decimal
+/// strings of `IC` are held in memory, only the points, which matters for
+/// keys of hundreds of megabytes. The counterpart of `export_vk_streaming`;
+/// same checks and errors as `import_vk`.
+#[cfg(feature = "std")]
+pub fn import_vk_streaming<E, P>(path: P) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    import_vk_streaming_with_limit::<E, _>(path, MAX_N_PUBLIC)
+}
+
+/// Same as `import_vk_streaming`, accepting keys with at most
+/// `max_n_public` public inputs instead of `MAX_N_PUBLIC`.
+///
+/// `protocol`, `curve` and `nPublic` are checked as soon as they are read,
+/// so a key for another curve or over the limit is rejected before any `IC`
+/// point that follows them is converted. Past `nPublic + 1` entries (or
+/// `max_n_public + 1` while `nPublic` has not been seen) the rest of `IC` is
+/// only counted, not converted, and the key fails with
+/// `SnarkjsError::IcLengthMismatch` or `SnarkjsError::TooManyPublicInputs`.
+#[cfg(feature = "std")]
+pub fn import_vk_streaming_with_limit<E, P>(
+    path: P,
+    max_n_public: usize,
+) -> Result<VerifyingKey<E>, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let reader = std::io::BufReader::new(fs::File::open(path)?);
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    // A check that fails mid-document stops the parser with a serde error
+    // and leaves the typed error here
+    let mut rejected = None;
+    let seed = VkStreamSeed::<E> {
+        max_n_public,
+        rejected: &mut rejected,
+        _curve: PhantomData,
+    };
+    let file = match seed.deserialize(&mut deserializer) {
+        Ok(file) => file,
+        Err(e) => return Err(rejected.unwrap_or(e.into())),
+    };
+    deserializer.end()?;
+
+    check_vk_header::<E>(
+        file.protocol.as_deref(),
+        file.curve.as_deref(),
+        file.n_public,
+        file.ic.len,
+        max_n_public,
+    )?;
+    Ok(VerifyingKey {
+        alpha_g1: g1_from_coords(&file.vk_alpha_1, "vk_alpha_1")?,
+        beta_g2: g2_from_coords(&file.vk_beta_2, "vk_beta_2")?,
+        gamma_g2: g2_from_coords(&file.vk_gamma_2, "vk_gamma_2")?,
+        delta_g2: g2_from_coords(&file.vk_delta_2, "vk_delta_2")?,
+        gamma_abc_g1: file.ic.points?,
+    })
+}
+
+/// A verifying key as read by `import_vk_streaming`: the fields of
+/// `VkFile`, with `IC` already converted to points.
+#[cfg(feature = "std")]
+struct VkStream<G> {
+    protocol: Option<String>,
+    curve: Option<String>,
+    n_public: usize,
+    vk_alpha_1: Vec<String>,
+    vk_beta_2: Vec<Vec<String>>,
+    vk_gamma_2: Vec<Vec<String>>,
+    vk_delta_2: Vec<Vec<String>>,
+    ic: IcPoints<G>,
+}
+
+/// `IC` converted while parsed. The first point that fails to convert is
+/// kept as a typed error (the remaining entries are still read, but not
+/// converted).
+#[cfg(feature = "std")]
+struct IcPoints<G> {
+    points: Result<Vec<G>, SnarkjsError>,
+    len: usize,
+}
+
+/// Reads a whole verifying key for curve `E` into a `VkStream`, checking
+/// the header entries as they come. A failed check is stored in `rejected`
+/// and aborts parsing.
+#[cfg(feature = "std")]
+struct VkStreamSeed<'a, E> {
+    max_n_public: usize,
+    rejected: &'a mut Option<SnarkjsError>,
+    _curve: PhantomData<E>,
+}
+
+/// Reads the `IC` array into `IcPoints`, converting at most `max_len`
+/// points. `n_public` is the key's `nPublic` if it came before `IC`.
+#[cfg(feature = "std")]
+struct IcSeed<'a, G> {
+    n_public: Option<usize>,
+    max_len: usize,
+    rejected: &'a mut Option<SnarkjsError>,
+    _point: PhantomData<G>,
+}
+
+/// Store `e` in `rejected` and return a serde error that stops the parser.
+#[cfg(feature = "std")]
+fn reject<D: de::Error>(rejected: &mut Option<SnarkjsError>, e: SnarkjsError) -> D {
+    let error = D::custom(&e);
+    *rejected = Some(e);
+    error
+}
+
+#[cfg(feature = "std")]
+impl<'de, E> DeserializeSeed<'de> for VkStreamSeed<'_, E>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+{
+    type Value = VkStream<E::G1Affine>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, E> Visitor<'de> for VkStreamSeed<'_, E>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+{
+    type Value = VkStream<E::G1Affine>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a snarkjs verification key object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut protocol, mut curve, mut n_public) = (None, None, None);
+        let (mut alpha, mut beta, mut gamma, mut delta) = (None, None, None, None);
+        let mut ic = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "protocol" => {
+                    protocol = map.next_value()?;
+                    check_protocol(protocol.as_deref()).map_err(|e| reject(self.rejected, e))?;
+                }
+                "curve" => {
+                    curve = map.next_value()?;
+                    check_curve::<E>(curve.as_deref()).map_err(|e| reject(self.rejected, e))?;
+                }
+                "nPublic" | "n_public" => {
+                    let n = map.next_value()?;
+                    if n > self.max_n_public {
+                        let e = SnarkjsError::TooManyPublicInputs {
+                            n_public: n,
+                            max: self.max_n_public,
+                        };
+                        return Err(reject(self.rejected, e));
+                    }
+                    n_public = Some(n);
+                }
+                "vk_alpha_1" => alpha = Some(map.next_value()?),
+                "vk_beta_2" => beta = Some(map.next_value()?),
+                "vk_gamma_2" => gamma = Some(map.next_value()?),
+                "vk_delta_2" => delta = Some(map.next_value()?),
+                "IC" => {
+                    // IC holds one point per public input plus the constant term
+                    let max_len = n_public.unwrap_or(self.max_n_public).saturating_add(1);
+                    ic = Some(map.next_value_seed(IcSeed {
+                        n_public,
+                        max_len,
+                        rejected: &mut *self.rejected,
+                        _point: PhantomData,
+                    })?)
+                }
+                // vk_alphabeta_12 and any extra entries are not needed
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(VkStream {
+            protocol,
+            curve,
+            n_public: n_public.ok_or_else(|| de::Error::missing_field("nPublic"))?,
+            vk_alpha_1: alpha.ok_or_else(|| de::Error::missing_field("vk_alpha_1"))?,
+            vk_beta_2: beta.ok_or_else(|| de::Error::missing_field("vk_beta_2"))?,
+            vk_gamma_2: gamma.ok_or_else(|| de::Error::missing_field("vk_gamma_2"))?,
+            vk_delta_2: delta.ok_or_else(|| de::Error::missing_field("vk_delta_2"))?,
+            ic: ic.ok_or_else(|| de::Error::missing_field("IC"))?,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, G> DeserializeSeed<'de> for IcSeed<'_, G>
+where
+    G: FromXy,
+    G::BaseField: PrimeField,
+{
+    type Value = IcPoints<G>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, G> Visitor<'de> for IcSeed<'_, G>
+where
+    G: FromXy,
+    G::BaseField: PrimeField,
+{
+    type Value = IcPoints<G>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an array of G1 points")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // A known nPublic is within the limit, so this reserves at most
+        // max_n_public + 1 points
+        let mut ic = IcPoints {
+            points: Ok(Vec::with_capacity(self.n_public.map_or(0, |n| n + 1))),
+            len: 0,
+        };
+        while let Some(coords) = seq.next_element::<Vec<String>>()? {
+            ic.len += 1;
+            if ic.len > self.max_len {
+                // Too long for any acceptable key: count the rest without
+                // keeping it
+                ic.points = Ok(Vec::new());
+                while seq.next_element::<IgnoredAny>()?.is_some() {
+                    ic.len += 1;
+                }
+                // Without nPublic yet, check_vk_header reports it once the
+                // whole key is read
+                if let Some(n_public) = self.n_public {
+                    let e = SnarkjsError::IcLengthMismatch {
+                        n_public,
+                        ic_len: ic.len,
+                    };
+                    return Err(reject(self.rejected, e));
+                }
+                break;
+            }
+            if let Ok(points) = &mut ic.points {
+                match g1_from_coords(&coords, "IC") {
+                    Ok(p) => points.push(p),
+                    Err(e) => ic.points = Err(e),
+                }
+            }
+        }
+        Ok(ic)
+    }
+}
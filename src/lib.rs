@@ -1,7 +1,142 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod bundle;
+#[cfg(feature = "std")]
+pub mod canonical;
+#[cfg(feature = "std")]
+pub mod diff;
+#[cfg(feature = "bls12_381")]
+pub mod eip2537;
+pub mod error;
+#[cfg(feature = "bn254")]
+pub mod eth_calldata;
+#[cfg(feature = "tokio")]
+pub mod export_async;
+#[cfg(feature = "std")]
+pub mod export_batch;
+#[cfg(feature = "fflonk")]
+pub mod export_fflonk;
+#[cfg(feature = "plonk")]
+pub mod export_plonk_proof;
 pub mod export_proof;
 pub mod export_vk;
+#[cfg(feature = "zstd")]
+pub mod export_zstd;
+pub mod gnark;
+pub mod import_proof;
+pub mod import_vk;
+#[cfg(feature = "std")]
+pub mod manifest;
+pub mod metadata;
+pub mod options;
+pub mod public_signals;
+#[cfg(feature = "bn254")]
+pub mod rapidsnark;
 pub mod snarkjs_common;
+pub mod verify;
+pub mod vk_hash;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod witness;
 
-pub use export_proof::{ProofJson, export_proof};
-pub use export_vk::{VkJson, export_vk, vk_to_snarkjs};
-pub use snarkjs_common::{AsFp2, CurveTag, f_to_dec, g1_xy, g2_xyxy};
+#[cfg(feature = "std")]
+pub use bundle::{BundleJson, export_bundle, import_bundle};
+#[cfg(feature = "flate2")]
+pub use bundle::{
+    MAX_BUNDLE_SIZE, export_bundle_tar_gz, import_bundle_tar_gz, import_bundle_tar_gz_with_limit,
+};
+#[cfg(feature = "std")]
+pub use canonical::{
+    export_proof_from_bytes, proof_from_bytes, read_canonical, read_proof_canonical,
+    read_public_canonical, read_vk_canonical, snarkjs_to_canonical,
+};
+#[cfg(feature = "std")]
+pub use diff::{Difference, diff_exports};
+#[cfg(feature = "bls12_381")]
+pub use eip2537::to_eip2537_bytes;
+pub use error::SnarkjsError;
+#[cfg(feature = "bn254")]
+pub use eth_calldata::{to_eth_calldata_bytes, to_eth_calldata_bytes_with_options};
+#[cfg(feature = "tokio")]
+pub use export_async::{export_proof_async, export_vk_async};
+#[cfg(feature = "std")]
+pub use export_batch::{
+    export_proof_batch, export_proofs_array, export_shared_public_batch, import_proofs_array,
+};
+#[cfg(feature = "fflonk")]
+pub use export_fflonk::{
+    FflonkProof, FflonkProofJson, FflonkVk, FflonkVkJson, fflonk_proof_to_snarkjs,
+    fflonk_vk_to_snarkjs,
+};
+#[cfg(all(feature = "fflonk", feature = "std"))]
+pub use export_fflonk::{export_fflonk_proof, export_fflonk_vk};
+#[cfg(all(feature = "plonk", feature = "std"))]
+pub use export_plonk_proof::export_plonk_proof;
+#[cfg(feature = "plonk")]
+pub use export_plonk_proof::{PlonkProof, PlonkProofJson, plonk_proof_to_snarkjs};
+pub use export_proof::{
+    ProofJson, proof_to_snarkjs, proof_to_snarkjs_dyn, proof_to_snarkjs_value, validate_proof,
+};
+#[cfg(feature = "std")]
+pub use export_proof::{
+    export_proof, export_proof_dyn, export_proof_from_strings, export_proof_verified,
+    export_proof_with_options,
+};
+pub use export_vk::{VkJson, validate_vk, vk_to_snarkjs, vk_to_snarkjs_value};
+#[cfg(feature = "std")]
+pub use export_vk::{
+    export_vk, export_vk_from_pk, export_vk_streaming, export_vk_with_hash, export_vk_with_labels,
+    export_vk_with_options,
+};
+#[cfg(feature = "zstd")]
+pub use export_zstd::{export_proof_zst, export_vk_zst, import_proof_zst, import_vk_zst};
+pub use gnark::{GnarkProofJson, GnarkVkJson, gnark_proof, gnark_vk};
+#[cfg(feature = "std")]
+pub use gnark::{export_gnark_proof, export_gnark_vk};
+pub use import_proof::import_proof_from_str;
+#[cfg(feature = "std")]
+pub use import_proof::{import_proof, import_proof_with_checksum, import_proof_with_version};
+pub use import_vk::{
+    MAX_N_PUBLIC, import_pvk_from_str, import_pvk_from_str_trusting_alphabeta,
+    import_pvk_from_str_verified, import_vk_from_str, import_vk_from_str_verified,
+    import_vk_from_str_with_limit,
+};
+#[cfg(feature = "std")]
+pub use import_vk::{
+    import_pvk, import_pvk_trusting_alphabeta, import_pvk_verified, import_vk, import_vk_streaming,
+    import_vk_streaming_with_limit, import_vk_verified, import_vk_with_checksum,
+    import_vk_with_limit, import_vk_with_version,
+};
+#[cfg(feature = "std")]
+pub use manifest::{ManifestFile, ManifestJson, export_verify_manifest};
+#[cfg(feature = "std")]
+pub use metadata::file_version;
+pub use metadata::{
+    CRATE_VERSION, FileVersion, VkMetadata, file_version_from_str, proof_size_estimate,
+    vk_metadata, vk_size_estimate,
+};
+pub use options::{CurveNameStyle, ExportOptions, ExportOptionsBuilder};
+#[cfg(feature = "std")]
+pub use public_signals::export_public_labeled;
+pub use public_signals::{PublicSignals, public_inputs_from_cs};
+#[cfg(all(feature = "bn254", feature = "std"))]
+pub use rapidsnark::export_rapidsnark;
+#[cfg(feature = "bn254")]
+pub use rapidsnark::{RapidsnarkProofJson, RapidsnarkVkJson, rapidsnark_proof, rapidsnark_vk};
+pub use snarkjs_common::{
+    AsFp2, CurveId, CurveTag, Endianness, ExportCurve, FromXy, G1Coords, curve_name_from_str,
+    dec_to_f, f_to_dec, f_to_hex, g1_from_dec, g1_xy, g2_from_compressed, g2_from_dec, g2_xyxy,
+    is_supported, parse_field_checked, supported_curves,
+};
+#[cfg(feature = "std")]
+pub use verify::verify_snarkjs;
+pub use verify::{VerifyResult, verify_detailed, verify_from_json, verify_snarkjs_from_str};
+pub use vk_hash::{vk_equal, vk_hash, vk_hash_hex, vk_json_equal};
+#[cfg(feature = "wasm")]
+pub use wasm::verify_bn254;
+#[cfg(feature = "std")]
+pub use witness::{export_witness, import_wtns};
+pub use witness::{witness_to_snarkjs, wtns_from_bytes};
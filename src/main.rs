@@ -0,0 +1,139 @@
+//! `ark-snarkjs` command line tool: converts arkworks-serialized Groth16
+//! artifacts (`CanonicalSerialize` `.bin` files) into snarkjs JSON.
+
+use std::{collections::HashMap, error::Error, process::ExitCode};
+
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use ark_groth16::{Groth16, Proof, VerifyingKey, prepare_verifying_key};
+use ark_serialize::CanonicalDeserialize;
+
+use ark_snarkjs::{
+    AsFp2, CurveId, CurveTag, export_proof, export_vk, is_supported, supported_curves,
+};
+
+const USAGE: &str = "\
+Usage:
+  ark-snarkjs export-proof --curve <bn254|bls12381> --proof <proof.bin> --public <public.bin> --out <proof.json>
+  ark-snarkjs export-vk    --curve <bn254|bls12381> --vk <vk.bin> --out <verification_key.json>
+  ark-snarkjs verify       --curve <bn254|bls12381> --vk <vk.bin> --proof <proof.bin> --public <public.bin>
+
+Inputs are arkworks CanonicalSerialize files (compressed or uncompressed);
+<public.bin> holds a serialized Vec of scalar field elements.";
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Vec<String>) -> CliResult<ExitCode> {
+    let Some((command, rest)) = args.split_first() else {
+        println!("{USAGE}");
+        return Ok(ExitCode::FAILURE);
+    };
+    if command == "-h" || command == "--help" {
+        println!("{USAGE}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let flags = parse_flags(rest)?;
+    let curve = flag(&flags, "curve")?;
+
+    // Accept the arkworks spellings as aliases of the snarkjs names
+    let curve = curve.parse::<CurveId>().map_or(curve, |id| id.name());
+    if !is_supported(curve) {
+        return Err(format!(
+            "unsupported curve {curve:?}, supported: {:?}",
+            supported_curves()
+        )
+        .into());
+    }
+
+    // Dispatch on the curve string to the matching monomorphization
+    match curve {
+        #[cfg(feature = "bn254")]
+        "bn128" => dispatch::<ark_bn254::Bn254>(command, &flags),
+        #[cfg(feature = "bls12_381")]
+        "bls12381" => dispatch::<ark_bls12_381::Bls12_381>(command, &flags),
+        #[cfg(feature = "mnt4_753")]
+        "mnt4753" => dispatch::<ark_mnt4_753::MNT4_753>(command, &flags),
+        #[cfg(feature = "mnt6_753")]
+        "mnt6753" => dispatch::<ark_mnt6_753::MNT6_753>(command, &flags),
+        other => unreachable!("curve {other:?} is supported but not dispatched"),
+    }
+}
+
+/// Parse `--key value` pairs into a map.
+fn parse_flags(args: &[String]) -> CliResult<HashMap<String, String>> {
+    let mut flags = HashMap::new();
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        let key = arg
+            .strip_prefix("--")
+            .ok_or_else(|| format!("unexpected argument {arg:?}\n\n{USAGE}"))?;
+        let value = it
+            .next()
+            .ok_or_else(|| format!("missing value for --{key}"))?;
+        flags.insert(key.to_string(), value.clone());
+    }
+    Ok(flags)
+}
+
+fn flag<'a>(flags: &'a HashMap<String, String>, key: &str) -> CliResult<&'a str> {
+    flags
+        .get(key)
+        .map(String::as_str)
+        .ok_or_else(|| format!("missing required flag --{key}\n\n{USAGE}").into())
+}
+
+fn dispatch<E>(command: &str, flags: &HashMap<String, String>) -> CliResult<ExitCode>
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as ark_ec::AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as ark_ec::AffineRepr>::BaseField: AsFp2,
+    E::ScalarField: PrimeField,
+{
+    match command {
+        "export-proof" => {
+            let proof: Proof<E> = read_canonical(flag(flags, "proof")?)?;
+            let public: Vec<E::ScalarField> = read_canonical(flag(flags, "public")?)?;
+            let out = flag(flags, "out")?;
+            export_proof::<E, _>(&proof, &public, out)?;
+            println!("Proof saved: {out}");
+        }
+        "export-vk" => {
+            let vk: VerifyingKey<E> = read_canonical(flag(flags, "vk")?)?;
+            let out = flag(flags, "out")?;
+            // IC holds one point per public input plus the constant term
+            let n_public = vk.gamma_abc_g1.len().saturating_sub(1);
+            export_vk::<E, _>(&vk, n_public, out)?;
+            println!("Verifying key saved: {out}");
+        }
+        "verify" => {
+            let vk: VerifyingKey<E> = read_canonical(flag(flags, "vk")?)?;
+            let proof: Proof<E> = read_canonical(flag(flags, "proof")?)?;
+            let public: Vec<E::ScalarField> = read_canonical(flag(flags, "public")?)?;
+            let pvk = prepare_verifying_key(&vk);
+            if Groth16::<E>::verify_proof(&pvk, &proof, &public)? {
+                println!("OK");
+            } else {
+                println!("INVALID");
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        other => return Err(format!("unknown command {other:?}\n\n{USAGE}").into()),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Read an arkworks-serialized value, naming the file in errors.
+fn read_canonical<T: CanonicalDeserialize>(path: &str) -> CliResult<T> {
+    ark_snarkjs::read_canonical(path).map_err(|e| format!("{path}: {e}").into())
+}
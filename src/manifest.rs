@@ -0,0 +1,94 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+use crate::error::SnarkjsError;
+use crate::export_proof::proof_to_snarkjs;
+use crate::export_vk::vk_to_snarkjs;
+use crate::public_signals::check_public_len;
+use crate::snarkjs_common::{AsFp2, CurveId, CurveTag, with_path};
+use crate::vk_hash::{sha256_hex, vk_hash_hex};
+
+/// `manifest.json` written by `export_verify_manifest`: what was produced and
+/// how to check it, so a generic verifier knows which files to load.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestJson {
+    pub protocol: String, // always "groth16"
+    pub curve: CurveId,
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    #[serde(rename = "vkHash")]
+    pub vk_hash: String, // `vk_hash_hex` of the verifying key
+    pub files: Vec<ManifestFile>,
+}
+
+/// One file listed in a `ManifestJson`, relative to the manifest's directory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestFile {
+    pub name: String,
+    pub sha256: String, // lowercase hex of the file bytes
+}
+
+/// Export `proof.json`, `public.json` and `verification_key.json` into
+/// `out_dir`, plus a `manifest.json` listing them with their sha256, the
+/// curve, `nPublic` and the key's `vkHash`. `n_public` is taken from
+/// `public.len()`, which must match `vk` or this fails with
+/// `SnarkjsError::PublicCountMismatch` before anything is written.
+/// Returns the in-memory `ManifestJson`.
+pub fn export_verify_manifest<E, P>(
+    proof: &Proof<E>,          // Groth16 proof from arkworks
+    public: &[E::ScalarField], // list of public inputs
+    vk: &VerifyingKey<E>,      // Groth16 verifying key from arkworks
+    out_dir: P,                // output directory for the four JSON files
+) -> Result<ManifestJson, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    check_public_len(vk, public.len())?;
+    let out_dir = out_dir.as_ref();
+    fs::create_dir_all(out_dir).map_err(|e| with_path(e.into(), out_dir))?;
+
+    let proof_json = proof_to_snarkjs::<E>(proof, public)?;
+    let vk_json = vk_to_snarkjs::<E>(vk, public.len())?;
+
+    // Hash exactly the bytes that end up on disk
+    let mut files = Vec::with_capacity(3);
+    for (name, bytes) in [
+        ("proof.json", serde_json::to_vec_pretty(&proof_json)?),
+        (
+            "public.json",
+            serde_json::to_vec_pretty(&proof_json.publicSignals)?,
+        ),
+        (
+            "verification_key.json",
+            serde_json::to_vec_pretty(&vk_json)?,
+        ),
+    ] {
+        let path = out_dir.join(name);
+        fs::write(&path, &bytes).map_err(|e| with_path(e.into(), &path))?;
+        files.push(ManifestFile {
+            name: name.into(),
+            sha256: sha256_hex(&bytes),
+        });
+    }
+
+    let manifest = ManifestJson {
+        protocol: "groth16".into(),
+        curve: E::ID,
+        n_public: public.len(),
+        vk_hash: vk_hash_hex::<E>(vk, public.len())?,
+        files,
+    };
+    let path = out_dir.join("manifest.json");
+    fs::write(&path, serde_json::to_vec_pretty(&manifest)?)
+        .map_err(|e| with_path(e.into(), &path))?;
+
+    Ok(manifest)
+}
@@ -0,0 +1,210 @@
+use alloc::string::String;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::VerifyingKey;
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+use crate::error::SnarkjsError;
+use crate::snarkjs_common::{CurveId, CurveTag};
+
+/// Version of this crate, as written to `"arkSnarkjsVersion"` when
+/// `ExportOptions::version_tag` is set.
+pub const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Summary of a Groth16 verifying key for logs and dashboards, built without
+/// converting any point to decimal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VkMetadata {
+    #[serde(rename = "nPublic")]
+    pub n_public: usize, // number of public inputs, `ic_len - 1`
+    #[serde(rename = "icLen")]
+    pub ic_len: usize, // number of IC points
+    pub curve: CurveId, // serialized as "bn128", "bls12381", ...
+}
+
+/// Summarize `vk`: `nPublic`, the number of `IC` points and the curve.
+/// `nPublic` is derived from `IC` (one point per public input plus the
+/// constant term), so a key with an empty `IC` reports 0.
+pub fn vk_metadata<E>(vk: &VerifyingKey<E>) -> VkMetadata
+where
+    E: Pairing + CurveTag,
+{
+    let ic_len = vk.gamma_abc_g1.len();
+    VkMetadata {
+        n_public: ic_len.saturating_sub(1),
+        ic_len,
+        curve: E::ID,
+    }
+}
+
+/// Upper bound on the size in bytes of a `proof.json` for curve `E` as
+/// written by `export_proof` (default formatting), with an empty
+/// `publicSignals`; each public signal adds at most its scalar field's
+/// decimal length plus 10 bytes.
+///
+/// Computed from the decimal length of the base field modulus, taking every
+/// coordinate at that length; real proofs are usually a few bytes smaller.
+pub fn proof_size_estimate<E>() -> usize
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+{
+    let fq = quoted(base_field_digits::<E>());
+    let one = quoted(1);
+    let g1 = array(1, &[fq, fq, one]); // z = 1
+    // z = [1, 0]
+    let g2 = array(
+        1,
+        &[
+            array(2, &[fq, fq]),
+            array(2, &[fq, fq]),
+            array(2, &[one, one]),
+        ],
+    );
+    object(&[
+        ("pi_a", g1),
+        ("pi_b", g2),
+        ("pi_c", g1),
+        ("protocol", quoted("groth16".len())),
+        ("curve", quoted(E::NAME.len())),
+        ("publicSignals", array(1, &[])),
+    ])
+}
+
+/// Upper bound on the size in bytes of a `verification_key.json` for curve
+/// `E` with `n_public` public inputs, as written by `export_vk` (default
+/// formatting). Grows linearly in `n_public` through the `IC` array.
+///
+/// Computed like `proof_size_estimate`, without serializing anything or
+/// allocating per `IC` entry. `None` if the size does not fit in `usize`.
+pub fn vk_size_estimate<E>(n_public: usize) -> Option<usize>
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+{
+    let fq = quoted(base_field_digits::<E>());
+    let g2 = array(1, &[array(2, &[fq, fq]); 2]);
+    let ic = repeated_array(1, n_public.checked_add(1)?, array(2, &[fq, fq]))?;
+    // Everything but the IC entries has a fixed size
+    let fixed = object(&[
+        ("protocol", quoted("groth16".len())),
+        ("curve", quoted(E::NAME.len())),
+        (
+            "nPublic",
+            n_public.checked_ilog10().map_or(1, |d| d as usize + 1),
+        ),
+        ("vk_alpha_1", array(1, &[fq, fq])),
+        ("vk_beta_2", g2),
+        ("vk_gamma_2", g2),
+        ("vk_delta_2", g2),
+        ("IC", 0),
+    ]);
+    fixed.checked_add(ic)
+}
+
+/// Decimal length of the largest base field element of `E`.
+fn base_field_digits<E>() -> usize
+where
+    E: Pairing,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+{
+    let modulus = <<E::G1Affine as AffineRepr>::BaseField as PrimeField>::MODULUS;
+    BigUint::from_bytes_be(&modulus.to_bytes_be())
+        .to_str_radix(10)
+        .len()
+}
+
+/// Size of a JSON string of `len` characters, with its quotes.
+fn quoted(len: usize) -> usize {
+    len + 2
+}
+
+/// Size of a pretty-printed array opened on a line indented `depth` levels,
+/// whose items take `items` bytes each: every item on its own line one
+/// level deeper, the closing bracket back at `depth`.
+fn array(depth: usize, items: &[usize]) -> usize {
+    if items.is_empty() {
+        return 2; // []
+    }
+    let lines: usize = items.iter().map(|s| 1 + 2 * (depth + 1) + s).sum();
+    1 + lines + (items.len() - 1) + 1 + 2 * depth + 1
+}
+
+/// Size of `array(depth, &[item; count])`, computed without building the
+/// slice. `None` on overflow.
+fn repeated_array(depth: usize, count: usize, item: usize) -> Option<usize> {
+    if count == 0 {
+        return Some(2); // []
+    }
+    let line = 1 + 2 * (depth + 1) + item;
+    count
+        .checked_mul(line)?
+        .checked_add(count - 1)?
+        .checked_add(1 + 1 + 2 * depth + 1)
+}
+
+/// Size of a pretty-printed top-level object with the given keys and value
+/// sizes (`{`, one `  "key": value` line per entry, `}`).
+fn object(entries: &[(&str, usize)]) -> usize {
+    let lines: usize = entries
+        .iter()
+        .map(|(key, value)| 1 + 2 + quoted(key.len()) + 2 + value)
+        .sum();
+    1 + lines + (entries.len() - 1) + 1 + 1
+}
+
+/// Which release of this crate wrote a file, from its `"arkSnarkjsVersion"`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileVersion {
+    /// The recorded version; `None` for files written by snarkjs or without
+    /// `ExportOptions::version_tag`.
+    pub written_by: Option<String>,
+    /// The file comes from a newer release than `CRATE_VERSION` (or records
+    /// a version that is not `major.minor.patch`), so it may use schema
+    /// changes this build does not know about.
+    pub newer: bool,
+}
+
+#[derive(Deserialize)]
+struct VersionTag {
+    #[serde(rename = "arkSnarkjsVersion")]
+    version: Option<String>,
+}
+
+/// `(major, minor, patch)` of a version string, ignoring any `-pre` or
+/// `+build` suffix.
+fn semver(v: &str) -> Option<(u64, u64, u64)> {
+    let core = v.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+    let version = (parts.next()??, parts.next()??, parts.next()??);
+    parts.next().is_none().then_some(version)
+}
+
+/// Read the `"arkSnarkjsVersion"` of any exported JSON file (proof,
+/// verifying key, ...) and compare it with `CRATE_VERSION`.
+pub fn file_version_from_str(s: &str) -> Result<FileVersion, SnarkjsError> {
+    let tag: VersionTag = serde_json::from_str(s)?;
+    let newer = match &tag.version {
+        None => false,
+        Some(v) => match (semver(v), semver(CRATE_VERSION)) {
+            (Some(file), Some(ours)) => file > ours,
+            _ => true,
+        },
+    };
+    Ok(FileVersion {
+        written_by: tag.version,
+        newer,
+    })
+}
+
+/// Same as `file_version_from_str`, reading the file at `path`.
+#[cfg(feature = "std")]
+pub fn file_version<P>(path: P) -> Result<FileVersion, SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    file_version_from_str(&fs::read_to_string(path)?)
+}
@@ -0,0 +1,215 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "std", feature = "bn254"))]
+use crate::error::SnarkjsError;
+
+#[cfg(feature = "std")]
+use crate::snarkjs_common::CurveId;
+
+/// Output options for the exporters (`export_proof_with_options`,
+/// `export_vk_with_options`, `to_eth_calldata_bytes_with_options`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Indentation used for pretty-printed JSON, e.g. `"    "` or `"\t"`.
+    /// Defaults to two spaces, like `serde_json::to_writer_pretty`.
+    pub indent: String,
+    /// Add a `"fieldModulus"` entry (decimal scalar field modulus) to
+    /// exported verifying keys. Off by default, which keeps the output
+    /// identical to snarkjs.
+    pub field_modulus: bool,
+    /// Add `"vk_alphabeta_12"` (the pairing `e(vk_alpha_1, vk_beta_2)`) to
+    /// exported verifying keys, as snarkjs does. Off by default since it
+    /// costs a pairing; Fp12 target fields (BN254, BLS12-381) only.
+    pub alphabeta: bool,
+    /// Write `-pi_a` instead of `pi_a` in calldata, for verifiers that check
+    /// `e(-A, B) · e(α, β) · e(L, γ) · e(C, δ) = 1` with the proof point
+    /// negated by the caller. Off by default (snarkjs verifier template).
+    pub negate_a: bool,
+    /// How the `"curve"` entry of exported proofs and verifying keys is
+    /// spelled. Defaults to snarkjs' own names (`"bn128"`, `"bls12381"`).
+    pub curve_name_style: CurveNameStyle,
+    /// Add an `"arkSnarkjsVersion"` entry (this crate's version) to exported
+    /// proofs and verifying keys, so importers can detect files from a newer
+    /// release (see `file_version`). Off by default for snarkjs parity.
+    pub version_tag: bool,
+    /// Reorder the public inputs on export: position `i` of the written
+    /// `publicSignals` (and of `IC[1..]`, so proof and key stay consistent)
+    /// takes arkworks input `public_order[i]`. Use it to match circom's
+    /// `[public outputs, public inputs]` order when the arkworks circuit
+    /// allocates its inputs differently. `None` (the default) writes the
+    /// inputs in arkworks allocation order, as-is.
+    pub public_order: Option<Vec<usize>>,
+    /// The `"protocol"` entry of exported proofs and verifying keys, for
+    /// snarkjs forks and tools that key on a patched protocol name.
+    /// Defaults to `"groth16"`; the importers of this crate only accept
+    /// `"groth16"` and reject anything else with
+    /// `SnarkjsError::ProtocolMismatch`.
+    pub protocol: &'static str,
+    /// Write the proof's G1 points `pi_a` / `pi_c` in snarkjs' projective
+    /// `[x, y, "1"]` form (the default). When off they are written as
+    /// affine `[x, y]` pairs for verifiers that expect two elements, with
+    /// the point at infinity as `["0", "0"]`. `pi_b` is left unchanged.
+    pub g1_projective: bool,
+    /// Write the keys of every JSON object in alphabetical order rather than
+    /// in snarkjs' order (the default), so the bytes of an export (and its
+    /// hash) do not depend on how this crate declares its structs. Useful
+    /// for content-addressed storage.
+    pub sort_keys: bool,
+}
+
+/// Spelling of the `"curve"` entry in exported files.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CurveNameStyle {
+    /// snarkjs names, `CurveId::name` (`"bn128"`, `"bls12381"`).
+    #[default]
+    Snarkjs,
+    /// arkworks names, `CurveId::canonical_name` (`"bn254"`, `"bls12-381"`),
+    /// for consumers other than snarkjs. The returned JSON then records the
+    /// curve as `CurveId::Other` holding that name, exactly as written.
+    Canonical,
+}
+
+impl CurveNameStyle {
+    /// `id` as it should be serialized in this style.
+    #[cfg(feature = "std")]
+    pub(crate) fn apply(self, id: CurveId) -> CurveId {
+        match self {
+            CurveNameStyle::Snarkjs => id,
+            CurveNameStyle::Canonical => CurveId::Other(id.canonical_name()),
+        }
+    }
+}
+
+impl ExportOptions {
+    /// `values` in the order given by `public_order` (cloned as-is without
+    /// one). Fails with `SnarkjsError::InvalidPublicOrder` unless the order
+    /// is a permutation of `0..values.len()`.
+    #[cfg(any(feature = "std", feature = "bn254"))]
+    pub(crate) fn reorder_public<T: Clone>(&self, values: &[T]) -> Result<Vec<T>, SnarkjsError> {
+        let Some(order) = &self.public_order else {
+            return Ok(values.to_vec());
+        };
+        let mut seen = alloc::vec![false; values.len()];
+        let is_permutation = order.len() == values.len()
+            && order
+                .iter()
+                .all(|&i| i < seen.len() && !core::mem::replace(&mut seen[i], true));
+        if !is_permutation {
+            return Err(SnarkjsError::InvalidPublicOrder { len: values.len() });
+        }
+        Ok(order.iter().map(|&i| values[i].clone()).collect())
+    }
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            indent: "  ".to_string(),
+            field_modulus: false,
+            alphabeta: false,
+            negate_a: false,
+            curve_name_style: CurveNameStyle::Snarkjs,
+            version_tag: false,
+            public_order: None,
+            protocol: "groth16",
+            g1_projective: true,
+            sort_keys: false,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Options with an indent of `width` spaces.
+    pub fn with_indent_width(width: usize) -> Self {
+        ExportOptions {
+            indent: " ".repeat(width),
+            ..Default::default()
+        }
+    }
+
+    /// Start from the defaults and change only what is needed:
+    /// `ExportOptions::builder().indent_width(1).alphabeta(true).build()`.
+    pub fn builder() -> ExportOptionsBuilder {
+        ExportOptionsBuilder::default()
+    }
+}
+
+/// Chainable setters for `ExportOptions`, created by `ExportOptions::builder`.
+/// Every field not set keeps its `Default` value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExportOptionsBuilder {
+    options: ExportOptions,
+}
+
+impl ExportOptionsBuilder {
+    /// Indentation of pretty-printed JSON, e.g. `"\t"`.
+    pub fn indent(mut self, indent: impl Into<String>) -> Self {
+        self.options.indent = indent.into();
+        self
+    }
+
+    /// Indent pretty-printed JSON with `width` spaces.
+    pub fn indent_width(self, width: usize) -> Self {
+        self.indent(" ".repeat(width))
+    }
+
+    /// See `ExportOptions::field_modulus`.
+    pub fn field_modulus(mut self, on: bool) -> Self {
+        self.options.field_modulus = on;
+        self
+    }
+
+    /// See `ExportOptions::alphabeta`.
+    pub fn alphabeta(mut self, on: bool) -> Self {
+        self.options.alphabeta = on;
+        self
+    }
+
+    /// See `ExportOptions::negate_a`.
+    pub fn negate_a(mut self, on: bool) -> Self {
+        self.options.negate_a = on;
+        self
+    }
+
+    /// See `ExportOptions::curve_name_style`.
+    pub fn curve_name_style(mut self, style: CurveNameStyle) -> Self {
+        self.options.curve_name_style = style;
+        self
+    }
+
+    /// See `ExportOptions::version_tag`.
+    pub fn version_tag(mut self, on: bool) -> Self {
+        self.options.version_tag = on;
+        self
+    }
+
+    /// See `ExportOptions::public_order`.
+    pub fn public_order(mut self, order: impl Into<Vec<usize>>) -> Self {
+        self.options.public_order = Some(order.into());
+        self
+    }
+
+    /// See `ExportOptions::protocol`.
+    pub fn protocol(mut self, protocol: &'static str) -> Self {
+        self.options.protocol = protocol;
+        self
+    }
+
+    /// See `ExportOptions::g1_projective`.
+    pub fn g1_projective(mut self, on: bool) -> Self {
+        self.options.g1_projective = on;
+        self
+    }
+
+    /// See `ExportOptions::sort_keys`.
+    pub fn sort_keys(mut self, on: bool) -> Self {
+        self.options.sort_keys = on;
+        self
+    }
+
+    /// The configured options.
+    pub fn build(self) -> ExportOptions {
+        self.options
+    }
+}
@@ -0,0 +1,170 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use ark_groth16::VerifyingKey;
+use ark_relations::r1cs::ConstraintSystemRef;
+use core::ops::Deref;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "std")]
+use std::{collections::BTreeSet, path::Path};
+
+use crate::error::SnarkjsError;
+#[cfg(feature = "std")]
+use crate::snarkjs_common::write_json_pretty;
+use crate::snarkjs_common::{f_to_dec, parse_signal_checked};
+
+/// Public inputs of a Groth16 proof, in circuit order.
+///
+/// Element `i` is the value of the `i`-th public input allocated by the
+/// circuit (the one bound to `vk.gamma_abc_g1[i + 1]`), which is also its
+/// position in snarkjs' `publicSignals` / `public.json`. Serializes to that
+/// array of decimal strings and parses back (accepting `0x`-prefixed hex
+/// entries as well), so the order is preserved across export and import.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct PublicSignals<F>(pub Vec<F>);
+
+impl<F> PublicSignals<F> {
+    /// Unwrap into the inner vector.
+    pub fn into_inner(self) -> Vec<F> {
+        self.0
+    }
+
+    /// Check that there is exactly one signal per public input of `vk`,
+    /// failing with `SnarkjsError::PublicCountMismatch` otherwise.
+    pub fn check_len<E>(&self, vk: &VerifyingKey<E>) -> Result<(), SnarkjsError>
+    where
+        E: Pairing<ScalarField = F>,
+    {
+        check_public_len(vk, self.0.len())
+    }
+}
+
+/// `PublicSignals::check_len` for a bare count, so callers holding a slice
+/// need not copy it into a `PublicSignals` first.
+pub(crate) fn check_public_len<E: Pairing>(
+    vk: &VerifyingKey<E>,
+    n_public: usize,
+) -> Result<(), SnarkjsError> {
+    if vk.gamma_abc_g1.len().checked_sub(1) != Some(n_public) {
+        return Err(SnarkjsError::PublicCountMismatch {
+            expected: vk.gamma_abc_g1.len().saturating_sub(1),
+            found: n_public,
+        });
+    }
+    Ok(())
+}
+
+impl<F> Deref for PublicSignals<F> {
+    type Target = [F];
+    fn deref(&self) -> &[F] {
+        &self.0
+    }
+}
+
+impl<F> From<Vec<F>> for PublicSignals<F> {
+    fn from(v: Vec<F>) -> Self {
+        PublicSignals(v)
+    }
+}
+
+impl<F: Clone> From<&Vec<F>> for PublicSignals<F> {
+    fn from(v: &Vec<F>) -> Self {
+        PublicSignals(v.clone())
+    }
+}
+
+impl<F: Clone> From<&[F]> for PublicSignals<F> {
+    fn from(v: &[F]) -> Self {
+        PublicSignals(v.to_vec())
+    }
+}
+
+impl<F: Clone, const N: usize> From<&[F; N]> for PublicSignals<F> {
+    fn from(v: &[F; N]) -> Self {
+        PublicSignals(v.to_vec())
+    }
+}
+
+impl<F: Clone> From<&PublicSignals<F>> for PublicSignals<F> {
+    fn from(v: &PublicSignals<F>) -> Self {
+        v.clone()
+    }
+}
+
+impl<F: PrimeField> Serialize for PublicSignals<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(f_to_dec))
+    }
+}
+
+impl<'de, F: PrimeField> Deserialize<'de> for PublicSignals<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+        strings
+            .iter()
+            .map(|s| parse_signal_checked(s))
+            .collect::<Result<Vec<F>, _>>()
+            .map(PublicSignals)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Public inputs assigned in `cs`, in allocation order: the instance
+/// assignment without its leading constant `1`, i.e. exactly what
+/// `Groth16::prove` binds to `vk.gamma_abc_g1[1..]`. Take it from the
+/// constraint system the proof was generated from (finalized or not), so
+/// the exported `publicSignals` cannot drift from the circuit instance.
+///
+/// Empty for `ConstraintSystemRef::None` and for a system in setup mode,
+/// which records no assignments.
+pub fn public_inputs_from_cs<F: PrimeField>(cs: &ConstraintSystemRef<F>) -> Vec<F> {
+    cs.borrow()
+        .and_then(|cs| cs.instance_assignment.get(1..).map(<[F]>::to_vec))
+        .unwrap_or_default()
+}
+
+/// `label -> decimal value` object, in the order the labels were given.
+#[cfg(feature = "std")]
+struct LabeledSignals<'a, F> {
+    public: &'a [F],
+    labels: &'a [&'a str],
+}
+
+#[cfg(feature = "std")]
+impl<F: PrimeField> Serialize for LabeledSignals<'_, F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.labels.iter().zip(self.public.iter().map(f_to_dec)))
+    }
+}
+
+/// Export public signals as a JSON object mapping each label to its decimal
+/// value, e.g. `{ "out": "33", "nullifier": "7" }`, for human inspection.
+///
+/// Not a snarkjs format: snarkjs only reads the plain `public.json` array.
+/// `labels[i]` names `public[i]`; fails with `SnarkjsError::InvalidShape` if
+/// the lengths differ or a label is repeated (which would hide a signal).
+#[cfg(feature = "std")]
+pub fn export_public_labeled<F, P>(
+    public: &[F],    // public inputs, in circuit order
+    labels: &[&str], // one label per public input
+    out_path: P,     // output path for JSON file
+) -> Result<(), SnarkjsError>
+where
+    F: PrimeField,
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    check_labels(labels, public.len())?;
+    write_json_pretty(out_path, &LabeledSignals { public, labels })
+}
+
+/// Fail with `SnarkjsError::InvalidShape` unless there are exactly `len`
+/// labels, all distinct.
+#[cfg(feature = "std")]
+pub(crate) fn check_labels(labels: &[&str], len: usize) -> Result<(), SnarkjsError> {
+    let mut seen = BTreeSet::new();
+    if labels.len() != len || !labels.iter().all(|label| seen.insert(label)) {
+        return Err(SnarkjsError::InvalidShape { field: "labels" });
+    }
+    Ok(())
+}
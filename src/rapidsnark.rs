@@ -0,0 +1,116 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::pairing::Pairing;
+use ark_groth16::{Proof, VerifyingKey};
+use serde::Serialize;
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+use ark_bn254::Bn254;
+#[cfg(feature = "std")]
+use ark_bn254::Fr;
+
+use crate::error::SnarkjsError;
+#[cfg(feature = "std")]
+use crate::public_signals::PublicSignals;
+use crate::snarkjs_common::{CurveId, CurveTag, g1_xyz, g2_xyz, gt_to_dec};
+#[cfg(feature = "std")]
+use crate::snarkjs_common::{with_path, write_json_file};
+
+/// Groth16 proof as rapidsnark's prover writes `proof.json`: the snarkjs
+/// layout without `publicSignals`, which rapidsnark keeps in `public.json`.
+#[derive(Serialize)]
+pub struct RapidsnarkProofJson {
+    pub pi_a: [String; 3],      // G1 point [x, y, z]
+    pub pi_b: [[String; 2]; 3], // G2 point [[x0, x1], [y0, y1], [z0, z1]]
+    pub pi_c: [String; 3],      // G1 point [x, y, z]
+    pub protocol: &'static str, // always "groth16"
+    pub curve: CurveId,         // always "bn128"
+}
+
+/// Groth16 verifying key in the layout of snarkjs' `zkey export
+/// verificationkey`, which is what rapidsnark's `verify` reads. Unlike
+/// `VkJson`, every point is projective (`[x, y, "1"]`, G2 with a
+/// `["1", "0"]` z) and `vk_alphabeta_12` is always present.
+#[derive(Serialize)]
+pub struct RapidsnarkVkJson {
+    pub protocol: &'static str, // always "groth16"
+    pub curve: CurveId,         // always "bn128"
+    #[serde(rename = "nPublic")]
+    pub n_public: usize, // number of public inputs
+    pub vk_alpha_1: [String; 3], // G1 point
+    pub vk_beta_2: [[String; 2]; 3], // G2 point
+    pub vk_gamma_2: [[String; 2]; 3], // G2 point
+    pub vk_delta_2: [[String; 2]; 3], // G2 point
+    pub vk_alphabeta_12: [[[String; 2]; 3]; 2], // e(alpha, beta)
+    #[serde(rename = "IC")]
+    pub ic: Vec<[String; 3]>, // list of G1 points for input coefficients
+}
+
+/// Convert a Bn254 Groth16 proof to rapidsnark's `proof.json` layout.
+/// G2 coordinates are `[c0, c1]`, as in snarkjs.
+pub fn rapidsnark_proof(proof: &Proof<Bn254>) -> Result<RapidsnarkProofJson, SnarkjsError> {
+    Ok(RapidsnarkProofJson {
+        pi_a: g1_xyz(&proof.a)?,
+        pi_b: g2_xyz(&proof.b)?,
+        pi_c: g1_xyz(&proof.c)?,
+        protocol: "groth16",
+        curve: Bn254::ID,
+    })
+}
+
+/// Convert a Bn254 Groth16 verifying key to the `verification_key.json`
+/// layout rapidsnark reads. `nPublic` is taken from `IC`; an empty `IC`
+/// fails with `SnarkjsError::IcLengthMismatch`. Costs one pairing for
+/// `vk_alphabeta_12`.
+pub fn rapidsnark_vk(vk: &VerifyingKey<Bn254>) -> Result<RapidsnarkVkJson, SnarkjsError> {
+    let n_public = vk
+        .gamma_abc_g1
+        .len()
+        .checked_sub(1)
+        .ok_or(SnarkjsError::IcLengthMismatch {
+            n_public: 0,
+            ic_len: 0,
+        })?;
+    Ok(RapidsnarkVkJson {
+        protocol: "groth16",
+        curve: Bn254::ID,
+        n_public,
+        vk_alpha_1: g1_xyz(&vk.alpha_g1)?,
+        vk_beta_2: g2_xyz(&vk.beta_g2)?,
+        vk_gamma_2: g2_xyz(&vk.gamma_g2)?,
+        vk_delta_2: g2_xyz(&vk.delta_g2)?,
+        vk_alphabeta_12: gt_to_dec(&Bn254::pairing(vk.alpha_g1, vk.beta_g2).0)?,
+        ic: vk
+            .gamma_abc_g1
+            .iter()
+            .map(g1_xyz)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Write the three files `rapidsnark verify` takes into `out_dir`:
+/// `verification_key.json`, `proof.json` and `public.json`.
+///
+/// Fails with `SnarkjsError::PublicCountMismatch` if `public` does not hold
+/// one value per public input of `vk`.
+#[cfg(feature = "std")]
+pub fn export_rapidsnark<P>(
+    proof: &Proof<Bn254>,     // Groth16 proof from arkworks
+    public: &[Fr],            // list of public inputs
+    vk: &VerifyingKey<Bn254>, // Groth16 verifying key from arkworks
+    out_dir: P,               // output directory for the three JSON files
+) -> Result<(), SnarkjsError>
+where
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    let public = PublicSignals::from(public);
+    public.check_len(vk)?;
+
+    let out_dir = out_dir.as_ref();
+    fs::create_dir_all(out_dir).map_err(|e| with_path(e.into(), out_dir))?;
+    write_json_file(out_dir.join("verification_key.json"), &rapidsnark_vk(vk)?)?;
+    write_json_file(out_dir.join("proof.json"), &rapidsnark_proof(proof)?)?;
+    write_json_file(out_dir.join("public.json"), &public)?;
+    Ok(())
+}
@@ -1,23 +1,193 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use ark_ec::AffineRepr;
+#[cfg(feature = "bn254")]
+use ark_ec::CurveGroup;
+use ark_ec::pairing::Pairing;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
 use ark_ff::{BigInteger, PrimeField};
+use ark_ff::{Field, One, Zero};
 use num_bigint::BigUint;
+#[cfg(feature = "std")]
+use serde::{Serialize, Serializer};
+#[cfg(feature = "std")]
+use serde_json::Value;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::{fs, fs::File, io::Write, path::Path};
+
+use crate::error::SnarkjsError;
+#[cfg(feature = "std")]
+use crate::options::ExportOptions;
 
 /// Curve marker used to tag curve type for snarkjs compatibility.
 pub trait CurveTag {
-    const NAME: &'static str;
+    const ID: CurveId;
+    const NAME: &'static str = Self::ID.name();
+    /// Whether `g2_xyxy` writes Fp2 coordinates as `[c1, c0]` for this curve.
+    /// snarkjs JSON is `[c0, c1]` (real part first) on every curve supported
+    /// today, so this is `false` everywhere; the Solidity calldata order
+    /// (`eth_calldata`) is a separate, always-swapped encoding.
+    const G2_SWAPPED: bool = false;
 }
 
+#[cfg(feature = "bn254")]
 impl CurveTag for ark_bn254::Bn254 {
-    const NAME: &'static str = "bn128";
+    const ID: CurveId = CurveId::Bn128;
 }
+#[cfg(feature = "bls12_381")]
 impl CurveTag for ark_bls12_381::Bls12_381 {
-    const NAME: &'static str = "bls12381";
+    const ID: CurveId = CurveId::Bls12381;
+}
+#[cfg(feature = "mnt4_753")]
+impl CurveTag for ark_mnt4_753::MNT4_753 {
+    const ID: CurveId = CurveId::Mnt4753;
+}
+#[cfg(feature = "mnt6_753")]
+impl CurveTag for ark_mnt6_753::MNT6_753 {
+    const ID: CurveId = CurveId::Mnt6753;
+}
+
+/// Extension point for curves this crate does not know about: a downstream
+/// crate implements it for its own `Pairing` type and exports with
+/// `export_proof_dyn`. Every `CurveTag` curve implements it already.
+pub trait ExportCurve: Pairing {
+    /// snarkjs `"curve"` value written for this curve.
+    const NAME: &'static str;
+    /// G1 point as snarkjs `[x, y, z]`.
+    fn g1_to_snarkjs(p: &Self::G1Affine) -> Result<[String; 3], SnarkjsError>;
+    /// G2 point as snarkjs `[[x0, x1], [y0, y1], [z0, z1]]`.
+    fn g2_to_snarkjs(p: &Self::G2Affine) -> Result<[[String; 2]; 3], SnarkjsError>;
+}
+
+impl<E> ExportCurve for E
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    const NAME: &'static str = <E as CurveTag>::NAME;
+    fn g1_to_snarkjs(p: &Self::G1Affine) -> Result<[String; 3], SnarkjsError> {
+        g1_xyz(p)
+    }
+    fn g2_to_snarkjs(p: &Self::G2Affine) -> Result<[[String; 2]; 3], SnarkjsError> {
+        g2_xyz(p)
+    }
+}
+
+/// snarkjs names (`CurveTag::NAME`) of every curve enabled in this build.
+const SUPPORTED_CURVES: &[&str] = &[
+    #[cfg(feature = "bn254")]
+    <ark_bn254::Bn254 as CurveTag>::NAME,
+    #[cfg(feature = "bls12_381")]
+    <ark_bls12_381::Bls12_381 as CurveTag>::NAME,
+    #[cfg(feature = "mnt4_753")]
+    <ark_mnt4_753::MNT4_753 as CurveTag>::NAME,
+    #[cfg(feature = "mnt6_753")]
+    <ark_mnt6_753::MNT6_753 as CurveTag>::NAME,
+];
+
+/// snarkjs curve names this build can export and import, e.g. to validate
+/// the `"curve"` field of a file before dispatching on it.
+pub fn supported_curves() -> &'static [&'static str] {
+    SUPPORTED_CURVES
+}
+
+/// Whether `name` is the snarkjs name of a curve enabled in this build.
+pub fn is_supported(name: &str) -> bool {
+    SUPPORTED_CURVES.contains(&name)
+}
+
+/// Runtime identifier of a snarkjs curve (the `"curve"` field of a JSON file).
+///
+/// Serializes to the snarkjs name; deserializes (and parses with `FromStr`)
+/// from it or from the arkworks spelling (`"bn254"`, `"bls12-381"`, ...).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CurveId {
+    Bn128,
+    Bls12381,
+    Mnt4753,
+    Mnt6753,
+    /// A curve outside this crate, exported through `ExportCurve`.
+    Other(&'static str),
+}
+
+impl CurveId {
+    /// Curve name as written by snarkjs.
+    pub const fn name(self) -> &'static str {
+        match self {
+            CurveId::Bn128 => "bn128",
+            CurveId::Bls12381 => "bls12381",
+            CurveId::Mnt4753 => "mnt4753",
+            CurveId::Mnt6753 => "mnt6753",
+            CurveId::Other(name) => name,
+        }
+    }
+
+    /// Curve name as spelled by arkworks and most non-snarkjs tools
+    /// (`"bn254"`, `"bls12-381"`, ...). Parses back to the same `CurveId`.
+    pub const fn canonical_name(self) -> &'static str {
+        match self {
+            CurveId::Bn128 => "bn254",
+            CurveId::Bls12381 => "bls12-381",
+            CurveId::Mnt4753 => "mnt4-753",
+            CurveId::Mnt6753 => "mnt6-753",
+            CurveId::Other(name) => name,
+        }
+    }
+}
+
+impl core::str::FromStr for CurveId {
+    type Err = SnarkjsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = match s {
+            "bn254" => "bn128",
+            "bls12-381" | "bls12_381" => "bls12381",
+            "mnt4-753" | "mnt4_753" => "mnt4753",
+            "mnt6-753" | "mnt6_753" => "mnt6753",
+            other => other,
+        };
+        curve_name_from_str(name).ok_or_else(|| SnarkjsError::UnknownCurve(s.to_string()))
+    }
+}
+
+impl serde::Serialize for CurveId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CurveId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Look up a curve by the snarkjs `"curve"` string (e.g. "bn128", "bls12381").
+pub fn curve_name_from_str(name: &str) -> Option<CurveId> {
+    match name {
+        "bn128" => Some(CurveId::Bn128),
+        "bls12381" => Some(CurveId::Bls12381),
+        "mnt4753" => Some(CurveId::Mnt4753),
+        "mnt6753" => Some(CurveId::Mnt6753),
+        _ => None,
+    }
 }
 
 /// Trait to access c0/c1 components of quadratic extension fields (Fp2).
-pub trait AsFp2 {
+/// Both accessors fail with `SnarkjsError::UnsupportedG2` for fields that
+/// have no snarkjs `[c0, c1]` form.
+pub trait AsFp2: Sized {
     type Base: PrimeField;
-    fn c0_c1(&self) -> (&Self::Base, &Self::Base);
+    /// `false` for G2 fields that are not quadratic extensions (e.g. the Fp3
+    /// of MNT6). Exporters check this up front and return
+    /// `SnarkjsError::UnsupportedG2` instead of writing a file.
+    const IS_FP2: bool = true;
+    fn c0_c1(&self) -> Result<(&Self::Base, &Self::Base), SnarkjsError>;
+    fn from_c0_c1(c0: Self::Base, c1: Self::Base) -> Result<Self, SnarkjsError>;
 }
 
 impl<P> AsFp2 for ark_ff::fields::models::QuadExtField<P>
@@ -26,35 +196,573 @@ where
     P::BaseField: PrimeField,
 {
     type Base = P::BaseField;
-    fn c0_c1(&self) -> (&Self::Base, &Self::Base) {
-        (&self.c0, &self.c1)
+    fn c0_c1(&self) -> Result<(&Self::Base, &Self::Base), SnarkjsError> {
+        Ok((&self.c0, &self.c1))
+    }
+    fn from_c0_c1(c0: Self::Base, c1: Self::Base) -> Result<Self, SnarkjsError> {
+        Ok(Self::new(c0, c1))
+    }
+}
+
+/// Cubic extensions (Fp3) only implement `AsFp2` so that curves like MNT6 can
+/// be passed to the exporters and rejected with `SnarkjsError::UnsupportedG2`.
+impl<P> AsFp2 for ark_ff::fields::models::CubicExtField<P>
+where
+    P: ark_ff::fields::models::cubic_extension::CubicExtConfig,
+    P::BaseField: PrimeField,
+{
+    type Base = P::BaseField;
+    const IS_FP2: bool = false;
+    fn c0_c1(&self) -> Result<(&Self::Base, &Self::Base), SnarkjsError> {
+        Err(SnarkjsError::UnsupportedG2)
+    }
+    fn from_c0_c1(_c0: Self::Base, _c1: Self::Base) -> Result<Self, SnarkjsError> {
+        Err(SnarkjsError::UnsupportedG2)
+    }
+}
+
+/// Fail with `SnarkjsError::UnsupportedG2` if `F` is not a quadratic extension.
+pub(crate) fn ensure_fp2<F: AsFp2>() -> Result<(), SnarkjsError> {
+    if F::IS_FP2 {
+        Ok(())
+    } else {
+        Err(SnarkjsError::UnsupportedG2)
+    }
+}
+
+/// Trait to rebuild affine points from their coordinates (inverse of `xy()`).
+pub trait FromXy: AffineRepr {
+    /// Build a point from affine coordinates without any validation.
+    fn from_xy_unchecked(x: Self::BaseField, y: Self::BaseField) -> Self;
+    /// Check that the point satisfies the curve equation.
+    fn is_on_curve(&self) -> bool;
+    /// Check that an on-curve point lies in the prime-order subgroup.
+    fn is_in_correct_subgroup(&self) -> bool;
+}
+
+impl<P: SWCurveConfig> FromXy for Affine<P> {
+    fn from_xy_unchecked(x: P::BaseField, y: P::BaseField) -> Self {
+        Affine::new_unchecked(x, y)
+    }
+    fn is_on_curve(&self) -> bool {
+        Affine::is_on_curve(self)
+    }
+    fn is_in_correct_subgroup(&self) -> bool {
+        self.is_in_correct_subgroup_assuming_on_curve()
     }
 }
 
 /// Convert a field element to decimal string (snarkjs expects decimal format).
+/// `into_bigint` yields the standard integer, not the Montgomery form.
+/// No sign or leading zeros; zero is written as `"0"`.
 pub fn f_to_dec<F: PrimeField>(f: &F) -> String {
     let bi = f.into_bigint();
     BigUint::from_bytes_be(&bi.to_bytes_be()).to_str_radix(10)
 }
 
+/// Byte order of the hex strings written by `f_to_hex`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Most significant byte first, as in Ethereum ABI words.
+    #[default]
+    Big,
+    /// Least significant byte first, as in arkworks `CanonicalSerialize`.
+    Little,
+}
+
+/// Convert a field element to a `0x`-prefixed lowercase hex string of the
+/// full field width (e.g. 32 bytes for BN254), in standard (non-Montgomery)
+/// representation with the bytes in `endianness` order.
+pub fn f_to_hex<F: PrimeField>(f: &F, endianness: Endianness) -> String {
+    let bi = f.into_bigint();
+    let bytes = match endianness {
+        Endianness::Big => bi.to_bytes_be(),
+        Endianness::Little => bi.to_bytes_le(),
+    };
+    let mut s = String::from("0x");
+    s.push_str(&hex(&bytes));
+    s
+}
+
+/// Lowercase hex without a prefix.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(2 * bytes.len());
+    for b in bytes {
+        s.push(char::from_digit((b >> 4) as u32, 16).unwrap());
+        s.push(char::from_digit((b & 0xf) as u32, 16).unwrap());
+    }
+    s
+}
+
+/// Parse a decimal string in standard (non-Montgomery) representation into a
+/// field element. Exact inverse of `f_to_dec`: `dec_to_f(&f_to_dec(&x)) == x`.
+/// Same validation as `parse_field_checked`.
+pub fn dec_to_f<F: PrimeField>(s: &str) -> Result<F, SnarkjsError> {
+    parse_field_checked(s)
+}
+
+/// Parse a decimal string into a field element (inverse of `f_to_dec`).
+/// Rejects empty strings, non-digits and values not below the modulus.
+pub fn parse_field_checked<F: PrimeField>(s: &str) -> Result<F, SnarkjsError> {
+    parse_radix_checked(s, s, 10)
+}
+
+/// Parse every string with `parse_field_checked`, failing with
+/// `SnarkjsError::InvalidFieldElementAt` at the first malformed element.
+///
+/// Parsing is not constant-time: it branches on the digits, which is fine
+/// for public signals and keys but not for secret witness values.
+pub fn parse_fields<F: PrimeField>(values: &[String]) -> Result<Vec<F>, SnarkjsError> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, s)| {
+            parse_field_checked(s).map_err(|_| SnarkjsError::InvalidFieldElementAt {
+                index,
+                value: s.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Parse a public signal: `0x`-prefixed hex (as emitted by some circom
+/// tooling and wallet SDKs) or decimal. Same range check as
+/// `parse_field_checked`.
+pub(crate) fn parse_signal_checked<F: PrimeField>(s: &str) -> Result<F, SnarkjsError> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => parse_radix_checked(s, hex, 16),
+        None => parse_field_checked(s),
+    }
+}
+
+/// Parse `digits` in `radix` (10 or 16) into a canonical field element;
+/// errors report the original string `s`.
+fn parse_radix_checked<F: PrimeField>(
+    s: &str,
+    digits: &str,
+    radix: u32,
+) -> Result<F, SnarkjsError> {
+    let invalid = || SnarkjsError::InvalidFieldElement(s.to_string());
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(invalid());
+    }
+    let n = BigUint::parse_bytes(digits.as_bytes(), radix).ok_or_else(invalid)?;
+    if n >= F::MODULUS.into() {
+        return Err(invalid());
+    }
+    // Below the modulus, so the reduction is a no-op
+    Ok(F::from_be_bytes_mod_order(&n.to_bytes_be()))
+}
+
 /// Convert a G1 point to string array [x, y].
-pub fn g1_xy<G>(p: &G) -> [String; 2]
+/// Fails with `SnarkjsError::PointAtInfinity` for the identity.
+pub fn g1_xy<G>(p: &G) -> Result<[String; 2], SnarkjsError>
 where
     G: AffineRepr,
     G::BaseField: PrimeField,
 {
-    let (x, y) = p.xy().expect("G1 point at infinity?");
-    [f_to_dec(&x), f_to_dec(&y)]
+    let (x, y) = p.xy().ok_or(SnarkjsError::PointAtInfinity)?;
+    Ok([f_to_dec(&x), f_to_dec(&y)])
+}
+
+/// `IC` entry as snarkjs `[x, y]`. Unlike `g1_xy`, the identity is allowed:
+/// an input that no constraint uses has an identity `IC` point, written as
+/// `["0", "0"]`, the affine zero of snarkjs (ffjavascript) and of the EVM
+/// pairing precompiles. `(0, 0)` lies on none of the supported curves, so
+/// it cannot be mistaken for a real point.
+pub(crate) fn ic_xy<G>(p: &G) -> Result<[String; 2], SnarkjsError>
+where
+    G: AffineRepr,
+    G::BaseField: PrimeField,
+{
+    if p.is_zero() {
+        return Ok(["0".to_string(), "0".to_string()]);
+    }
+    g1_xy(p)
 }
 
 /// Convert a G2 point to nested string array [[x.c0, x.c1], [y.c0, y.c1]].
-pub fn g2_xyxy<G>(p: &G) -> [[String; 2]; 2]
+/// The `[c0, c1]` order (real part first) is snarkjs' own and is pinned by
+/// a golden test against snarkjs' G2 generators on BN254 and BLS12-381.
+/// Fails with `SnarkjsError::UnsupportedG2` if G2 is not over Fp2 and with
+/// `SnarkjsError::PointAtInfinity` for the identity.
+pub fn g2_xyxy<G>(p: &G) -> Result<[[String; 2]; 2], SnarkjsError>
+where
+    G: AffineRepr,
+    G::BaseField: AsFp2,
+{
+    ensure_fp2::<G::BaseField>()?;
+    let (x, y) = p.xy().ok_or(SnarkjsError::PointAtInfinity)?;
+    let (x0, x1) = x.c0_c1()?;
+    let (y0, y1) = y.c0_c1()?;
+    Ok([[f_to_dec(x0), f_to_dec(x1)], [f_to_dec(y0), f_to_dec(y1)]])
+}
+
+/// G1 point as snarkjs `[x, y, z]`: `z = "1"` for an affine point,
+/// `["0", "1", "0"]` for the point at infinity.
+pub(crate) fn g1_xyz<G>(p: &G) -> Result<[String; 3], SnarkjsError>
+where
+    G: AffineRepr,
+    G::BaseField: PrimeField,
+{
+    if p.is_zero() {
+        return Ok(["0".to_string(), "1".to_string(), "0".to_string()]);
+    }
+    let [x, y] = g1_xy(p)?;
+    Ok([x, y, "1".to_string()])
+}
+
+/// G2 point as snarkjs `[x, y, z]` over Fp2: `z = ["1", "0"]` for an affine
+/// point, `[["0", "0"], ["1", "0"], ["0", "0"]]` for the point at infinity.
+pub(crate) fn g2_xyz<G>(p: &G) -> Result<[[String; 2]; 3], SnarkjsError>
 where
     G: AffineRepr,
     G::BaseField: AsFp2,
 {
-    let (x, y) = p.xy().expect("G2 point at infinity?");
-    let (x0, x1) = x.c0_c1();
-    let (y0, y1) = y.c0_c1();
-    [[f_to_dec(x0), f_to_dec(x1)], [f_to_dec(y0), f_to_dec(y1)]]
+    let zero = || ["0".to_string(), "0".to_string()];
+    let one = || ["1".to_string(), "0".to_string()];
+
+    ensure_fp2::<G::BaseField>()?;
+    if p.is_zero() {
+        return Ok([zero(), one(), zero()]);
+    }
+    let [x, y] = g2_xyxy(p)?;
+    Ok([x, y, one()])
+}
+
+/// Negate a G1 or G2 point (the point at infinity stays at infinity), for
+/// verifiers that expect a proof point negated.
+#[cfg(feature = "bn254")]
+pub(crate) fn negate<G: AffineRepr>(p: &G) -> G {
+    (-p.into_group()).into_affine()
+}
+
+/// Parse a G1 point from string array [x, y] (inverse of `g1_xy`).
+/// The point must lie on the curve and in the prime-order subgroup;
+/// `["0", "0"]` is read as the point at infinity, as snarkjs does.
+pub fn g1_from_dec<G>(xy: &[String; 2]) -> Result<G, SnarkjsError>
+where
+    G: FromXy,
+    G::BaseField: PrimeField,
+{
+    let x = parse_field_checked(&xy[0])?;
+    let y = parse_field_checked(&xy[1])?;
+    affine_g1(x, y)
+}
+
+/// A snarkjs G1 point in either shape: affine `[x, y]` as in verifying keys
+/// (`IC`, `vk_alpha_1`) or projective `[x, y, z]` as in proofs. Deserializes
+/// from whichever JSON array is present.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum G1Coords {
+    Affine([String; 2]),
+    Projective([String; 3]),
+}
+
+impl G1Coords {
+    /// Parse the point: `z = 1` is dropped, any other `z` is normalized and
+    /// `z = 0` is the point at infinity. The result must lie on the curve and
+    /// in the prime-order subgroup.
+    pub fn to_point<G>(&self) -> Result<G, SnarkjsError>
+    where
+        G: FromXy,
+        G::BaseField: PrimeField,
+    {
+        match self {
+            Self::Affine(xy) => g1_from_dec(xy),
+            Self::Projective(xyz) => g1_from_coords(xyz, "G1"),
+        }
+    }
+}
+
+/// Parse a G2 point from nested string array [[x.c0, x.c1], [y.c0, y.c1]]
+/// (inverse of `g2_xyxy`). The point must lie on the curve and in the
+/// prime-order subgroup.
+pub fn g2_from_dec<G>(xy: &[[String; 2]; 2]) -> Result<G, SnarkjsError>
+where
+    G: FromXy,
+    G::BaseField: AsFp2,
+{
+    ensure_fp2::<G::BaseField>()?;
+    let fp2 = |c: &[String; 2]| -> Result<G::BaseField, SnarkjsError> {
+        G::BaseField::from_c0_c1(parse_field_checked(&c[0])?, parse_field_checked(&c[1])?)
+    };
+    checked_point(fp2(&xy[0])?, fp2(&xy[1])?)
+}
+
+/// Rebuild a G2 point from its compressed form: the x coordinate
+/// `[x.c0, x.c1]` and whether y is the lexicographically largest of the two
+/// roots `±y` (the sign bit of arkworks' compressed encoding). The missing y
+/// is found by solving the curve equation. The point must lie in the
+/// prime-order subgroup; an x without a matching y fails with
+/// `SnarkjsError::NotOnCurve`.
+pub fn g2_from_compressed<P>(x: &[String; 2], y_is_largest: bool) -> Result<Affine<P>, SnarkjsError>
+where
+    P: SWCurveConfig,
+    P::BaseField: AsFp2,
+{
+    ensure_fp2::<P::BaseField>()?;
+    let x = P::BaseField::from_c0_c1(parse_field_checked(&x[0])?, parse_field_checked(&x[1])?)?;
+    let p =
+        Affine::<P>::get_point_from_x_unchecked(x, y_is_largest).ok_or(SnarkjsError::NotOnCurve)?;
+    checked_point(p.x, p.y)
+}
+
+/// Pairing target (Fp12) element as snarkjs' `vk_alphabeta_12`:
+/// `[[c0.c0, c0.c1, c0.c2], [c1.c0, c1.c1, c1.c2]]`, each an Fp2 `[c0, c1]`.
+/// Fails with `SnarkjsError::UnsupportedGt` if `F` is not a degree-12
+/// extension (e.g. MNT4/MNT6).
+#[cfg(any(feature = "std", feature = "bn254"))]
+pub(crate) fn gt_to_dec<F: Field>(f: &F) -> Result<[[[String; 2]; 3]; 2], SnarkjsError> {
+    if F::extension_degree() != 12 {
+        return Err(SnarkjsError::UnsupportedGt);
+    }
+    // Base field elements come in tower order: c0.c0.c0, c0.c0.c1, c0.c1.c0, ...
+    let mut c = f.to_base_prime_field_elements().map(|x| f_to_dec(&x));
+    let mut fp2 = || [c.next().unwrap(), c.next().unwrap()];
+    let mut fp6 = || [fp2(), fp2(), fp2()];
+    Ok([fp6(), fp6()])
+}
+
+/// Parse snarkjs' `vk_alphabeta_12` (inverse of `gt_to_dec`). `field` names
+/// the JSON key for `SnarkjsError::InvalidShape`.
+pub(crate) fn gt_from_dec<F: Field>(
+    v: &[Vec<Vec<String>>],
+    field: &'static str,
+) -> Result<F, SnarkjsError> {
+    if F::extension_degree() != 12 {
+        return Err(SnarkjsError::UnsupportedGt);
+    }
+    let shape_ok = v.len() == 2
+        && v.iter()
+            .all(|fp6| fp6.len() == 3 && fp6.iter().all(|fp2| fp2.len() == 2));
+    if !shape_ok {
+        return Err(SnarkjsError::InvalidShape { field });
+    }
+    let elems = v
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|s| parse_field_checked::<F::BasePrimeField>(s))
+        .collect::<Result<Vec<_>, _>>()?;
+    F::from_base_prime_field_elems(elems).ok_or(SnarkjsError::InvalidShape { field })
+}
+
+/// Parse a snarkjs G1 point: affine `[x, y]` or Jacobian `[x, y, z]`.
+/// `field` names the JSON key for `SnarkjsError::InvalidShape`.
+pub(crate) fn g1_from_coords<G>(p: &[String], field: &'static str) -> Result<G, SnarkjsError>
+where
+    G: FromXy,
+    G::BaseField: PrimeField,
+{
+    match p {
+        [x, y] => affine_g1(parse_field_checked(x)?, parse_field_checked(y)?),
+        [x, y, z] => normalize(
+            parse_field_checked(x)?,
+            parse_field_checked(y)?,
+            parse_field_checked(z)?,
+        ),
+        _ => Err(SnarkjsError::InvalidShape { field }),
+    }
+}
+
+/// Parse a snarkjs G2 point: affine `[[x0, x1], [y0, y1]]` or Jacobian
+/// `[[x0, x1], [y0, y1], [z0, z1]]`.
+pub(crate) fn g2_from_coords<G>(p: &[Vec<String>], field: &'static str) -> Result<G, SnarkjsError>
+where
+    G: FromXy,
+    G::BaseField: AsFp2,
+{
+    ensure_fp2::<G::BaseField>()?;
+    let fp2 = |c: &Vec<String>| -> Result<G::BaseField, SnarkjsError> {
+        let [c0, c1] = c.as_slice() else {
+            return Err(SnarkjsError::InvalidShape { field });
+        };
+        G::BaseField::from_c0_c1(parse_field_checked(c0)?, parse_field_checked(c1)?)
+    };
+    match p {
+        [x, y] => checked_point(fp2(x)?, fp2(y)?),
+        [x, y, z] => normalize(fp2(x)?, fp2(y)?, fp2(z)?),
+        _ => Err(SnarkjsError::InvalidShape { field }),
+    }
+}
+
+/// Convert Jacobian `(x, y, z)` to an affine point (`z = 0` is infinity).
+fn normalize<G: FromXy>(
+    x: G::BaseField,
+    y: G::BaseField,
+    z: G::BaseField,
+) -> Result<G, SnarkjsError> {
+    let Some(z_inv) = z.inverse() else {
+        return Ok(G::zero());
+    };
+    if z.is_one() {
+        return checked_point(x, y);
+    }
+    let z_inv2 = z_inv.square();
+    checked_point(x * z_inv2, y * z_inv2 * z_inv)
+}
+
+/// `checked_point` for a G1 point in snarkjs' affine form, where `(0, 0)`
+/// stands for the point at infinity (see `ic_xy`).
+fn affine_g1<G>(x: G::BaseField, y: G::BaseField) -> Result<G, SnarkjsError>
+where
+    G: FromXy,
+    G::BaseField: PrimeField,
+{
+    if x.is_zero() && y.is_zero() {
+        return Ok(G::zero());
+    }
+    checked_point(x, y)
+}
+
+/// Build a point from affine coordinates, rejecting points off the curve or
+/// outside the prime-order subgroup (small-subgroup attacks on untrusted keys).
+pub(crate) fn checked_point<G: FromXy>(
+    x: G::BaseField,
+    y: G::BaseField,
+) -> Result<G, SnarkjsError> {
+    let p = G::from_xy_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(SnarkjsError::NotOnCurve);
+    }
+    if !p.is_in_correct_subgroup() {
+        return Err(SnarkjsError::NotInSubgroup);
+    }
+    Ok(p)
+}
+
+/// Write `value` as pretty-printed JSON to `out_path`, creating parent
+/// directories as needed.
+#[cfg(feature = "std")]
+pub(crate) fn write_json_pretty<P, T>(out_path: P, value: &T) -> Result<(), SnarkjsError>
+where
+    P: AsRef<Path>,
+    T: Serialize + ?Sized,
+{
+    write_json_pretty_with(out_path, value, &ExportOptions::default())
+}
+
+/// Same as `write_json_pretty`, formatted according to `options`.
+#[cfg(feature = "std")]
+pub(crate) fn write_json_pretty_with<P, T>(
+    out_path: P,
+    value: &T,
+    options: &ExportOptions,
+) -> Result<(), SnarkjsError>
+where
+    P: AsRef<Path>,
+    T: Serialize + ?Sized,
+{
+    create_parent_dir(out_path.as_ref())?;
+    write_json_file_with(out_path, value, options)
+}
+
+/// Create the parent directories of `out_path` if they do not exist yet.
+#[cfg(feature = "std")]
+pub(crate) fn create_parent_dir(out_path: &Path) -> Result<(), SnarkjsError> {
+    if let Some(parent) = out_path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent).map_err(|e| with_path(e.into(), parent))?;
+    }
+    Ok(())
+}
+
+/// Write `value` as pretty-printed JSON to `out_path`, assuming the parent
+/// directory already exists.
+#[cfg(feature = "std")]
+pub(crate) fn write_json_file<P, T>(out_path: P, value: &T) -> Result<(), SnarkjsError>
+where
+    P: AsRef<Path>,
+    T: Serialize + ?Sized,
+{
+    write_json_file_with(out_path, value, &ExportOptions::default())
+}
+
+/// Same as `write_json_file`, formatted according to `options`.
+#[cfg(feature = "std")]
+pub(crate) fn write_json_file_with<P, T>(
+    out_path: P,
+    value: &T,
+    options: &ExportOptions,
+) -> Result<(), SnarkjsError>
+where
+    P: AsRef<Path>,
+    T: Serialize + ?Sized,
+{
+    let out_path = out_path.as_ref();
+    let write = || -> Result<(), SnarkjsError> {
+        let file = std::io::BufWriter::new(File::create(out_path)?);
+        write_json_to(file, value, options)?;
+        Ok(())
+    };
+    write().map_err(|e| with_path(e, out_path))
+}
+
+/// Write `value` as pretty-printed JSON to `writer`, formatted according to
+/// `options`. The writer is flushed and handed back, so encoders wrapping a
+/// file can be finished by the caller.
+#[cfg(feature = "std")]
+pub(crate) fn write_json_to<W, T>(
+    writer: W,
+    value: &T,
+    options: &ExportOptions,
+) -> Result<W, SnarkjsError>
+where
+    W: Write,
+    T: Serialize + ?Sized,
+{
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(options.indent.as_bytes());
+    let mut serializer = serde_json::Serializer::with_formatter(writer, formatter);
+    if options.sort_keys {
+        SortedKeys(&serde_json::to_value(value)?).serialize(&mut serializer)?;
+    } else {
+        value.serialize(&mut serializer)?;
+    }
+    let mut writer = serializer.into_inner();
+    writer.flush()?;
+    Ok(writer)
+}
+
+/// A JSON value serialized with the keys of every object in sorted order
+/// (see `ExportOptions::sort_keys`), whatever the map type of `Value`.
+#[cfg(feature = "std")]
+struct SortedKeys<'a>(&'a Value);
+
+#[cfg(feature = "std")]
+impl Serialize for SortedKeys<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Object(map) => {
+                let sorted: BTreeMap<_, _> = map.iter().map(|(k, v)| (k, SortedKeys(v))).collect();
+                sorted.serialize(serializer)
+            }
+            Value::Array(items) => serializer.collect_seq(items.iter().map(SortedKeys)),
+            value => value.serialize(serializer),
+        }
+    }
+}
+
+/// Prefix an I/O or JSON error with the file it occurred on, so a failed
+/// write (e.g. disk full) names its output file. I/O failures surfacing
+/// through `serde_json` are reported as `SnarkjsError::Io`, keeping their
+/// `ErrorKind`.
+#[cfg(feature = "std")]
+pub(crate) fn with_path(e: SnarkjsError, path: &Path) -> SnarkjsError {
+    let e = match e {
+        SnarkjsError::Serde(e) if e.is_io() => SnarkjsError::Io(e.into()),
+        e => e,
+    };
+    let path = path.display();
+    match e {
+        SnarkjsError::Io(e) => {
+            SnarkjsError::Io(std::io::Error::new(e.kind(), format!("{path}: {e}")))
+        }
+        SnarkjsError::Serde(e) => {
+            SnarkjsError::Serde(serde::ser::Error::custom(format!("{path}: {e}")))
+        }
+        other => other,
+    }
 }
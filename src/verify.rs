@@ -0,0 +1,302 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ec::{AffineRepr, CurveGroup, pairing::Pairing};
+use ark_ff::{PrimeField, Zero};
+use ark_groth16::{Proof, VerifyingKey};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+use crate::error::SnarkjsError;
+use crate::export_proof::ProofJson;
+use crate::export_vk::VkJson;
+use crate::import_proof::{ProofFile, proof_from_file};
+use crate::import_vk::import_vk_from_str;
+use crate::public_signals::PublicSignals;
+use crate::snarkjs_common::{
+    AsFp2, CurveId, CurveTag, FromXy, g1_from_coords, g1_from_dec, g2_from_coords, g2_from_dec,
+    parse_fields,
+};
+
+/// Verify a Groth16 proof from the three snarkjs files:
+/// `verification_key.json`, `proof.json` and `public.json`.
+///
+/// Before the pairing check, the number of public inputs is compared across
+/// the files: `nPublic` of the key, `publicSignals` of the proof (if it has
+/// that key; snarkjs' own `proof.json` does not) and the length of
+/// `public.json`. Any disagreement fails with
+/// `SnarkjsError::InconsistentPublicCount` listing the three counts.
+/// Returns `Ok(false)` for a well-formed proof that does not verify.
+#[cfg(feature = "std")]
+pub fn verify_snarkjs<E>(
+    vk_path: impl AsRef<Path>,     // accepts &str, String, Path, PathBuf
+    proof_path: impl AsRef<Path>,  // each path may be a different type
+    public_path: impl AsRef<Path>, // (e.g. a `PathBuf` and two `&str`)
+) -> Result<bool, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    verify_snarkjs_from_str::<E>(
+        &fs::read_to_string(vk_path)?,
+        &fs::read_to_string(proof_path)?,
+        &fs::read_to_string(public_path)?,
+    )
+}
+
+/// Same as `verify_snarkjs`, given the JSON text of the three files.
+pub fn verify_snarkjs_from_str<E>(
+    vk_json: &str,
+    proof_json: &str,
+    public_json: &str,
+) -> Result<bool, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let vk = import_vk_from_str::<E>(vk_json)?;
+    let proof_file: ProofFile = serde_json::from_str(proof_json)?;
+    let (proof, _) = proof_from_file::<E>(&proof_file)?;
+    let public: PublicSignals<E::ScalarField> = serde_json::from_str(public_json)?;
+
+    // `import_vk` already checked IC against nPublic
+    let n_vk = vk.gamma_abc_g1.len() - 1;
+    let n_proof = proof_file.n_public_signals();
+    if n_vk != public.len() || n_proof.is_some_and(|n| n != public.len()) {
+        return Err(SnarkjsError::InconsistentPublicCount {
+            vk: n_vk,
+            proof: n_proof,
+            public: public.len(),
+        });
+    }
+
+    Ok(groth16_check(&vk, &proof, &public))
+}
+
+/// Verify a Groth16 proof given as this crate's in-memory `ProofJson`
+/// against a `VkJson`, with the pairing check implemented here rather than
+/// by `ark_groth16`'s verifier (usable without `std`, e.g. on WASM).
+///
+/// Points are parsed with the usual curve and subgroup checks. Fails with
+/// `SnarkjsError::CurveMismatch` if either side is for another curve and
+/// with `SnarkjsError::IcLengthMismatch` if `IC` does not hold `nPublic + 1`
+/// points, or with `SnarkjsError::InconsistentPublicCount` if
+/// `publicSignals` does not hold `nPublic` values. Returns
+/// `Ok(false)` for a well-formed proof that does not verify. Use
+/// `verify_detailed` to learn which check failed.
+pub fn verify_from_json<E>(vk: &VkJson, proof: &ProofJson) -> Result<bool, SnarkjsError>
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    if let Some(found) = other_curve::<E>(vk, proof) {
+        return Err(SnarkjsError::CurveMismatch {
+            expected: E::NAME,
+            found,
+        });
+    }
+    if vk.ic.len().checked_sub(1) != Some(vk.n_public) {
+        return Err(SnarkjsError::IcLengthMismatch {
+            n_public: vk.n_public,
+            ic_len: vk.ic.len(),
+        });
+    }
+    let n_public = proof.publicSignals.len();
+    if n_public != vk.n_public {
+        return Err(SnarkjsError::InconsistentPublicCount {
+            vk: vk.n_public,
+            proof: Some(n_public),
+            public: n_public,
+        });
+    }
+
+    let public = parse_fields::<E::ScalarField>(&proof.publicSignals)?;
+    match verify_detailed::<E>(vk, proof, &public) {
+        VerifyResult::Valid => Ok(true),
+        VerifyResult::PairingCheckFailed => Ok(false),
+        VerifyResult::NotOnCurve { .. } => Err(SnarkjsError::NotOnCurve),
+        VerifyResult::NotInSubgroup { .. } => Err(SnarkjsError::NotInSubgroup),
+        VerifyResult::Invalid { error, .. } => Err(error),
+        // Ruled out above
+        VerifyResult::CurveMismatch { found } => Err(SnarkjsError::CurveMismatch {
+            expected: E::NAME,
+            found,
+        }),
+        VerifyResult::PublicCountMismatch { vk, proof, public } => {
+            Err(SnarkjsError::InconsistentPublicCount { vk, proof, public })
+        }
+    }
+}
+
+/// Outcome of `verify_detailed`: either the proof verifies, or the first
+/// check it failed. `field` names the JSON entry at fault (`"vk_alpha_1"`,
+/// `"IC"`, `"pi_a"`, ...).
+#[derive(Debug)]
+pub enum VerifyResult {
+    /// The proof verifies.
+    Valid,
+    /// The key or the proof records a curve other than the one verified on.
+    CurveMismatch { found: String },
+    /// The number of public inputs differs between the key's `nPublic`,
+    /// the proof's `publicSignals` (`None` if it has none) and `public`.
+    PublicCountMismatch {
+        vk: usize,
+        proof: Option<usize>,
+        public: usize,
+    },
+    /// A point does not satisfy the curve equation.
+    NotOnCurve { field: &'static str },
+    /// A point is on the curve but outside the prime-order subgroup.
+    NotInSubgroup { field: &'static str },
+    /// Any other malformed entry: a coordinate that is not a field
+    /// element, a point with the wrong shape or at infinity, or an `IC`
+    /// whose length does not match `nPublic`.
+    Invalid {
+        field: &'static str,
+        error: SnarkjsError,
+    },
+    /// Everything is well-formed, but the pairing equation does not hold:
+    /// the proof is for another statement, key or witness.
+    PairingCheckFailed,
+}
+
+impl VerifyResult {
+    /// Whether the proof verified.
+    pub fn is_valid(&self) -> bool {
+        matches!(self, VerifyResult::Valid)
+    }
+}
+
+impl fmt::Display for VerifyResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyResult::Valid => write!(f, "proof is valid"),
+            VerifyResult::CurveMismatch { found } => write!(f, "file is for curve {found}"),
+            VerifyResult::PublicCountMismatch { vk, proof, public } => {
+                write!(f, "public input count: verifying key has {vk}")?;
+                if let Some(proof) = proof {
+                    write!(f, ", proof has {proof}")?;
+                }
+                write!(f, ", {public} given")
+            }
+            VerifyResult::NotOnCurve { field } => write!(f, "{field}: point is not on the curve"),
+            VerifyResult::NotInSubgroup { field } => {
+                write!(f, "{field}: point is not in the prime-order subgroup")
+            }
+            VerifyResult::Invalid { field, error } => write!(f, "{field}: {error}"),
+            VerifyResult::PairingCheckFailed => write!(f, "pairing check failed"),
+        }
+    }
+}
+
+/// Verify a Groth16 proof like `verify_from_json`, against the public
+/// inputs `public`, and report which check failed instead of a bare
+/// `false` or error. For debugging proofs that do not verify, e.g. after a
+/// circom/arkworks mismatch.
+///
+/// Checks run in order: curves, public input counts, the key's points, the
+/// proof's points, then the pairing equation. Only the number of the
+/// proof's `publicSignals` is compared (and only if it has any); the values
+/// checked are those of `public`.
+pub fn verify_detailed<E>(vk: &VkJson, proof: &ProofJson, public: &[E::ScalarField]) -> VerifyResult
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let check = || -> Result<bool, VerifyResult> {
+        if let Some(found) = other_curve::<E>(vk, proof) {
+            return Err(VerifyResult::CurveMismatch { found });
+        }
+        if vk.ic.len().checked_sub(1) != Some(vk.n_public) {
+            return Err(VerifyResult::Invalid {
+                field: "IC",
+                error: SnarkjsError::IcLengthMismatch {
+                    n_public: vk.n_public,
+                    ic_len: vk.ic.len(),
+                },
+            });
+        }
+        let n_proof = (!proof.publicSignals.is_empty()).then_some(proof.publicSignals.len());
+        if vk.n_public != public.len() || n_proof.is_some_and(|n| n != public.len()) {
+            return Err(VerifyResult::PublicCountMismatch {
+                vk: vk.n_public,
+                proof: n_proof,
+                public: public.len(),
+            });
+        }
+
+        let vk = VerifyingKey::<E> {
+            alpha_g1: at("vk_alpha_1", g1_from_dec(&vk.vk_alpha_1))?,
+            beta_g2: at("vk_beta_2", g2_from_dec(&vk.vk_beta_2))?,
+            gamma_g2: at("vk_gamma_2", g2_from_dec(&vk.vk_gamma_2))?,
+            delta_g2: at("vk_delta_2", g2_from_dec(&vk.vk_delta_2))?,
+            gamma_abc_g1: at("IC", vk.ic.iter().map(g1_from_dec).collect())?,
+        };
+        let pi_b: Vec<Vec<String>> = proof.pi_b.iter().map(|c| c.to_vec()).collect();
+        let proof = Proof::<E> {
+            a: at("pi_a", g1_from_coords(&proof.pi_a, "pi_a"))?,
+            b: at("pi_b", g2_from_coords(&pi_b, "pi_b"))?,
+            c: at("pi_c", g1_from_coords(&proof.pi_c, "pi_c"))?,
+        };
+        Ok(groth16_check(&vk, &proof, public))
+    };
+    match check() {
+        Ok(true) => VerifyResult::Valid,
+        Ok(false) => VerifyResult::PairingCheckFailed,
+        Err(failed) => failed,
+    }
+}
+
+/// The curve named by `vk` or `proof` if it is not `E`'s.
+fn other_curve<E: CurveTag>(vk: &VkJson, proof: &ProofJson) -> Option<String> {
+    [vk.curve, proof.curve]
+        .into_iter()
+        .find(|curve| curve.name().parse::<CurveId>().ok() != Some(E::ID))
+        .map(|curve| curve.name().into())
+}
+
+/// Attach `field` to a point parsing error as a `VerifyResult`.
+fn at<T>(field: &'static str, parsed: Result<T, SnarkjsError>) -> Result<T, VerifyResult> {
+    parsed.map_err(|error| match error {
+        SnarkjsError::NotOnCurve => VerifyResult::NotOnCurve { field },
+        SnarkjsError::NotInSubgroup => VerifyResult::NotInSubgroup { field },
+        error => VerifyResult::Invalid { field, error },
+    })
+}
+
+/// The Groth16 check `e(A, B) = e(α, β) · e(vk_x, γ) · e(C, δ)` with
+/// `vk_x = IC[0] + Σ public[i] · IC[i + 1]`, as a single multi-pairing
+/// `e(A, B) · e(-α, β) · e(-vk_x, γ) · e(-C, δ) = 1`. The caller has checked
+/// that `public` holds one value per `IC` point after the first.
+pub(crate) fn groth16_check<E: Pairing>(
+    vk: &VerifyingKey<E>,
+    proof: &Proof<E>,
+    public: &[E::ScalarField],
+) -> bool {
+    let vk_x = vk.gamma_abc_g1[1..]
+        .iter()
+        .zip(public)
+        .fold(vk.gamma_abc_g1[0].into_group(), |acc, (ic, x)| {
+            acc + *ic * x
+        });
+    let g1 = [
+        proof.a,
+        (-vk.alpha_g1.into_group()).into_affine(),
+        (-vk_x).into_affine(),
+        (-proof.c.into_group()).into_affine(),
+    ];
+    let g2 = [proof.b, vk.beta_g2, vk.gamma_g2, vk.delta_g2];
+    E::multi_pairing(g1, g2).is_zero()
+}
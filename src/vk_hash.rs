@@ -0,0 +1,137 @@
+use alloc::string::String;
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_groth16::VerifyingKey;
+#[cfg(feature = "std")]
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+use crate::error::SnarkjsError;
+use crate::export_vk::VkJson;
+use crate::snarkjs_common::{AsFp2, ensure_fp2, hex};
+
+/// Compute a keccak256 fingerprint of a Groth16 verifying key.
+///
+/// This encoding is specific to this crate: snarkjs and circom define no vk
+/// hash, so the value only matches hashes computed by this function (or a
+/// reimplementation of the layout below), not any external registry.
+///
+/// Hashed bytes: `nPublic` as a 32-byte big-endian word, followed by every
+/// coordinate as a big-endian integer of the base field width, in the key
+/// order of `verification_key.json`: `vk_alpha_1`, `vk_beta_2`, `vk_gamma_2`,
+/// `vk_delta_2`, `IC`. G2 coordinates are hashed as `x.c0, x.c1, y.c0, y.c1`
+/// (same as `g2_xyxy`). An identity `IC` entry is hashed as `(0, 0)`, its
+/// form in the exported JSON.
+///
+/// Fails with `SnarkjsError::UnsupportedG2` if G2 is not over Fp2 (e.g. MNT6)
+/// and with `SnarkjsError::PointAtInfinity` if `alpha`, `beta`, `gamma` or
+/// `delta` is the identity.
+pub fn vk_hash<E>(vk: &VerifyingKey<E>, n_public: usize) -> Result<[u8; 32], SnarkjsError>
+where
+    E: Pairing,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    ensure_fp2::<<E::G2Affine as AffineRepr>::BaseField>()?;
+    let mut hasher = Keccak256::new();
+
+    let mut n = [0u8; 32];
+    n[24..].copy_from_slice(&(n_public as u64).to_be_bytes());
+    hasher.update(n);
+
+    if vk.alpha_g1.is_zero() {
+        return Err(SnarkjsError::PointAtInfinity);
+    }
+    hash_g1(&mut hasher, &vk.alpha_g1);
+    hash_g2(&mut hasher, &vk.beta_g2)?;
+    hash_g2(&mut hasher, &vk.gamma_g2)?;
+    hash_g2(&mut hasher, &vk.delta_g2)?;
+    for p in &vk.gamma_abc_g1 {
+        hash_g1(&mut hasher, p);
+    }
+
+    Ok(hasher.finalize().into())
+}
+
+/// `vk_hash` as a `0x`-prefixed lowercase hex string.
+pub fn vk_hash_hex<E>(vk: &VerifyingKey<E>, n_public: usize) -> Result<String, SnarkjsError>
+where
+    E: Pairing,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let mut s = String::from("0x");
+    s.push_str(&hex(&vk_hash::<E>(vk, n_public)?));
+    Ok(s)
+}
+
+/// Whether two verifying keys are identical, point for point. Unlike
+/// comparing `vk_hash` values this needs no `nPublic` and accepts keys with
+/// points at infinity.
+pub fn vk_equal<E: Pairing>(a: &VerifyingKey<E>, b: &VerifyingKey<E>) -> bool {
+    a == b
+}
+
+/// Whether two snarkjs verifying keys describe the same key: protocol, curve,
+/// `nPublic` and every point must match. The optional `vkHash`,
+/// `fieldModulus` and `vk_alphabeta_12` entries are derived from those and are
+/// not compared, nor is `arkSnarkjsVersion`; key order and whitespace of the
+/// files they came from play no role.
+pub fn vk_json_equal(a: &VkJson, b: &VkJson) -> bool {
+    a.protocol == b.protocol
+        && a.curve == b.curve
+        && a.n_public == b.n_public
+        && a.vk_alpha_1 == b.vk_alpha_1
+        && a.vk_beta_2 == b.vk_beta_2
+        && a.vk_gamma_2 == b.vk_gamma_2
+        && a.vk_delta_2 == b.vk_delta_2
+        && a.ic == b.ic
+}
+
+/// sha256 of `bytes` as lowercase hex without a prefix (the form listed in
+/// `manifest.json`).
+#[cfg(feature = "std")]
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    hex(&Sha256::digest(bytes))
+}
+
+/// Check `bytes` against an expected sha256 given as hex (case-insensitive,
+/// optional `0x` prefix).
+#[cfg(feature = "std")]
+pub(crate) fn check_sha256(bytes: &[u8], expected: &str) -> Result<(), SnarkjsError> {
+    let found = sha256_hex(bytes);
+    let want = expected.strip_prefix("0x").unwrap_or(expected);
+    if !want.eq_ignore_ascii_case(&found) {
+        return Err(SnarkjsError::ChecksumMismatch {
+            expected: expected.into(),
+            found,
+        });
+    }
+    Ok(())
+}
+
+fn hash_g1<G>(hasher: &mut Keccak256, p: &G)
+where
+    G: AffineRepr,
+    G::BaseField: PrimeField,
+{
+    // The identity (an unused input's IC entry) hashes as (0, 0), as in the JSON
+    let (x, y) = p
+        .xy()
+        .unwrap_or((G::BaseField::zero(), G::BaseField::zero()));
+    hasher.update(x.into_bigint().to_bytes_be());
+    hasher.update(y.into_bigint().to_bytes_be());
+}
+
+fn hash_g2<G>(hasher: &mut Keccak256, p: &G) -> Result<(), SnarkjsError>
+where
+    G: AffineRepr,
+    G::BaseField: AsFp2,
+{
+    let (x, y) = p.xy().ok_or(SnarkjsError::PointAtInfinity)?;
+    for c in [x.c0_c1()?, y.c0_c1()?] {
+        hasher.update(c.0.into_bigint().to_bytes_be());
+        hasher.update(c.1.into_bigint().to_bytes_be());
+    }
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use ark_bn254::Bn254;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::verify::verify_snarkjs_from_str;
+
+/// Verify a BN254 Groth16 proof given the three snarkjs JSON documents:
+/// `verification_key.json`, `proof.json` and `public.json` (an array of
+/// decimal or `0x`-hex strings).
+///
+/// Returns `false` both for a proof that does not verify and for malformed
+/// input.
+#[wasm_bindgen]
+pub fn verify_bn254(vk_json: &str, proof_json: &str, public_json: &str) -> bool {
+    verify_snarkjs_from_str::<Bn254>(vk_json, proof_json, public_json).unwrap_or(false)
+}
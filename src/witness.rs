@@ -0,0 +1,130 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_ff::{BigInteger, PrimeField};
+use num_bigint::BigUint;
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+use crate::error::SnarkjsError;
+use crate::snarkjs_common::f_to_dec;
+#[cfg(feature = "std")]
+use crate::snarkjs_common::write_json_pretty;
+
+/// Convert a full assignment to the decimal array of snarkjs'
+/// `wtns export json` (in-memory only).
+///
+/// snarkjs orders the witness as `[1, public..., private...]`; for an
+/// arkworks `ConstraintSystem` that is `instance_assignment` (which starts
+/// with the constant one) followed by `witness_assignment`.
+pub fn witness_to_snarkjs<F: PrimeField>(assignment: &[F]) -> Vec<String> {
+    assignment.iter().map(f_to_dec).collect()
+}
+
+/// Export a full assignment as a snarkjs `witness.json` decimal array.
+/// Writes the file to `out_path` and returns the in-memory array.
+#[cfg(feature = "std")]
+pub fn export_witness<F, P>(
+    assignment: &[F], // [1, public..., private...]
+    out_path: P,      // output path for JSON file
+) -> Result<Vec<String>, SnarkjsError>
+where
+    F: PrimeField,
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    let json = witness_to_snarkjs(assignment);
+
+    write_json_pretty(out_path, &json)?;
+
+    Ok(json)
+}
+
+/// Read a circom/snarkjs binary `.wtns` witness file into field elements.
+/// Same checks as `wtns_from_bytes`.
+#[cfg(feature = "std")]
+pub fn import_wtns<F, P>(path: P) -> Result<Vec<F>, SnarkjsError>
+where
+    F: PrimeField,
+    P: AsRef<Path>, // accepts &str, String, Path, PathBuf
+{
+    wtns_from_bytes(&fs::read(path)?)
+}
+
+/// Parse the bytes of a binary `.wtns` witness file.
+///
+/// Layout (all integers little-endian): magic `"wtns"`, `u32` version,
+/// `u32` section count, then sections of `u32` type and `u64` size. Section 1
+/// holds the field size `n8` (`u32`), the prime (`n8` bytes) and the witness
+/// count (`u32`); section 2 holds the witness, `n8` bytes per element.
+///
+/// Fails with `SnarkjsError::InvalidWtns` on a malformed file or a prime
+/// other than the modulus of `F`, and with `SnarkjsError::InvalidFieldElement`
+/// for an element not below the modulus.
+pub fn wtns_from_bytes<F: PrimeField>(bytes: &[u8]) -> Result<Vec<F>, SnarkjsError> {
+    let mut r = Reader(bytes);
+    if r.take(4)? != b"wtns" {
+        return Err(SnarkjsError::InvalidWtns("missing \"wtns\" magic"));
+    }
+    let _version = r.u32()?;
+    let n_sections = r.u32()?;
+
+    let (mut header, mut data) = (None, None);
+    for _ in 0..n_sections {
+        let kind = r.u32()?;
+        let size = usize::try_from(r.u64()?)
+            .map_err(|_| SnarkjsError::InvalidWtns("section size overflows"))?;
+        let body = r.take(size)?;
+        match kind {
+            1 => header = Some(body),
+            2 => data = Some(body),
+            _ => {} // unknown sections are skipped, as snarkjs does
+        }
+    }
+    let mut header = Reader(header.ok_or(SnarkjsError::InvalidWtns("missing header section"))?);
+    let data = data.ok_or(SnarkjsError::InvalidWtns("missing witness section"))?;
+
+    // The file's prime must be the modulus of F
+    let n8 = header.u32()? as usize;
+    let modulus = F::MODULUS.to_bytes_le();
+    if n8 != modulus.len() || header.take(n8)? != modulus.as_slice() {
+        return Err(SnarkjsError::InvalidWtns("prime does not match the field"));
+    }
+    let n_witness = header.u32()? as usize;
+    if n_witness.checked_mul(n8) != Some(data.len()) {
+        return Err(SnarkjsError::InvalidWtns(
+            "witness section has the wrong size",
+        ));
+    }
+
+    let modulus = BigUint::from_bytes_le(&modulus);
+    data.chunks_exact(n8)
+        .map(|le| {
+            let value = BigUint::from_bytes_le(le);
+            if value >= modulus {
+                return Err(SnarkjsError::InvalidFieldElement(value.to_str_radix(10)));
+            }
+            Ok(F::from_le_bytes_mod_order(le))
+        })
+        .collect()
+}
+
+/// Little-endian cursor over a byte slice; running past the end is an error.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], SnarkjsError> {
+        if self.0.len() < n {
+            return Err(SnarkjsError::InvalidWtns("unexpected end of file"));
+        }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn u32(&mut self) -> Result<u32, SnarkjsError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, SnarkjsError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
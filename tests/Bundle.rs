@@ -0,0 +1,156 @@
+// Single-file bundle: proof + publicSignals + vk, exported and imported back
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_ff::One;
+use ark_groth16::Groth16;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snarkjs::bundle::{export_bundle, import_bundle};
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bn254::{Bn254, Fr};
+
+/// Simple multiplication circuit: enforce x * y = z (z is public).
+#[derive(Clone)]
+struct MulCircuit {
+    x: Option<Fr>, // secret witness
+    y: Option<Fr>, // secret witness
+    z: Fr,         // public input
+}
+
+impl ConstraintSynthesizer<Fr> for MulCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let x = FpVar::<Fr>::new_witness(cs.clone(), || {
+            self.x.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let y = FpVar::<Fr>::new_witness(cs.clone(), || {
+            self.y.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let z = FpVar::<Fr>::new_input(cs, || Ok(self.z))?;
+        (&x * &y).enforce_equal(&z)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_roundtrip_bn254() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+
+        let empty = MulCircuit {
+            x: None,
+            y: None,
+            z: Fr::one(),
+        };
+        let (pk, vk) = Groth16::<Bn254>::setup(empty, &mut rng).unwrap();
+
+        let (x, y) = (Fr::from(641u64), Fr::from(6_700_417u64));
+        let circuit = MulCircuit {
+            x: Some(x),
+            y: Some(y),
+            z: x * y,
+        };
+        let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+        let public_inputs = [x * y];
+
+        let path = "target/test-output/bundle/bn254/bundle.json";
+        export_bundle::<Bn254, _>(&proof, &public_inputs, &vk, path).unwrap();
+
+        // Top-level layout: public signals are stored once, outside `proof`
+        let mut raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert!(raw["proof"].get("publicSignals").is_none());
+        assert_eq!(raw["publicSignals"][0], (x * y).to_string());
+        assert_eq!(raw["vk"]["curve"], "bn128");
+
+        let (proof2, public2, vk2) = import_bundle::<Bn254, _>(path).unwrap();
+        assert_eq!(proof2, proof);
+        assert_eq!(public2, public_inputs);
+        assert_eq!(vk2, vk);
+        assert!(Groth16::<Bn254>::verify(&vk2, &public2, &proof2).unwrap());
+
+        // The public inputs must match the key, checked before writing
+        let bad_path = "target/test-output/bundle/bn254/bad.json";
+        let _ = std::fs::remove_file(bad_path);
+        assert!(matches!(
+            export_bundle::<Bn254, _>(&proof, &[x, y], &vk, bad_path),
+            Err(ark_snarkjs::SnarkjsError::PublicCountMismatch {
+                expected: 1,
+                found: 2
+            })
+        ));
+        assert!(!std::path::Path::new(bad_path).exists());
+
+        // An extra public signal in the file is caught on import
+        raw["publicSignals"]
+            .as_array_mut()
+            .unwrap()
+            .push("0".into());
+        std::fs::write(bad_path, serde_json::to_vec(&raw).unwrap()).unwrap();
+        assert!(matches!(
+            import_bundle::<Bn254, _>(bad_path),
+            Err(ark_snarkjs::SnarkjsError::PublicCountMismatch {
+                expected: 1,
+                found: 2
+            })
+        ));
+
+        #[cfg(feature = "flate2")]
+        {
+            use ark_snarkjs::bundle::{export_bundle_tar_gz, import_bundle_tar_gz};
+
+            let path = "target/test-output/bundle/bn254/bundle.tar.gz";
+            export_bundle_tar_gz::<Bn254, _>(&proof, &public_inputs, &vk, path).unwrap();
+            let (proof3, public3, vk3) = import_bundle_tar_gz::<Bn254, _>(path).unwrap();
+            assert_eq!(proof3, proof);
+            assert_eq!(public3, public_inputs);
+            assert_eq!(vk3, vk);
+            assert!(
+                export_bundle_tar_gz::<Bn254, _>(
+                    &proof,
+                    &[],
+                    &vk,
+                    "target/test-output/bundle/bn254/bad.tar.gz"
+                )
+                .is_err()
+            );
+
+            // Decompressed size is capped
+            let err = ark_snarkjs::bundle::import_bundle_tar_gz_with_limit::<Bn254, _>(path, 1024)
+                .unwrap_err();
+            assert!(matches!(err, ark_snarkjs::SnarkjsError::Io(ref e)
+                if e.kind() == std::io::ErrorKind::InvalidData));
+
+            // A header with a broken checksum is rejected
+            use std::io::{Read, Write};
+            let mut archive = Vec::new();
+            flate2::read::GzDecoder::new(std::fs::File::open(path).unwrap())
+                .read_to_end(&mut archive)
+                .unwrap();
+            archive[0] ^= 1; // first byte of the first entry's name
+            let corrupted = "target/test-output/bundle/bn254/corrupted.tar.gz";
+            let mut gz = flate2::write::GzEncoder::new(
+                std::fs::File::create(corrupted).unwrap(),
+                flate2::Compression::default(),
+            );
+            gz.write_all(&archive).unwrap();
+            gz.finish().unwrap();
+            assert!(import_bundle_tar_gz::<Bn254, _>(corrupted).is_err());
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// arkworks CanonicalSerialize files read back for export (compressed and
+// uncompressed), and snarkjs proofs converted to them
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::CurveGroup;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_serialize::{CanonicalSerialize, Compress};
+use ark_snarkjs::{
+    SnarkjsError, export_proof, export_proof_from_bytes, export_vk, import_proof, proof_from_bytes,
+    read_proof_canonical, read_public_canonical, read_vk_canonical, snarkjs_to_canonical,
+};
+use ark_std::UniformRand;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+
+use ark_bls12_381::{Bls12_381, Fr, G1Projective, G2Projective};
+
+/// Write `value` to `path`, compressed or not.
+fn write_bin<T: CanonicalSerialize>(value: &T, path: &str, compressed: bool) {
+    let mut bytes = Vec::new();
+    if compressed {
+        value.serialize_compressed(&mut bytes).unwrap();
+    } else {
+        value.serialize_uncompressed(&mut bytes).unwrap();
+    }
+    std::fs::create_dir_all("target/test-output/canonical").unwrap();
+    std::fs::write(path, bytes).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_canonical_then_export() {
+        let mut rng = StdRng::seed_from_u64(572);
+        let mut g1 = || G1Projective::rand(&mut rng).into_affine();
+        let (a, c, alpha) = (g1(), g1(), g1());
+        let ic = vec![g1(), g1()];
+        let mut g2 = || G2Projective::rand(&mut rng).into_affine();
+        let proof = Proof::<Bls12_381> { a, b: g2(), c };
+        let vk = VerifyingKey::<Bls12_381> {
+            alpha_g1: alpha,
+            beta_g2: g2(),
+            gamma_g2: g2(),
+            delta_g2: g2(),
+            gamma_abc_g1: ic,
+        };
+        let public = vec![Fr::from(42u64)];
+        let dir = "target/test-output/canonical";
+
+        for compressed in [true, false] {
+            write_bin(&proof, &format!("{dir}/proof.bin"), compressed);
+            write_bin(&vk, &format!("{dir}/vk.bin"), compressed);
+            write_bin(&public, &format!("{dir}/public.bin"), compressed);
+
+            let vk2 = read_vk_canonical::<Bls12_381, _>(format!("{dir}/vk.bin")).unwrap();
+            let proof2 = read_proof_canonical::<Bls12_381, _>(format!("{dir}/proof.bin")).unwrap();
+            let public2 =
+                read_public_canonical::<Bls12_381, _>(format!("{dir}/public.bin")).unwrap();
+            assert_eq!((&vk2, &proof2, &public2), (&vk, &proof, &public));
+
+            // One byte past the end is not silently ignored
+            let path = format!("{dir}/proof_trailing.bin");
+            write_bin(&proof, &path, compressed);
+            let mut bytes = std::fs::read(&path).unwrap();
+            bytes.push(0);
+            std::fs::write(&path, bytes).unwrap();
+            assert!(matches!(
+                read_proof_canonical::<Bls12_381, _>(&path),
+                Err(SnarkjsError::Canonical(_))
+            ));
+        }
+
+        // File to file in one call each
+        export_vk::<Bls12_381, _>(
+            &read_vk_canonical(format!("{dir}/vk.bin")).unwrap(),
+            1,
+            format!("{dir}/verification_key.json"),
+        )
+        .unwrap();
+        export_proof::<Bls12_381, _>(
+            &read_proof_canonical(format!("{dir}/proof.bin")).unwrap(),
+            &public,
+            format!("{dir}/proof.json"),
+        )
+        .unwrap();
+
+        std::fs::write(format!("{dir}/garbage.bin"), [1u8, 2, 3]).unwrap();
+        assert!(matches!(
+            read_vk_canonical::<Bls12_381, _>(format!("{dir}/garbage.bin")),
+            Err(SnarkjsError::Canonical(_))
+        ));
+        assert!(matches!(
+            read_proof_canonical::<Bls12_381, _>(format!("{dir}/missing.bin")),
+            Err(SnarkjsError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_snarkjs_to_canonical() {
+        use ark_bn254::Bn254;
+
+        let dir = "target/test-output/canonical/from_snarkjs";
+        let json = format!(
+            "{}/tests/data/snarkjs/proof.json",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let (proof, _) = import_proof::<Bn254, _>(&json).unwrap();
+
+        for (compress, name, size) in [
+            (Compress::Yes, "proof_compressed.bin", 128),
+            (Compress::No, "proof_uncompressed.bin", 256),
+        ] {
+            let path = format!("{dir}/{name}");
+            let (converted, _) =
+                snarkjs_to_canonical::<Bn254, _, _>(&json, &path, compress).unwrap();
+            assert_eq!(converted, proof);
+
+            let bytes = std::fs::read(&path).unwrap();
+            let mut expected = Vec::new();
+            proof.serialize_with_mode(&mut expected, compress).unwrap();
+            assert_eq!((bytes.len(), &bytes), (size, &expected));
+            assert_eq!(read_proof_canonical::<Bn254, _>(&path).unwrap(), proof);
+        }
+
+        // Import errors surface before anything is written
+        let out = format!("{dir}/not_written.bin");
+        assert!(matches!(
+            snarkjs_to_canonical::<Bn254, _, _>(format!("{dir}/missing.json"), &out, Compress::Yes),
+            Err(SnarkjsError::Io(_))
+        ));
+        assert!(!std::path::Path::new(&out).exists());
+    }
+
+    #[test]
+    fn test_export_proof_from_bytes() {
+        let mut rng = StdRng::seed_from_u64(612);
+        let proof = Proof::<Bls12_381> {
+            a: G1Projective::rand(&mut rng).into_affine(),
+            b: G2Projective::rand(&mut rng).into_affine(),
+            c: G1Projective::rand(&mut rng).into_affine(),
+        };
+        let public = vec![Fr::from(7u64)];
+        let dir = "target/test-output/canonical_bytes";
+        let _ = std::fs::remove_dir_all(dir);
+
+        // Both encodings, same proof.json as export_proof
+        let (mut compressed, mut uncompressed) = (Vec::new(), Vec::new());
+        proof.serialize_compressed(&mut compressed).unwrap();
+        proof.serialize_uncompressed(&mut uncompressed).unwrap();
+        assert_eq!(proof_from_bytes::<Bls12_381>(&compressed).unwrap(), proof);
+        assert_eq!(proof_from_bytes::<Bls12_381>(&uncompressed).unwrap(), proof);
+        let path = format!("{dir}/proof.json");
+        export_proof_from_bytes::<Bls12_381, _>(&uncompressed, &public, &path).unwrap();
+        let expected = format!("{dir}/expected.json");
+        export_proof::<Bls12_381, _>(&proof, &public, &expected).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            std::fs::read_to_string(&expected).unwrap()
+        );
+
+        // A flipped bit in pi_a's x coordinate leaves the point off the curve
+        let mut tampered = uncompressed.clone();
+        tampered[0] ^= 1;
+        let out = format!("{dir}/tampered.json");
+        assert!(matches!(
+            export_proof_from_bytes::<Bls12_381, _>(&tampered, &public, &out),
+            Err(SnarkjsError::NotOnCurve)
+        ));
+        assert!(!std::path::Path::new(&out).exists());
+
+        // Truncated or padded blobs match neither encoding
+        for bytes in [&uncompressed[1..], &[&compressed[..], &[0]].concat()[..]] {
+            assert!(matches!(
+                proof_from_bytes::<Bls12_381>(bytes),
+                Err(SnarkjsError::Canonical(_))
+            ));
+        }
+    }
+}
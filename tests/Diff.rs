@@ -0,0 +1,144 @@
+// diff_exports: semantic differences between two export directories
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_groth16::{Proof, VerifyingKey};
+use ark_snarkjs::{
+    Difference, ExportOptions, SnarkjsError, diff_exports, export_rapidsnark,
+    export_verify_manifest, export_vk_with_options, f_to_dec,
+};
+
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+
+/// Proof and key built from multiples of the generators, `n_public` inputs.
+fn artifacts(k: u64, n_public: usize) -> (Proof<Bn254>, VerifyingKey<Bn254>) {
+    let g1 = |m: u64| (G1Affine::generator() * Fr::from(m)).into_affine();
+    let g2 = |m: u64| (G2Affine::generator() * Fr::from(m)).into_affine();
+    let proof = Proof {
+        a: g1(k),
+        b: g2(k + 1),
+        c: g1(k + 2),
+    };
+    let vk = VerifyingKey {
+        alpha_g1: g1(3),
+        beta_g2: g2(4),
+        gamma_g2: g2(5),
+        delta_g2: g2(6),
+        gamma_abc_g1: (0..=n_public as u64).map(|i| g1(10 + i)).collect(),
+    };
+    (proof, vk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_exports() {
+        let dir = "target/test-output/diff";
+        let public = [Fr::from(1u64), Fr::from(2u64)];
+        let (proof, vk) = artifacts(7, 2);
+        export_verify_manifest(&proof, &public, &vk, format!("{dir}/a")).unwrap();
+
+        // Same values in another layout (projective points, extra fields,
+        // other indentation) are no difference
+        export_rapidsnark(&proof, &public, &vk, format!("{dir}/same")).unwrap();
+        let options = ExportOptions::builder()
+            .indent_width(1)
+            .alphabeta(true)
+            .build();
+        export_vk_with_options(
+            &vk,
+            2,
+            format!("{dir}/same/verification_key.json"),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(
+            diff_exports::<Bn254, _, _>(format!("{dir}/a"), format!("{dir}/same")).unwrap(),
+            []
+        );
+
+        // Another proof, a changed public signal, one more IC point
+        let (proof_b, vk_b) = artifacts(8, 3);
+        let public_b = [Fr::from(1u64), Fr::from(5u64), Fr::from(6u64)];
+        export_verify_manifest(&proof_b, &public_b, &vk_b, format!("{dir}/b")).unwrap();
+        let diffs = diff_exports::<Bn254, _, _>(format!("{dir}/a"), format!("{dir}/b")).unwrap();
+
+        let paths: Vec<_> = diffs
+            .iter()
+            .map(|d| match d {
+                Difference::Value { file, path, .. } | Difference::Count { file, path, .. } => {
+                    (*file, path.as_str())
+                }
+                Difference::Missing { file, .. } => (*file, ""),
+            })
+            .collect();
+        // Every affine coordinate of every proof point differs
+        for point in ["pi_a", "pi_c"] {
+            for i in 0..2 {
+                assert!(paths.contains(&("proof.json", &format!("{point}[{i}]"))));
+            }
+            assert!(!paths.contains(&("proof.json", &format!("{point}[2]")))); // z = 1
+        }
+        assert!(paths.contains(&("proof.json", "pi_b[1][0]")));
+        assert!(
+            !paths
+                .iter()
+                .any(|(f, p)| *f == "verification_key.json" && p.starts_with("vk_"))
+        );
+
+        assert!(diffs.contains(&Difference::Count {
+            file: "public.json",
+            path: String::new(),
+            a: 2,
+            b: 3,
+        }));
+        assert!(diffs.contains(&Difference::Value {
+            file: "public.json",
+            path: "[1]".into(),
+            a: "2".into(),
+            b: "5".into(),
+        }));
+        assert!(!paths.contains(&("public.json", "[0]")));
+        assert!(diffs.contains(&Difference::Count {
+            file: "verification_key.json",
+            path: "IC".into(),
+            a: 3,
+            b: 4,
+        }));
+        assert!(!paths.iter().any(|(_, p)| p.starts_with("IC[")));
+
+        let shown: Vec<_> = diffs.iter().map(ToString::to_string).collect();
+        assert!(shown.contains(&"public.json: 2 entries != 3 entries".to_string()));
+        assert!(shown.contains(&"public.json [1]: 2 != 5".to_string()));
+        assert!(shown.contains(&format!(
+            "proof.json pi_a[0]: {} != {}",
+            f_to_dec(&proof.a.x().unwrap()),
+            f_to_dec(&proof_b.a.x().unwrap())
+        )));
+
+        // Missing files, and files that fail to import
+        std::fs::remove_file(format!("{dir}/b/public.json")).unwrap();
+        let diffs = diff_exports::<Bn254, _, _>(format!("{dir}/a"), format!("{dir}/b")).unwrap();
+        assert!(diffs.contains(&Difference::Missing {
+            file: "public.json",
+            in_a: true,
+        }));
+        std::fs::write(format!("{dir}/b/proof.json"), "{}").unwrap();
+        assert!(matches!(
+            diff_exports::<Bn254, _, _>(format!("{dir}/a"), format!("{dir}/b")),
+            Err(SnarkjsError::Serde(_))
+        ));
+    }
+}
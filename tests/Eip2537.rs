@@ -0,0 +1,104 @@
+// EIP-2537 byte layout for Bls12_381 proofs (64-byte padded Fp, c0 before c1)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use ark_groth16::Proof;
+use ark_snarkjs::{SnarkjsError, to_eip2537_bytes};
+use ark_std::UniformRand;
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bls12_381::{Bls12_381, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+
+/// Decode one 64-byte EIP-2537 field element; the padding must be zero.
+fn fp(bytes: &[u8]) -> Fq {
+    assert_eq!(bytes.len(), 64);
+    assert!(bytes[..16].iter().all(|&b| b == 0), "non-zero padding");
+    Fq::from_be_bytes_mod_order(&bytes[16..])
+}
+
+fn g1(bytes: &[u8]) -> G1Affine {
+    let (x, y) = (fp(&bytes[..64]), fp(&bytes[64..128]));
+    if x.is_zero() && y.is_zero() {
+        return G1Affine::zero();
+    }
+    G1Affine::new(x, y)
+}
+
+fn g2(bytes: &[u8]) -> G2Affine {
+    let c: Vec<Fq> = bytes.chunks(64).map(fp).collect();
+    let (x, y) = (Fq2::new(c[0], c[1]), Fq2::new(c[2], c[3]));
+    if x.is_zero() && y.is_zero() {
+        return G2Affine::zero();
+    }
+    G2Affine::new(x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eip2537_bytes_decode_to_proof() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        let proof = Proof::<Bls12_381> {
+            a: G1Projective::rand(&mut rng).into_affine(),
+            b: G2Projective::rand(&mut rng).into_affine(),
+            c: G1Projective::rand(&mut rng).into_affine(),
+        };
+        let public = [Fr::from(7u64), -Fr::from(1u64)];
+
+        let bytes = to_eip2537_bytes::<Bls12_381>(&proof, &public).unwrap();
+        assert_eq!(bytes.len(), 128 + 256 + 128 + 2 * 32);
+
+        // G1Affine::new / G2Affine::new assert the points are on the curve
+        assert_eq!(g1(&bytes[..128]), proof.a);
+        assert_eq!(g2(&bytes[128..384]), proof.b);
+        assert_eq!(g1(&bytes[384..512]), proof.c);
+        assert_eq!(Fr::from_be_bytes_mod_order(&bytes[512..544]), public[0]);
+        assert_eq!(Fr::from_be_bytes_mod_order(&bytes[544..]), public[1]);
+    }
+
+    #[test]
+    fn test_eip2537_infinity_is_zero() {
+        let proof = Proof::<Bls12_381> {
+            a: G1Affine::zero(),
+            b: G2Affine::zero(),
+            c: G1Affine::generator(),
+        };
+        let bytes = to_eip2537_bytes::<Bls12_381>(&proof, &[]).unwrap();
+        assert!(bytes[..384].iter().all(|&b| b == 0));
+        assert_eq!(g1(&bytes[..128]), G1Affine::zero());
+        assert_eq!(g2(&bytes[128..384]), G2Affine::zero());
+        assert_eq!(g1(&bytes[384..]), G1Affine::generator());
+    }
+
+    #[test]
+    fn test_eip2537_rejects_out_of_range_scalar() {
+        // A non-canonical scalar, bypassing the modular reduction
+        let bad = Fr::new_unchecked(Fr::MODULUS);
+        let proof = Proof::<Bls12_381> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::generator(),
+        };
+        assert!(matches!(
+            to_eip2537_bytes::<Bls12_381>(&proof, &[Fr::from(1u64), bad]),
+            Err(SnarkjsError::ScalarOutOfRange {
+                index: 1,
+                curve: "bls12381"
+            })
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+// ABI-encoded calldata bytes for the Bn254 Solidity verifier / EVM precompiles
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use ark_groth16::Proof;
+use ark_snarkjs::{
+    ExportOptions, SnarkjsError, to_eth_calldata_bytes, to_eth_calldata_bytes_with_options,
+};
+use ark_std::UniformRand;
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+
+/// Split calldata into 32-byte big-endian words.
+fn words(bytes: &[u8]) -> Vec<&[u8]> {
+    assert_eq!(bytes.len() % 32, 0);
+    bytes.chunks(32).collect()
+}
+
+fn g1_at(w: &[&[u8]], i: usize) -> G1Affine {
+    let (x, y) = (
+        Fq::from_be_bytes_mod_order(w[i]),
+        Fq::from_be_bytes_mod_order(w[i + 1]),
+    );
+    if x.is_zero() && y.is_zero() {
+        return G1Affine::zero();
+    }
+    G1Affine::new(x, y)
+}
+
+fn g2_at(w: &[&[u8]], i: usize) -> G2Affine {
+    // EVM order: [x.c1, x.c0, y.c1, y.c0]
+    let f = |j: usize| Fq::from_be_bytes_mod_order(w[i + j]);
+    G2Affine::new(Fq2::new(f(1), f(0)), Fq2::new(f(3), f(2)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calldata_decodes_to_points() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        let proof = Proof::<Bn254> {
+            a: G1Projective::rand(&mut rng).into_affine(),
+            b: G2Projective::rand(&mut rng).into_affine(),
+            c: G1Projective::rand(&mut rng).into_affine(),
+        };
+        let public = [Fr::rand(&mut rng), Fr::from(42u64)];
+
+        let bytes = to_eth_calldata_bytes::<Bn254>(&proof, &public).unwrap();
+        let w = words(&bytes);
+        assert_eq!(w.len(), 8 + public.len());
+
+        assert_eq!(g1_at(&w, 0), proof.a);
+        assert_eq!(g2_at(&w, 2), proof.b);
+        assert_eq!(g1_at(&w, 6), proof.c);
+        assert_eq!(Fr::from_be_bytes_mod_order(w[8]), public[0]);
+        assert_eq!(w[9][31], 42);
+        assert!(w[9][..31].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_calldata_negate_a() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        let proof = Proof::<Bn254> {
+            a: G1Projective::rand(&mut rng).into_affine(),
+            b: G2Projective::rand(&mut rng).into_affine(),
+            c: G1Projective::rand(&mut rng).into_affine(),
+        };
+        let options = ExportOptions {
+            negate_a: true,
+            ..Default::default()
+        };
+        let plain = to_eth_calldata_bytes::<Bn254>(&proof, &[]).unwrap();
+        let negated = to_eth_calldata_bytes_with_options::<Bn254>(&proof, &[], &options).unwrap();
+
+        // -A + A = O, everything after A is untouched
+        let neg_a = g1_at(&words(&negated), 0);
+        assert!((neg_a + proof.a).is_zero());
+        assert_ne!(neg_a, proof.a);
+        assert_eq!(negated[64..], plain[64..]);
+    }
+
+    #[test]
+    fn test_calldata_infinity_is_zero() {
+        let proof = Proof::<Bn254> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::zero(),
+        };
+        let bytes = to_eth_calldata_bytes::<Bn254>(&proof, &[]).unwrap();
+        assert!(bytes[6 * 32..].iter().all(|&b| b == 0));
+        assert_eq!(g1_at(&words(&bytes), 6), proof.c);
+    }
+
+    #[test]
+    fn test_calldata_rejects_out_of_range_scalar() {
+        // A non-canonical scalar, bypassing the modular reduction
+        let bad = Fr::new_unchecked(Fr::MODULUS);
+        let proof = Proof::<Bn254> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::generator(),
+        };
+        assert!(matches!(
+            to_eth_calldata_bytes::<Bn254>(&proof, &[Fr::from(1u64), bad]),
+            Err(SnarkjsError::ScalarOutOfRange {
+                index: 1,
+                curve: "bn128"
+            })
+        ));
+    }
+}
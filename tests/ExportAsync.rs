@@ -0,0 +1,61 @@
+// Async export through tokio::fs produces the same files as the blocking API
+// Run with: cargo test --features tokio --test ExportAsync
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+#![cfg(feature = "tokio")]
+
+use ark_ec::AffineRepr;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_snarkjs::{export_proof, export_proof_async, export_vk, export_vk_async};
+
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_export_async_matches_blocking() {
+        let (g1, g2) = (G1Affine::generator(), G2Affine::generator());
+        let proof = Proof::<Bn254> {
+            a: g1,
+            b: g2,
+            c: -g1,
+        };
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: g1,
+            beta_g2: g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            gamma_abc_g1: vec![g1, g1],
+        };
+        let public = [Fr::from(9u64)];
+        let dir = "target/test-output/async";
+
+        export_proof_async::<Bn254, _>(&proof, &public, format!("{dir}/proof.json"))
+            .await
+            .unwrap();
+        export_vk_async::<Bn254, _>(&vk, 1, format!("{dir}/verification_key.json"))
+            .await
+            .unwrap();
+        export_proof::<Bn254, _>(&proof, &public, format!("{dir}/sync/proof.json")).unwrap();
+        export_vk::<Bn254, _>(&vk, 1, format!("{dir}/sync/verification_key.json")).unwrap();
+
+        for name in ["proof.json", "verification_key.json"] {
+            assert_eq!(
+                std::fs::read(format!("{dir}/{name}")).unwrap(),
+                std::fs::read(format!("{dir}/sync/{name}")).unwrap(),
+                "{name}"
+            );
+        }
+    }
+}
@@ -0,0 +1,197 @@
+// Batch export: several proofs for x * y = z sharing one verifying key, or
+// one statement and its public signals (Bn254)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_snarkjs::export_batch::{
+    export_proof_batch, export_proofs_array, export_shared_public_batch, import_proofs_array,
+};
+use ark_snarkjs::{SnarkjsError, verify_snarkjs};
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_ff::One;
+use ark_groth16::{Groth16, Proof, VerifyingKey};
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bn254::{Bn254, Fr};
+
+/// Simple multiplication circuit: enforce x * y = z (z is public).
+#[derive(Clone)]
+struct MulCircuit {
+    x: Option<Fr>, // secret witness
+    y: Option<Fr>, // secret witness
+    z: Fr,         // public input
+}
+
+impl ConstraintSynthesizer<Fr> for MulCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let x = FpVar::<Fr>::new_witness(cs.clone(), || {
+            self.x.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let y = FpVar::<Fr>::new_witness(cs.clone(), || {
+            self.y.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let z = FpVar::<Fr>::new_input(cs, || Ok(self.z))?;
+        (&x * &y).enforce_equal(&z)?;
+        Ok(())
+    }
+}
+
+type ProofsWithInputs = Vec<(Proof<Bn254>, Vec<Fr>)>;
+
+/// Three proofs of `i * (i + 10)` against one setup, with the verifying key.
+fn three_proofs() -> (VerifyingKey<Bn254>, ProofsWithInputs) {
+    let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+
+    let empty = MulCircuit {
+        x: None,
+        y: None,
+        z: Fr::one(),
+    };
+    let (pk, vk) = Groth16::<Bn254>::setup(empty, &mut rng).unwrap();
+
+    let proofs = (1u64..=3)
+        .map(|i| {
+            let (x, y) = (Fr::from(i), Fr::from(i + 10));
+            let circuit = MulCircuit {
+                x: Some(x),
+                y: Some(y),
+                z: x * y,
+            };
+            let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+            (proof, vec![x * y])
+        })
+        .collect();
+    (vk, proofs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_proof_batch_bn254() {
+        // Three proofs against the same circuit
+        let (vk, proofs) = three_proofs();
+
+        let dir = "target/test-output/batch/bn254";
+        let jsons = export_proof_batch::<Bn254, _>(&vk, &proofs, dir).unwrap();
+
+        assert_eq!(jsons.len(), proofs.len());
+        assert!(
+            std::path::Path::new(dir)
+                .join("verification_key.json")
+                .exists()
+        );
+        for (i, json) in jsons.iter().enumerate() {
+            assert!(
+                std::path::Path::new(dir)
+                    .join(format!("proof_{i}.json"))
+                    .exists()
+            );
+            assert_eq!(json.publicSignals, vec![proofs[i].1[0].to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_proofs_array_roundtrip() {
+        let (vk, proofs) = three_proofs();
+        let path = "target/test-output/batch/proofs.json";
+        let jsons = export_proofs_array::<Bn254, _>(&proofs, path).unwrap();
+        assert_eq!(jsons.len(), 3);
+
+        // [{ proof: { pi_a, ..., curve }, publicSignals: [...] }, ...]
+        let raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let entries = raw.as_array().unwrap();
+        assert_eq!(entries.len(), 3);
+        for (entry, (_, public)) in entries.iter().zip(&proofs) {
+            let keys: Vec<&String> = entry.as_object().unwrap().keys().collect();
+            assert_eq!(keys, ["proof", "publicSignals"]);
+            assert!(entry["proof"].get("publicSignals").is_none());
+            assert_eq!(entry["proof"]["curve"], "bn128");
+            assert_eq!(entry["publicSignals"][0], public[0].to_string());
+        }
+
+        let imported = import_proofs_array::<Bn254, _>(path).unwrap();
+        assert_eq!(imported, proofs);
+        let pvk = Groth16::<Bn254>::process_vk(&vk).unwrap();
+        for (proof, public) in &imported {
+            assert!(Groth16::<Bn254>::verify_with_processed_vk(&pvk, public, proof).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_export_shared_public_batch() {
+        // Same statement z = 36, three different witnesses
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(610);
+        let z = Fr::from(36u64);
+        let setup = MulCircuit {
+            x: None,
+            y: None,
+            z: Fr::one(),
+        };
+        let (pk, vk) = Groth16::<Bn254>::setup(setup, &mut rng).unwrap();
+        let proofs: Vec<_> = [(4u64, 9u64), (6, 6), (12, 3)]
+            .into_iter()
+            .map(|(x, y)| {
+                let circuit = MulCircuit {
+                    x: Some(Fr::from(x)),
+                    y: Some(Fr::from(y)),
+                    z,
+                };
+                Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap()
+            })
+            .collect();
+
+        let dir = "target/test-output/batch/shared_public";
+        let _ = std::fs::remove_dir_all(dir);
+        let jsons = export_shared_public_batch::<Bn254, _>(&vk, &proofs, &[z], dir).unwrap();
+        assert_eq!(jsons.len(), 3);
+
+        // One public.json, proof files without publicSignals
+        let public: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(format!("{dir}/public.json")).unwrap())
+                .unwrap();
+        assert_eq!(public, serde_json::json!(["36"]));
+        for i in 0..3 {
+            let proof_path = format!("{dir}/proof_{i}.json");
+            let raw: serde_json::Value =
+                serde_json::from_str(&std::fs::read_to_string(&proof_path).unwrap()).unwrap();
+            assert!(raw.get("publicSignals").is_none());
+            assert!(
+                verify_snarkjs::<Bn254>(
+                    format!("{dir}/verification_key.json"),
+                    &proof_path,
+                    format!("{dir}/public.json"),
+                )
+                .unwrap()
+            );
+        }
+        assert!(!std::path::Path::new(&format!("{dir}/proof_3.json")).exists());
+
+        // The public inputs must match the key, checked before writing
+        let bad_dir = "target/test-output/batch/shared_public_bad";
+        let _ = std::fs::remove_dir_all(bad_dir);
+        assert!(matches!(
+            export_shared_public_batch::<Bn254, _>(&vk, &proofs, &[z, z], bad_dir),
+            Err(SnarkjsError::PublicCountMismatch {
+                expected: 1,
+                found: 2
+            })
+        ));
+        assert!(!std::path::Path::new(bad_dir).exists());
+    }
+}
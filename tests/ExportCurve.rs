@@ -0,0 +1,111 @@
+// ExportCurve as an extension point: a curve defined outside the crate is
+// exported through export_proof_dyn, built-in curves go through the blanket impl
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::pairing::{MillerLoopOutput, Pairing, PairingOutput};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_groth16::Proof;
+use ark_snarkjs::{
+    CurveId, ExportCurve, SnarkjsError, export_proof, export_proof_dyn, g1_xy, g2_xyxy,
+};
+use ark_std::UniformRand;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+
+use ark_bls12_381::{Bls12_381, Fr};
+
+/// A "downstream" pairing: BLS12-381 under a name this crate does not know.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Custom;
+
+impl Pairing for Custom {
+    type BaseField = <Bls12_381 as Pairing>::BaseField;
+    type ScalarField = Fr;
+    type G1 = <Bls12_381 as Pairing>::G1;
+    type G1Affine = <Bls12_381 as Pairing>::G1Affine;
+    type G1Prepared = <Bls12_381 as Pairing>::G1Prepared;
+    type G2 = <Bls12_381 as Pairing>::G2;
+    type G2Affine = <Bls12_381 as Pairing>::G2Affine;
+    type G2Prepared = <Bls12_381 as Pairing>::G2Prepared;
+    type TargetField = <Bls12_381 as Pairing>::TargetField;
+
+    fn multi_miller_loop(
+        a: impl IntoIterator<Item = impl Into<Self::G1Prepared>>,
+        b: impl IntoIterator<Item = impl Into<Self::G2Prepared>>,
+    ) -> MillerLoopOutput<Self> {
+        MillerLoopOutput(Bls12_381::multi_miller_loop(a, b).0)
+    }
+
+    fn final_exponentiation(mlo: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>> {
+        Bls12_381::final_exponentiation(MillerLoopOutput(mlo.0)).map(|out| PairingOutput(out.0))
+    }
+}
+
+impl ExportCurve for Custom {
+    const NAME: &'static str = "custom381";
+
+    fn g1_to_snarkjs(p: &Self::G1Affine) -> Result<[String; 3], SnarkjsError> {
+        let [x, y] = g1_xy(p)?;
+        Ok([x, y, "1".to_string()])
+    }
+
+    fn g2_to_snarkjs(p: &Self::G2Affine) -> Result<[[String; 2]; 3], SnarkjsError> {
+        let [x, y] = g2_xyxy(p)?;
+        Ok([x, y, ["1".to_string(), "0".to_string()]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_proof_dyn_custom_curve() {
+        let mut rng = StdRng::seed_from_u64(575);
+        let a = <Custom as Pairing>::G1::rand(&mut rng).into_affine();
+        let b = <Custom as Pairing>::G2::rand(&mut rng).into_affine();
+        let c = <Custom as Pairing>::G1::rand(&mut rng).into_affine();
+        let public = [Fr::from(9u64)];
+        let dir = "target/test-output/export_curve";
+
+        let json = export_proof_dyn::<Custom, _>(
+            &Proof { a, b, c },
+            &public,
+            format!("{dir}/custom.json"),
+        )
+        .unwrap();
+        assert_eq!(json.curve, CurveId::Other("custom381"));
+        let raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(format!("{dir}/custom.json")).unwrap())
+                .unwrap();
+        assert_eq!(raw["curve"], "custom381");
+        assert_eq!(raw["publicSignals"][0], "9");
+
+        // Same points through the built-in curve: identical apart from the name
+        let builtin = export_proof::<Bls12_381, _>(
+            &Proof { a, b, c },
+            &public,
+            format!("{dir}/bls12381.json"),
+        )
+        .unwrap();
+        assert_eq!(builtin.curve, CurveId::Bls12381);
+        assert_eq!(
+            (&builtin.pi_a, &builtin.pi_b, &builtin.pi_c),
+            (&json.pi_a, &json.pi_b, &json.pi_c)
+        );
+        assert_eq!(
+            <Bls12_381 as ExportCurve>::g1_to_snarkjs(&<Bls12_381 as Pairing>::G1Affine::zero())
+                .unwrap(),
+            ["0", "1", "0"]
+        );
+    }
+}
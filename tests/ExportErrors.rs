@@ -0,0 +1,108 @@
+// Export errors: failed writes name their file, bad scalars are rejected
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::AffineRepr;
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_snarkjs::{SnarkjsError, export_proof, export_vk, proof_to_snarkjs};
+
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+
+fn proof() -> Proof<Bn254> {
+    Proof {
+        a: G1Affine::generator(),
+        b: G2Affine::generator(),
+        c: G1Affine::generator(),
+    }
+}
+
+/// Verifying key whose JSON is far larger than the 8 KiB write buffer, so the
+/// write fails while `serde_json` is still serializing.
+fn large_vk(n_public: usize) -> VerifyingKey<Bn254> {
+    VerifyingKey {
+        alpha_g1: G1Affine::generator(),
+        beta_g2: G2Affine::generator(),
+        gamma_g2: G2Affine::generator(),
+        delta_g2: G2Affine::generator(),
+        gamma_abc_g1: vec![G1Affine::generator(); n_public + 1],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parent_dir_error_names_path() {
+        let blocker = "target/test-output/export_errors/blocker";
+        std::fs::create_dir_all("target/test-output/export_errors").unwrap();
+        std::fs::write(blocker, b"not a directory").unwrap();
+
+        let err =
+            export_proof::<Bn254, _>(&proof(), &[Fr::from(1u64)], format!("{blocker}/proof.json"))
+                .err()
+                .expect("export must fail");
+        assert!(matches!(err, SnarkjsError::Io(_)), "{err:?}");
+        assert!(err.to_string().contains(blocker), "{err}");
+    }
+
+    #[test]
+    fn test_out_of_range_public_signal() {
+        // A non-canonical scalar, bypassing the modular reduction
+        let bad = Fr::new_unchecked(Fr::MODULUS);
+        assert!(bad.into_bigint() >= Fr::MODULUS);
+        assert!(matches!(
+            proof_to_snarkjs::<Bn254>(&proof(), &[Fr::from(1u64), bad]),
+            Err(SnarkjsError::ScalarOutOfRange {
+                index: 1,
+                curve: "bn128"
+            })
+        ));
+
+        // Nothing is written for a rejected proof
+        let path = "target/test-output/export_errors/out_of_range/proof.json";
+        let _ = std::fs::remove_file(path);
+        assert!(matches!(
+            export_proof::<Bn254, _>(&proof(), &[bad], path),
+            Err(SnarkjsError::ScalarOutOfRange { index: 0, .. })
+        ));
+        assert!(!std::path::Path::new(path).exists());
+    }
+
+    /// `/dev/full` accepts `open` and fails every write with `ENOSPC`, like a
+    /// full disk.
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_disk_full_error_names_path() {
+        // Small file: the error surfaces when the buffer is flushed
+        let err = export_proof::<Bn254, _>(&proof(), &[Fr::from(1u64)], "/dev/full")
+            .err()
+            .expect("export must fail");
+        match &err {
+            SnarkjsError::Io(e) => assert_eq!(e.kind(), std::io::ErrorKind::StorageFull),
+            other => panic!("expected Io, got {other:?}"),
+        }
+        assert!(err.to_string().contains("/dev/full"), "{err}");
+
+        // Large file: the error surfaces inside serde_json and is still
+        // reported as I/O with the path
+        let err = export_vk::<Bn254, _>(&large_vk(500), 500, "/dev/full")
+            .err()
+            .expect("export must fail");
+        match &err {
+            SnarkjsError::Io(e) => assert_eq!(e.kind(), std::io::ErrorKind::StorageFull),
+            other => panic!("expected Io, got {other:?}"),
+        }
+        assert!(err.to_string().contains("/dev/full"), "{err}");
+    }
+}
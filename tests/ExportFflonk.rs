@@ -0,0 +1,120 @@
+// snarkjs fflonk proof.json / verification_key.json layout from explicit components
+// Run with: cargo test --features fflonk --test ExportFflonk
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+#![cfg(feature = "fflonk")]
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_snarkjs::{FflonkProof, FflonkVk, export_fflonk_proof, export_fflonk_vk, f_to_dec};
+use ark_std::UniformRand;
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bn254::{Fr, G1Projective, G2Affine};
+
+const EVALUATIONS: [&str; 16] = [
+    "ql", "qr", "qm", "qo", "qc", "s1", "s2", "s3", "a", "b", "c", "z", "zw", "t1w", "t2w", "inv",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_fflonk_proof() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        let mut g1 = || G1Projective::rand(&mut rng).into_affine();
+        let (c1, c2, w1, w2) = (g1(), g1(), g1(), g1());
+        let e: Vec<Fr> = (0..16).map(|_| Fr::rand(&mut rng)).collect();
+        let proof = FflonkProof {
+            c1,
+            c2,
+            w1,
+            w2,
+            ql: e[0],
+            qr: e[1],
+            qm: e[2],
+            qo: e[3],
+            qc: e[4],
+            s1: e[5],
+            s2: e[6],
+            s3: e[7],
+            a: e[8],
+            b: e[9],
+            c: e[10],
+            z: e[11],
+            zw: e[12],
+            t1w: e[13],
+            t2w: e[14],
+            inv: e[15],
+        };
+
+        let path = "target/test-output/fflonk/proof.json";
+        let json = export_fflonk_proof(&proof, &[Fr::from(33u64)], path).unwrap();
+        assert_eq!(
+            json.polynomials.C1,
+            [f_to_dec(&c1.x), f_to_dec(&c1.y), "1".to_string()]
+        );
+
+        let raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(raw["protocol"], "fflonk");
+        assert_eq!(raw["curve"], "bn128");
+        for key in ["C1", "C2", "W1", "W2"] {
+            assert_eq!(
+                raw["polynomials"][key].as_array().unwrap().len(),
+                3,
+                "{key}"
+            );
+        }
+        for (key, value) in EVALUATIONS.iter().zip(&e) {
+            assert_eq!(raw["evaluations"][key], f_to_dec(value), "{key}");
+        }
+        assert_eq!(raw["publicSignals"][0], "33");
+    }
+
+    #[test]
+    fn test_export_fflonk_vk() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        let vk = FflonkVk {
+            n_public: 2,
+            power: 10,
+            k1: Fr::from(2u64),
+            k2: Fr::from(3u64),
+            w: Fr::rand(&mut rng),
+            w3: Fr::rand(&mut rng),
+            w4: Fr::rand(&mut rng),
+            w8: Fr::rand(&mut rng),
+            wr: Fr::rand(&mut rng),
+            x_2: G2Affine::generator(),
+            c0: G1Projective::rand(&mut rng).into_affine(),
+        };
+
+        let path = "target/test-output/fflonk/verification_key.json";
+        export_fflonk_vk(&vk, path).unwrap();
+        let text = std::fs::read_to_string(path).unwrap();
+        let raw: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+        // snarkjs key order
+        let offsets: Vec<usize> = [
+            "protocol", "curve", "nPublic", "power", "k1", "k2", "w", "w3", "w4", "w8", "wr",
+            "X_2", "C0",
+        ]
+        .iter()
+        .map(|key| text.find(&format!("\"{key}\":")).unwrap())
+        .collect();
+        assert!(offsets.is_sorted(), "{text}");
+        assert_eq!(raw["nPublic"], 2);
+        assert_eq!(raw["k2"], "3");
+        assert_eq!(raw["X_2"][2], serde_json::json!(["1", "0"]));
+    }
+}
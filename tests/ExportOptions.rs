@@ -0,0 +1,366 @@
+// Output formatting through ExportOptions (indentation of pretty JSON)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_groth16::{Proof, VerifyingKey};
+use ark_snarkjs::{
+    CurveNameStyle, ExportOptions, SnarkjsError, export_proof_with_options, export_vk_with_options,
+    import_proof, import_vk,
+};
+use ark_std::UniformRand;
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::{Bn254, Fr, G1Affine, G1Projective, G2Affine};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_indent() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        let proof = Proof::<Bn254> {
+            a: G1Projective::rand(&mut rng).into_affine(),
+            b: G2Affine::generator(),
+            c: G1Affine::generator(),
+        };
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: G1Affine::generator(),
+            beta_g2: G2Affine::generator(),
+            gamma_g2: G2Affine::generator(),
+            delta_g2: G2Affine::generator(),
+            gamma_abc_g1: vec![G1Affine::generator(); 2],
+        };
+        let public = [Fr::from(5u64)];
+        let dir = "target/test-output/options";
+
+        // Default stays two-space, like serde_json::to_writer_pretty
+        let path = format!("{dir}/proof_default.json");
+        let json =
+            export_proof_with_options::<Bn254, _>(&proof, &public, &path, &Default::default())
+                .unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(written, serde_json::to_string_pretty(&json).unwrap());
+        assert_eq!(json.to_string(), written);
+
+        let path = format!("{dir}/proof_4.json");
+        let four = ExportOptions::with_indent_width(4);
+        export_proof_with_options::<Bn254, _>(&proof, &public, &path, &four).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.starts_with("{\n    \"pi_a\""), "{written}");
+
+        let path = format!("{dir}/vk_tab.json");
+        let tab = ExportOptions {
+            indent: "\t".to_string(),
+            ..Default::default()
+        };
+        export_vk_with_options::<Bn254, _>(&vk, 1, &path, &tab).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            written.contains("\n\t\"IC\": [\n\t\t[\n\t\t\t\"1\""),
+            "{written}"
+        );
+        assert!(!written.contains("fieldModulus"), "off by default");
+
+        let path = format!("{dir}/vk_modulus.json");
+        let with_modulus = ExportOptions {
+            field_modulus: true,
+            ..Default::default()
+        };
+        let vk_json = export_vk_with_options::<Bn254, _>(&vk, 1, &path, &with_modulus).unwrap();
+        assert_eq!(vk_json.to_string(), std::fs::read_to_string(&path).unwrap());
+        let raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            raw["fieldModulus"],
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617"
+        );
+    }
+
+    #[test]
+    fn test_curve_name_style() {
+        let proof = Proof::<Bn254> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::generator(),
+        };
+        let vk = VerifyingKey::<Bls12_381> {
+            alpha_g1: ark_bls12_381::G1Affine::generator(),
+            beta_g2: ark_bls12_381::G2Affine::generator(),
+            gamma_g2: ark_bls12_381::G2Affine::generator(),
+            delta_g2: ark_bls12_381::G2Affine::generator(),
+            gamma_abc_g1: vec![ark_bls12_381::G1Affine::generator(); 2],
+        };
+        let dir = "target/test-output/options_curve_name";
+        let curve_of = |path: &str| -> serde_json::Value {
+            serde_json::from_str::<serde_json::Value>(&std::fs::read_to_string(path).unwrap())
+                .unwrap()["curve"]
+                .clone()
+        };
+        let canonical = ExportOptions {
+            curve_name_style: CurveNameStyle::Canonical,
+            ..Default::default()
+        };
+
+        // Default is unchanged: snarkjs names
+        let path = format!("{dir}/proof_snarkjs.json");
+        export_proof_with_options::<Bn254, _>(
+            &proof,
+            &[Fr::from(1u64)],
+            &path,
+            &Default::default(),
+        )
+        .unwrap();
+        assert_eq!(curve_of(&path), "bn128");
+        let path = format!("{dir}/vk_snarkjs.json");
+        export_vk_with_options::<Bls12_381, _>(&vk, 1, &path, &Default::default()).unwrap();
+        assert_eq!(curve_of(&path), "bls12381");
+
+        // Canonical names, still importable
+        let path = format!("{dir}/proof_canonical.json");
+        let json =
+            export_proof_with_options::<Bn254, _>(&proof, &[Fr::from(1u64)], &path, &canonical)
+                .unwrap();
+        assert_eq!(curve_of(&path), "bn254");
+        assert_eq!(json.to_string(), std::fs::read_to_string(&path).unwrap());
+        assert_eq!(import_proof::<Bn254, _>(&path).unwrap().0, proof);
+
+        let path = format!("{dir}/vk_canonical.json");
+        export_vk_with_options::<Bls12_381, _>(&vk, 1, &path, &canonical).unwrap();
+        assert_eq!(curve_of(&path), "bls12-381");
+        assert_eq!(import_vk::<Bls12_381, _>(&path).unwrap(), vk);
+    }
+
+    #[test]
+    fn test_custom_protocol() {
+        let proof = Proof::<Bn254> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::generator(),
+        };
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: G1Affine::generator(),
+            beta_g2: G2Affine::generator(),
+            gamma_g2: G2Affine::generator(),
+            delta_g2: G2Affine::generator(),
+            gamma_abc_g1: vec![G1Affine::generator(); 2],
+        };
+        let dir = "target/test-output/options_protocol";
+        let protocol_of = |path: &str| -> serde_json::Value {
+            serde_json::from_str::<serde_json::Value>(&std::fs::read_to_string(path).unwrap())
+                .unwrap()["protocol"]
+                .clone()
+        };
+        let fork = ExportOptions::builder().protocol("groth16-patched").build();
+        let public = [Fr::from(1u64)];
+
+        // Default stays "groth16"
+        assert_eq!(ExportOptions::default().protocol, "groth16");
+        let path = format!("{dir}/proof_default.json");
+        export_proof_with_options::<Bn254, _>(&proof, &public, &path, &Default::default()).unwrap();
+        assert_eq!(protocol_of(&path), "groth16");
+
+        let path = format!("{dir}/proof_fork.json");
+        let json = export_proof_with_options::<Bn254, _>(&proof, &public, &path, &fork).unwrap();
+        assert_eq!(json.protocol, "groth16-patched");
+        assert_eq!(protocol_of(&path), "groth16-patched");
+        assert!(matches!(
+            import_proof::<Bn254, _>(&path),
+            Err(SnarkjsError::ProtocolMismatch(p)) if p == "groth16-patched"
+        ));
+
+        let path = format!("{dir}/vk_fork.json");
+        let json = export_vk_with_options::<Bn254, _>(&vk, 1, &path, &fork).unwrap();
+        assert_eq!(json.protocol, "groth16-patched");
+        assert_eq!(protocol_of(&path), "groth16-patched");
+    }
+
+    #[test]
+    fn test_g1_affine_pairs() {
+        let proof = Proof::<Bn254> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::zero(),
+        };
+        let dir = "target/test-output/options_g1_affine";
+        let read = |path: &str| -> serde_json::Value {
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+        };
+        let public = [Fr::from(5u64)];
+
+        // Default keeps snarkjs' [x, y, "1"]
+        assert!(ExportOptions::default().g1_projective);
+        let path = format!("{dir}/proof_projective.json");
+        export_proof_with_options::<Bn254, _>(&proof, &public, &path, &Default::default()).unwrap();
+        let raw = read(&path);
+        assert_eq!(raw["pi_a"], serde_json::json!(["1", "2", "1"]));
+        assert_eq!(raw["pi_c"], serde_json::json!(["0", "1", "0"]));
+
+        // Two elements for pi_a / pi_c only; same keys, same order
+        let options = ExportOptions::builder()
+            .g1_projective(false)
+            .version_tag(true)
+            .build();
+        let path = format!("{dir}/proof_affine.json");
+        let json = export_proof_with_options::<Bn254, _>(&proof, &public, &path, &options).unwrap();
+        assert_eq!(json.pi_a.len(), 3);
+        let raw = read(&path);
+        assert_eq!(raw["pi_a"], serde_json::json!(["1", "2"]));
+        assert_eq!(raw["pi_c"], serde_json::json!(["0", "0"]));
+        assert_eq!(
+            raw["pi_b"],
+            read(&format!("{dir}/proof_projective.json"))["pi_b"]
+        );
+        assert_eq!(raw["publicSignals"], serde_json::json!(["5"]));
+        let text = std::fs::read_to_string(&path).unwrap();
+        let keys = [
+            "\"pi_a\"",
+            "\"pi_b\"",
+            "\"pi_c\"",
+            "\"protocol\"",
+            "\"curve\"",
+            "\"publicSignals\"",
+            "\"arkSnarkjsVersion\"",
+        ];
+        let positions: Vec<_> = keys.iter().map(|k| text.find(k).unwrap()).collect();
+        assert!(positions.is_sorted());
+
+        // The importers read the pairs back
+        let (back, signals) = import_proof::<Bn254, _>(&path).unwrap();
+        assert_eq!(back, proof);
+        assert_eq!(signals, public);
+    }
+
+    #[test]
+    fn test_sort_keys() {
+        let proof = Proof::<Bn254> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::generator(),
+        };
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: G1Affine::generator(),
+            beta_g2: G2Affine::generator(),
+            gamma_g2: G2Affine::generator(),
+            delta_g2: G2Affine::generator(),
+            gamma_abc_g1: vec![G1Affine::generator(); 2],
+        };
+        let dir = "target/test-output/options_sort_keys";
+        let public = [Fr::from(9u64)];
+        // Top-level keys of a pretty-printed file, in file order
+        let keys_of = |path: &str| -> Vec<String> {
+            std::fs::read_to_string(path)
+                .unwrap()
+                .lines()
+                .filter_map(|line| line.strip_prefix("  \""))
+                .map(|line| line[..line.find('"').unwrap()].to_string())
+                .collect()
+        };
+        let sorted = ExportOptions::builder()
+            .sort_keys(true)
+            .alphabeta(true)
+            .version_tag(true)
+            .build();
+        let snarkjs_order = ExportOptions {
+            sort_keys: false,
+            ..sorted.clone()
+        };
+        assert!(!ExportOptions::default().sort_keys);
+
+        // Same content, keys alphabetical instead of snarkjs' order
+        let path = format!("{dir}/proof_sorted.json");
+        export_proof_with_options::<Bn254, _>(&proof, &public, &path, &sorted).unwrap();
+        let unsorted_path = format!("{dir}/proof_unsorted.json");
+        export_proof_with_options::<Bn254, _>(&proof, &public, &unsorted_path, &snarkjs_order)
+            .unwrap();
+        let keys = keys_of(&path);
+        assert_eq!(keys[..3], ["arkSnarkjsVersion", "curve", "pi_a"]);
+        assert!(keys.is_sorted());
+        assert!(!keys_of(&unsorted_path).is_sorted());
+        let read = |path: &str| -> serde_json::Value {
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+        };
+        assert_eq!(read(&path), read(&unsorted_path));
+        assert_eq!(import_proof::<Bn254, _>(&path).unwrap().0, proof);
+
+        let path = format!("{dir}/vk_sorted.json");
+        export_vk_with_options::<Bn254, _>(&vk, 1, &path, &sorted).unwrap();
+        let keys = keys_of(&path);
+        assert!(keys.is_sorted() && keys.contains(&"vk_alphabeta_12".to_string()));
+        assert_eq!(import_vk::<Bn254, _>(&path).unwrap(), vk);
+
+        // Deterministic bytes
+        let again = format!("{dir}/vk_sorted_again.json");
+        export_vk_with_options::<Bn254, _>(&vk, 1, &again, &sorted).unwrap();
+        assert_eq!(
+            std::fs::read(&path).unwrap(),
+            std::fs::read(&again).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_export_options_builder() {
+        assert_eq!(ExportOptions::builder().build(), ExportOptions::default());
+        assert_eq!(
+            ExportOptions::builder().indent_width(4).build(),
+            ExportOptions::with_indent_width(4)
+        );
+
+        let options = ExportOptions::builder()
+            .indent("\t")
+            .field_modulus(true)
+            .alphabeta(true)
+            .negate_a(true)
+            .curve_name_style(CurveNameStyle::Canonical)
+            .version_tag(true)
+            .public_order([1, 0])
+            .protocol("groth16-fork")
+            .g1_projective(false)
+            .sort_keys(true)
+            .build();
+        assert_eq!(
+            options,
+            ExportOptions {
+                indent: "\t".to_string(),
+                field_modulus: true,
+                alphabeta: true,
+                negate_a: true,
+                curve_name_style: CurveNameStyle::Canonical,
+                version_tag: true,
+                public_order: Some(vec![1, 0]),
+                protocol: "groth16-fork",
+                g1_projective: false,
+                sort_keys: true,
+            }
+        );
+
+        // Usable wherever options are taken
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: G1Affine::generator(),
+            beta_g2: G2Affine::generator(),
+            gamma_g2: G2Affine::generator(),
+            delta_g2: G2Affine::generator(),
+            gamma_abc_g1: vec![G1Affine::generator(); 3],
+        };
+        let path = "target/test-output/options_builder/verification_key.json";
+        let json = export_vk_with_options::<Bn254, _>(&vk, 2, path, &options).unwrap();
+        assert!(json.vk_alphabeta_12.is_some() && json.field_modulus.is_some());
+        assert!(
+            std::fs::read_to_string(path)
+                .unwrap()
+                .contains("\n\t\"curve\": \"bn254\"")
+        );
+    }
+}
@@ -0,0 +1,68 @@
+// snarkjs Plonk proof.json layout from explicit proof components
+// Run with: cargo test --features plonk --test ExportPlonk
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+#![cfg(feature = "plonk")]
+
+use ark_ec::CurveGroup;
+use ark_snarkjs::{PlonkProof, export_plonk_proof, f_to_dec};
+use ark_std::UniformRand;
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bn254::{Bn254, Fr, G1Projective};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_plonk_proof_bn254() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        let mut g1 = || G1Projective::rand(&mut rng).into_affine();
+        let (a, b, c, z, t1, t2, t3, wxi, wxiw) =
+            (g1(), g1(), g1(), g1(), g1(), g1(), g1(), g1(), g1());
+        let evals: Vec<Fr> = (0..6).map(|_| Fr::rand(&mut rng)).collect();
+        let proof = PlonkProof::<Bn254> {
+            a,
+            b,
+            c,
+            z,
+            t1,
+            t2,
+            t3,
+            wxi,
+            wxiw,
+            eval_a: evals[0],
+            eval_b: evals[1],
+            eval_c: evals[2],
+            eval_s1: evals[3],
+            eval_s2: evals[4],
+            eval_zw: evals[5],
+        };
+        let public = [Fr::from(33u64)];
+
+        let path = "target/test-output/plonk/bn254/proof.json";
+        let json = export_plonk_proof::<Bn254, _>(&proof, &public, path).unwrap();
+        assert_eq!(json.A, [f_to_dec(&a.x), f_to_dec(&a.y), "1".to_string()]);
+
+        let raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(raw["protocol"], "plonk");
+        assert_eq!(raw["curve"], "bn128");
+        for key in ["A", "B", "C", "Z", "T1", "T2", "T3", "Wxi", "Wxiw"] {
+            assert_eq!(raw[key].as_array().unwrap().len(), 3, "{key}");
+        }
+        assert_eq!(raw["eval_zw"], f_to_dec(&evals[5]));
+        assert_eq!(raw["publicSignals"][0], "33");
+    }
+}
@@ -0,0 +1,80 @@
+// zstd-compressed export round-trips through the matching importers
+// Run with: cargo test --features zstd --test ExportZstd
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+#![cfg(feature = "zstd")]
+
+use ark_ec::AffineRepr;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_snarkjs::{
+    SnarkjsError, export_proof, export_proof_zst, export_vk, export_vk_zst, import_proof_zst,
+    import_vk_zst,
+};
+
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zstd_round_trip() {
+        let (g1, g2) = (G1Affine::generator(), G2Affine::generator());
+        let proof = Proof::<Bn254> {
+            a: g1,
+            b: g2,
+            c: -g1,
+        };
+        let n_public = 64;
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: g1,
+            beta_g2: g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            gamma_abc_g1: vec![g1; n_public + 1],
+        };
+        let public: Vec<Fr> = (0..n_public as u64).map(Fr::from).collect();
+        let dir = "target/test-output/zstd";
+
+        let proof_json =
+            export_proof_zst(&proof, &public, format!("{dir}/proof.json.zst")).unwrap();
+        let vk_json =
+            export_vk_zst(&vk, n_public, format!("{dir}/verification_key.json.zst")).unwrap();
+        assert_eq!(proof_json.publicSignals.len(), n_public);
+        assert_eq!(vk_json.ic.len(), n_public + 1);
+
+        let (proof2, public2) =
+            import_proof_zst::<Bn254, _>(format!("{dir}/proof.json.zst")).unwrap();
+        assert_eq!(proof2, proof);
+        assert_eq!(public2, public);
+        assert_eq!(
+            import_vk_zst::<Bn254, _>(format!("{dir}/verification_key.json.zst")).unwrap(),
+            vk
+        );
+
+        // A zstd frame, smaller than the plain JSON
+        export_proof(&proof, &public, format!("{dir}/proof.json")).unwrap();
+        export_vk(&vk, n_public, format!("{dir}/verification_key.json")).unwrap();
+        for name in ["proof.json", "verification_key.json"] {
+            let plain = std::fs::read(format!("{dir}/{name}")).unwrap();
+            let packed = std::fs::read(format!("{dir}/{name}.zst")).unwrap();
+            assert_eq!(packed[..4], [0x28, 0xb5, 0x2f, 0xfd], "{name}");
+            assert!(packed.len() * 2 < plain.len(), "{name}");
+        }
+
+        // Plain JSON is not a zstd stream
+        assert!(matches!(
+            import_vk_zst::<Bn254, _>(format!("{dir}/verification_key.json")),
+            Err(SnarkjsError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+// gnark JSON layout for Groth16 proofs and verifying keys on Bn254,
+// compared byte for byte against reference files in gnark's `encoding/json`
+// form (see tests/data/gnark/README.md)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use std::str::FromStr;
+
+use ark_ec::AffineRepr;
+use ark_groth16::{Proof, ProvingKey, VerifyingKey};
+use ark_snarkjs::{export_gnark_proof, export_gnark_vk};
+use serde_json::Value;
+
+use ark_bn254::{Bn254, Fq, Fq2, G1Affine, G2Affine};
+
+/// Raw text of a reference file from `tests/data/gnark`.
+fn reference(name: &str) -> String {
+    let path = format!("{}/tests/data/gnark/{name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(path)
+        .unwrap()
+        .trim_end()
+        .to_string()
+}
+
+/// gnark writes small field elements as JSON numbers, others as strings.
+fn fq(v: &Value) -> Fq {
+    let dec = match v {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        other => panic!("not a gnark field element: {other}"),
+    };
+    Fq::from_str(&dec).unwrap()
+}
+
+/// gnark `G1Affine`, with `(0, 0)` as the point at infinity.
+fn g1(v: &Value) -> G1Affine {
+    let (x, y) = (fq(&v["X"]), fq(&v["Y"]));
+    if x == Fq::from(0u64) && y == Fq::from(0u64) {
+        return G1Affine::zero();
+    }
+    let p = G1Affine::new_unchecked(x, y);
+    assert!(p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve());
+    p
+}
+
+/// gnark `G2Affine`: `X`, `Y` as `{"A0": c0, "A1": c1}`.
+fn g2(v: &Value) -> G2Affine {
+    let e2 = |e: &Value| Fq2::new(fq(&e["A0"]), fq(&e["A1"]));
+    let p = G2Affine::new_unchecked(e2(&v["X"]), e2(&v["Y"]));
+    assert!(p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve());
+    p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gnark_proof_matches_reference() {
+        let text = reference("proof.json");
+        let value: Value = serde_json::from_str(&text).unwrap();
+        let proof = Proof::<Bn254> {
+            a: g1(&value["Ar"]),
+            b: g2(&value["Bs"]),
+            c: g1(&value["Krs"]),
+        };
+        let json =
+            export_gnark_proof::<Bn254, _>(&proof, "target/test-output/gnark/proof.json").unwrap();
+        assert_eq!(serde_json::to_string(&json).unwrap(), text);
+    }
+
+    #[test]
+    fn test_gnark_vk_matches_reference() {
+        let text = reference("vk.json");
+        let value: Value = serde_json::from_str(&text).unwrap();
+        let (vk_g1, vk_g2) = (&value["G1"], &value["G2"]);
+        let pk = ProvingKey::<Bn254> {
+            vk: VerifyingKey {
+                alpha_g1: g1(&vk_g1["Alpha"]),
+                beta_g2: g2(&vk_g2["Beta"]),
+                gamma_g2: g2(&vk_g2["Gamma"]),
+                delta_g2: g2(&vk_g2["Delta"]),
+                gamma_abc_g1: vk_g1["K"].as_array().unwrap().iter().map(g1).collect(),
+            },
+            beta_g1: g1(&vk_g1["Beta"]),
+            delta_g1: g1(&vk_g1["Delta"]),
+            a_query: vec![],
+            b_g1_query: vec![],
+            b_g2_query: vec![],
+            h_query: vec![],
+            l_query: vec![],
+        };
+        let json = export_gnark_vk::<Bn254, _>(&pk, "target/test-output/gnark/vk.json").unwrap();
+        assert_eq!(serde_json::to_string(&json).unwrap(), text);
+    }
+}
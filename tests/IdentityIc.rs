@@ -0,0 +1,128 @@
+// Verifying keys with an identity IC entry (an input no constraint uses):
+// exported as ["0", "0"], imported back and verified (BLS12-381)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_ec::AffineRepr;
+use ark_groth16::Groth16;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snarkjs::{
+    export_proof, export_vk, export_vk_streaming, import_vk, rapidsnark_vk, verify_snarkjs, vk_hash,
+};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+
+use ark_bls12_381::{Bls12_381, Fr, G1Affine};
+
+/// x * y = z with z public, plus a public input `unused` that no
+/// constraint reads.
+struct UnusedInputCircuit {
+    xy: Option<(Fr, Fr)>,
+    unused: Fr,
+}
+
+impl ConstraintSynthesizer<Fr> for UnusedInputCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let xy = self.xy.ok_or(SynthesisError::AssignmentMissing);
+        let x = FpVar::new_witness(cs.clone(), || xy.map(|(x, _)| x))?;
+        let y = FpVar::new_witness(cs.clone(), || xy.map(|(_, y)| y))?;
+        let z = FpVar::new_input(cs.clone(), || xy.map(|(x, y)| x * y))?;
+        let _unused = FpVar::new_input(cs, || Ok(self.unused))?;
+        (&x * &y).enforce_equal(&z)
+    }
+}
+
+/// Public inputs as snarkjs `public.json` strings.
+fn json_public(public: &[Fr]) -> Vec<String> {
+    public.iter().map(ark_snarkjs::f_to_dec).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_ic_round_trip() {
+        let dir = "target/test-output/identity_ic";
+        let mut rng = StdRng::seed_from_u64(607);
+        let setup = UnusedInputCircuit {
+            xy: None,
+            unused: Fr::from(0u64),
+        };
+        let (pk, mut vk) = Groth16::<Bls12_381>::setup(setup, &mut rng).unwrap();
+        let (x, y) = (Fr::from(6u64), Fr::from(7u64));
+        let circuit = UnusedInputCircuit {
+            xy: Some((x, y)),
+            unused: Fr::from(0u64),
+        };
+        let proof = Groth16::<Bls12_381>::prove(&pk, circuit, &mut rng).unwrap();
+        let public = [x * y, Fr::from(0u64)];
+
+        // arkworks' QAP reduction gives every input a term of its own, so
+        // zero the unused input's entry by hand; with that input fixed to 0
+        // the verification equation is unchanged
+        vk.gamma_abc_g1[2] = G1Affine::zero();
+        assert!(Groth16::<Bls12_381>::verify(&vk, &public, &proof).unwrap());
+
+        let json =
+            export_vk::<Bls12_381, _>(&vk, 2, format!("{dir}/verification_key.json")).unwrap();
+        assert_eq!(json.ic[2], ["0", "0"]);
+        export_proof::<Bls12_381, _>(&proof, &public, format!("{dir}/proof.json")).unwrap();
+        std::fs::write(
+            format!("{dir}/public.json"),
+            serde_json::to_string(&json_public(&public)).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            import_vk::<Bls12_381, _>(format!("{dir}/verification_key.json")).unwrap(),
+            vk
+        );
+        assert!(
+            verify_snarkjs::<Bls12_381>(
+                format!("{dir}/verification_key.json"),
+                format!("{dir}/proof.json"),
+                format!("{dir}/public.json"),
+            )
+            .unwrap()
+        );
+
+        // The streaming writer agrees, and the key hashes without panicking
+        export_vk_streaming::<Bls12_381, _>(&vk, 2, format!("{dir}/streaming.json")).unwrap();
+        assert_eq!(
+            std::fs::read(format!("{dir}/streaming.json")).unwrap(),
+            std::fs::read(format!("{dir}/verification_key.json")).unwrap()
+        );
+        assert_ne!(vk_hash::<Bls12_381>(&vk, 2).unwrap(), [0u8; 32]);
+    }
+
+    #[test]
+    fn test_identity_ic_projective_form() {
+        // Three-element form ["0", "1", "0"], as rapidsnark writes IC
+        let mut vk = ark_groth16::VerifyingKey::<ark_bn254::Bn254> {
+            alpha_g1: ark_bn254::G1Affine::generator(),
+            beta_g2: ark_bn254::G2Affine::generator(),
+            gamma_g2: ark_bn254::G2Affine::generator(),
+            delta_g2: ark_bn254::G2Affine::generator(),
+            gamma_abc_g1: vec![ark_bn254::G1Affine::generator(); 2],
+        };
+        vk.gamma_abc_g1[1] = ark_bn254::G1Affine::zero();
+        let json = rapidsnark_vk(&vk).unwrap();
+        assert_eq!(json.ic[1], ["0", "1", "0"]);
+
+        let path = "target/test-output/identity_ic/rapidsnark_vk.json";
+        std::fs::create_dir_all("target/test-output/identity_ic").unwrap();
+        std::fs::write(path, serde_json::to_string(&json).unwrap()).unwrap();
+        assert_eq!(import_vk::<ark_bn254::Bn254, _>(path).unwrap(), vk);
+    }
+}
@@ -0,0 +1,173 @@
+// Property tests: export/import round-trip of real Groth16 proofs on Bn254,
+// and typed errors (never panics) for malformed snarkjs JSON
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::Groth16;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snarkjs::{
+    SnarkjsError, export_proof::export_proof, export_vk::export_vk, import_proof::import_proof,
+    import_vk::import_vk, proof_to_snarkjs_value,
+};
+use ark_std::UniformRand;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+use ark_bn254::{Bn254, Fr};
+
+/// Small random circuit: `n` independent constraints x_i * y_i = z_i (z_i public).
+#[derive(Clone)]
+struct MulsCircuit {
+    xy: Vec<Option<(Fr, Fr)>>, // secret witnesses, None during setup
+}
+
+impl ConstraintSynthesizer<Fr> for MulsCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        for xy in self.xy {
+            let x = FpVar::new_witness(cs.clone(), || {
+                xy.map(|(x, _)| x).ok_or(SynthesisError::AssignmentMissing)
+            })?;
+            let y = FpVar::new_witness(cs.clone(), || {
+                xy.map(|(_, y)| y).ok_or(SynthesisError::AssignmentMissing)
+            })?;
+            let z = FpVar::new_input(cs.clone(), || {
+                xy.map(|(x, y)| x * y)
+                    .ok_or(SynthesisError::AssignmentMissing)
+            })?;
+            (&x * &y).enforce_equal(&z)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prove a random circuit with `n` public inputs, export proof and vk to `dir`,
+/// import them back and check equality and verification.
+fn roundtrip_random(seed: u64, n: usize, dir: &str) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (pk, vk) = Groth16::<Bn254>::setup(MulsCircuit { xy: vec![None; n] }, &mut rng).unwrap();
+
+    let xy: Vec<(Fr, Fr)> = (0..n)
+        .map(|_| (Fr::rand(&mut rng), Fr::rand(&mut rng)))
+        .collect();
+    let public: Vec<Fr> = xy.iter().map(|(x, y)| *x * y).collect();
+    let circuit = MulsCircuit {
+        xy: xy.into_iter().map(Some).collect(),
+    };
+    let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+
+    export_proof::<Bn254, _>(&proof, &public, format!("{dir}/proof.json")).unwrap();
+    export_vk::<Bn254, _>(&vk, n, format!("{dir}/verification_key.json")).unwrap();
+
+    let (proof2, public2) = import_proof::<Bn254, _>(format!("{dir}/proof.json")).unwrap();
+    let vk2 = import_vk::<Bn254, _>(format!("{dir}/verification_key.json")).unwrap();
+    assert_eq!(proof2, proof);
+    assert_eq!(public2, public);
+    assert_eq!(vk2, vk);
+    assert!(Groth16::<Bn254>::verify(&vk2, &public2, &proof2).unwrap());
+}
+
+/// A well-formed proof.json (random on-curve points) used as the base for
+/// malformed variants.
+fn valid_proof_json() -> serde_json::Value {
+    let mut rng = StdRng::seed_from_u64(0);
+    let proof = ark_groth16::Proof::<Bn254> {
+        a: ark_bn254::G1Projective::rand(&mut rng).into_affine(),
+        b: ark_bn254::G2Projective::rand(&mut rng).into_affine(),
+        c: ark_bn254::G1Projective::rand(&mut rng).into_affine(),
+    };
+    proof_to_snarkjs_value::<Bn254>(&proof, &[Fr::from(1u64)]).unwrap()
+}
+
+/// Decimal string of the Bn254 base field modulus plus `k` (never a valid element).
+fn out_of_range(k: u64) -> String {
+    let modulus = BigUint::from_bytes_be(&<ark_bn254::Fq as PrimeField>::MODULUS.to_bytes_be());
+    (modulus + k).to_str_radix(10)
+}
+
+/// Write `json` to a per-case file and import it as a proof.
+fn import_json(json: &serde_json::Value, name: &str) -> Result<(), SnarkjsError> {
+    let path = format!("target/test-output/fuzz/malformed/{name}.json");
+    std::fs::create_dir_all("target/test-output/fuzz/malformed").unwrap();
+    std::fs::write(&path, serde_json::to_vec(json).unwrap()).unwrap();
+    import_proof::<Bn254, _>(&path).map(|_| ())
+}
+
+const POINT_FIELDS: [&str; 3] = ["pi_a", "pi_b", "pi_c"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(8))]
+
+        #[test]
+        fn prop_roundtrip_verifies(seed in any::<u64>(), n in 1usize..4) {
+            roundtrip_random(seed, n, &format!("target/test-output/fuzz/roundtrip/{seed}"));
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn prop_out_of_range_decimal(k in any::<u64>(), field in 0usize..3, idx in 0usize..2) {
+            let mut json = valid_proof_json();
+            let name = POINT_FIELDS[field];
+            if name == "pi_b" {
+                json[name][idx][0] = out_of_range(k).into();
+            } else {
+                json[name][idx] = out_of_range(k).into();
+            }
+            let result = import_json(&json, &format!("range_{k}"));
+            prop_assert!(matches!(result, Err(SnarkjsError::InvalidFieldElement(_))), "unexpected result: {:?}", result);
+        }
+
+        #[test]
+        fn prop_wrong_array_length(len in 0usize..8, field in 0usize..3) {
+            // 2 (affine) and 3 (projective) are the only valid lengths
+            prop_assume!(len != 2 && len != 3);
+            let mut json = valid_proof_json();
+            let name = POINT_FIELDS[field];
+            let item = json[name][0].clone();
+            json[name] = serde_json::Value::Array(vec![item; len]);
+            let result = import_json(&json, &format!("len_{name}_{len}"));
+            prop_assert!(
+                matches!(result, Err(SnarkjsError::InvalidShape { field }) if field == name),
+                "unexpected result: {:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn prop_missing_field(field in 0usize..3) {
+            let mut json = valid_proof_json();
+            let name = POINT_FIELDS[field];
+            json.as_object_mut().unwrap().remove(name);
+            let result = import_json(&json, &format!("missing_{name}"));
+            prop_assert!(matches!(result, Err(SnarkjsError::Serde(_))), "unexpected result: {:?}", result);
+        }
+
+        #[test]
+        fn prop_garbage_decimal(s in "[^0-9]{1,8}") {
+            let mut json = valid_proof_json();
+            json["pi_a"][0] = s.into();
+            let result = import_json(&json, "garbage");
+            prop_assert!(matches!(result, Err(SnarkjsError::InvalidFieldElement(_))), "unexpected result: {:?}", result);
+        }
+    }
+}
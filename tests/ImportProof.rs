@@ -0,0 +1,220 @@
+// Import of snarkjs proof.json back into arkworks, including projective forms
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::{AffineRepr, CurveGroup, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::Proof;
+use ark_snarkjs::{
+    AsFp2, CurveTag, FromXy, SnarkjsError, export_proof::export_proof, f_to_dec,
+    import_proof::import_proof, import_proof_from_str, proof_to_snarkjs_value,
+};
+use ark_std::UniformRand;
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+
+/// Random (not necessarily valid) proof: import/export only cares about points.
+fn random_proof<E: Pairing>(rng: &mut impl RngCore) -> Proof<E> {
+    Proof {
+        a: E::G1::rand(rng).into_affine(),
+        b: E::G2::rand(rng).into_affine(),
+        c: E::G1::rand(rng).into_affine(),
+    }
+}
+
+fn roundtrip<E>(label: &str)
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+    let proof = random_proof::<E>(&mut rng);
+    let public = vec![E::ScalarField::rand(&mut rng), E::ScalarField::from(7u64)];
+
+    let path = format!("target/test-output/import/{label}/proof.json");
+    export_proof::<E, _>(&proof, &public, &path).unwrap();
+
+    let (imported, imported_public) = import_proof::<E, _>(&path).unwrap();
+    assert_eq!(
+        imported, proof,
+        "[{label}] proof must survive export/import"
+    );
+    assert_eq!(
+        imported_public, public,
+        "[{label}] public signals must match"
+    );
+
+    // Same result from the JSON text, without touching the filesystem
+    let text = proof_to_snarkjs_value::<E>(&proof, &public)
+        .unwrap()
+        .to_string();
+    let (from_str, from_str_public) = import_proof_from_str::<E>(&text).unwrap();
+    assert_eq!(from_str, proof, "[{label}] proof from string");
+    assert_eq!(
+        from_str_public, public,
+        "[{label}] public signals from string"
+    );
+}
+
+fn write(path: &str, value: serde_json::Value) {
+    std::fs::create_dir_all(std::path::Path::new(path).parent().unwrap()).unwrap();
+    std::fs::write(path, value.to_string()).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_proof_roundtrip() {
+        roundtrip::<Bn254>("Bn254");
+        roundtrip::<Bls12_381>("Bls12-381");
+    }
+
+    /// A circuit without public inputs exports `"publicSignals": []`.
+    fn zero_public<E>(label: &str)
+    where
+        E: Pairing + CurveTag,
+        E::G1Affine: FromXy,
+        E::G2Affine: FromXy,
+        <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+        <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    {
+        let proof = random_proof::<E>(&mut test_rng());
+        let path = format!("target/test-output/import/{label}/zero_public.json");
+        let json = export_proof::<E, _>(&proof, &[], &path).unwrap();
+        assert!(json.publicSignals.is_empty());
+
+        let raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(raw["publicSignals"], serde_json::json!([]), "[{label}]");
+        let (imported, public) = import_proof::<E, _>(&path).unwrap();
+        assert_eq!(imported, proof, "[{label}]");
+        assert!(public.is_empty());
+    }
+
+    #[test]
+    fn test_export_proof_zero_public_inputs() {
+        zero_public::<Bn254>("Bn254");
+        zero_public::<Bls12_381>("Bls12-381");
+    }
+
+    #[test]
+    fn test_import_proof_normalizes_projective() {
+        let g1 = ark_bn254::G1Affine::generator();
+        let g2 = ark_bn254::G2Affine::generator();
+
+        // pi_a as Jacobian with z = 2: (x * z^2, y * z^3, z)
+        let z = ark_bn254::Fq::from(2u64);
+        let (x, y) = (g1.x * z * z, g1.y * z * z * z);
+        let json = serde_json::json!({
+            "pi_a": [f_to_dec(&x), f_to_dec(&y), "2"],
+            "pi_b": [
+                [f_to_dec(&g2.x.c0), f_to_dec(&g2.x.c1)],
+                [f_to_dec(&g2.y.c0), f_to_dec(&g2.y.c1)],
+                ["1", "0"]
+            ],
+            "pi_c": ["0", "1", "0"],
+            "protocol": "groth16",
+            "curve": "bn128"
+        });
+        let path = "target/test-output/import/projective/proof.json";
+        write(path, json);
+
+        let (proof, public) = import_proof::<Bn254, _>(path).unwrap();
+        assert_eq!(proof.a, g1);
+        assert_eq!(proof.b, g2);
+        assert!(proof.c.is_zero(), "z = 0 must be the point at infinity");
+        assert!(public.is_empty());
+    }
+
+    #[test]
+    fn test_import_proof_rejects_malformed() {
+        let path = "target/test-output/import/malformed/proof.json";
+        write(
+            path,
+            serde_json::json!({
+                "pi_a": ["1", "2", "1", "0"],
+                "pi_b": [["1", "0"], ["1", "0"], ["1", "0"]],
+                "pi_c": ["0", "1", "0"],
+                "curve": "bn128"
+            }),
+        );
+        assert!(matches!(
+            import_proof::<Bn254, _>(path),
+            Err(SnarkjsError::InvalidShape { field: "pi_a" })
+        ));
+
+        write(
+            path,
+            serde_json::json!({
+                "pi_a": ["0", "1", "0"],
+                "pi_b": [["0", "0"], ["1", "0"], ["0", "0"]],
+                "pi_c": ["0", "1", "0"],
+                "curve": "bls12381"
+            }),
+        );
+        assert!(matches!(
+            import_proof::<Bn254, _>(path),
+            Err(SnarkjsError::CurveMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_import_proof_mixed_hex_decimal_signals() {
+        let proof = random_proof::<Bn254>(&mut test_rng());
+        let mut json = ark_snarkjs::proof_to_snarkjs_value::<Bn254>(&proof, &[]).unwrap();
+        json["publicSignals"] = serde_json::json!(["0x2a", "42", "0XfF", "0"]);
+        let path = "target/test-output/import/hex_signals/proof.json";
+        write(path, json.clone());
+
+        let (_, public) = import_proof::<Bn254, _>(path).unwrap();
+        let expected: Vec<ark_bn254::Fr> = [42u64, 42, 255, 0].map(Into::into).to_vec();
+        assert_eq!(public, expected);
+
+        // Hex is range-checked like decimal; "0x" alone is not a number
+        for bad in [
+            "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+            "0x",
+            "0xg1",
+        ] {
+            json["publicSignals"] = serde_json::json!([bad]);
+            write(path, json.clone());
+            assert!(matches!(
+                import_proof::<Bn254, _>(path),
+                Err(SnarkjsError::InvalidFieldElement(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_export_proof_infinity_roundtrip() {
+        let mut proof = random_proof::<Bn254>(&mut test_rng());
+        proof.c = ark_bn254::G1Affine::zero();
+        proof.b = ark_bn254::G2Affine::zero();
+
+        let path = "target/test-output/import/infinity/proof.json";
+        let json = ark_snarkjs::export_proof::<Bn254, _>(&proof, &[], path).unwrap();
+        assert_eq!(json.pi_a[2], "1");
+        assert_eq!(json.pi_c, ["0", "1", "0"]);
+        assert_eq!(json.pi_b, [["0", "0"], ["1", "0"], ["0", "0"]]);
+
+        let (imported, _) = import_proof::<Bn254, _>(path).unwrap();
+        assert_eq!(imported, proof);
+    }
+}
@@ -0,0 +1,520 @@
+// Import of snarkjs verification_key.json back into arkworks
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::{AffineRepr, CurveGroup, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::{VerifyingKey, prepare_verifying_key};
+use ark_snarkjs::{
+    AsFp2, CurveTag, ExportOptions, FromXy, MAX_N_PUBLIC, SnarkjsError, export_vk::export_vk,
+    export_vk_with_options, import_pvk, import_pvk_from_str,
+    import_pvk_from_str_trusting_alphabeta, import_pvk_from_str_verified,
+    import_pvk_trusting_alphabeta, import_pvk_verified, import_vk::import_vk, import_vk_from_str,
+    import_vk_from_str_verified, import_vk_from_str_with_limit, import_vk_streaming,
+    import_vk_streaming_with_limit, import_vk_verified, import_vk_with_limit, vk_to_snarkjs_value,
+};
+use ark_std::UniformRand;
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+
+/// Random verifying key with `n_public` public inputs.
+fn random_vk<E: Pairing>(rng: &mut impl RngCore, n_public: usize) -> VerifyingKey<E> {
+    VerifyingKey {
+        alpha_g1: E::G1::rand(rng).into_affine(),
+        beta_g2: E::G2::rand(rng).into_affine(),
+        gamma_g2: E::G2::rand(rng).into_affine(),
+        delta_g2: E::G2::rand(rng).into_affine(),
+        gamma_abc_g1: (0..=n_public)
+            .map(|_| E::G1::rand(rng).into_affine())
+            .collect(),
+    }
+}
+
+fn roundtrip<E>(label: &str)
+where
+    E: Pairing + CurveTag,
+    E::G1Affine: FromXy,
+    E::G2Affine: FromXy,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+    let vk = random_vk::<E>(&mut rng, 3);
+
+    let path = format!("target/test-output/import_vk/{label}/verification_key.json");
+    export_vk::<E, _>(&vk, 3, &path).unwrap();
+
+    let imported = import_vk::<E, _>(&path).unwrap();
+    assert_eq!(imported, vk, "[{label}] vk must survive export/import");
+
+    let text = vk_to_snarkjs_value::<E>(&vk, 3).unwrap().to_string();
+    assert_eq!(
+        import_vk_from_str::<E>(&text).unwrap(),
+        vk,
+        "[{label}] vk from string"
+    );
+
+    let pvk = import_pvk::<E, _>(&path).unwrap();
+    assert_eq!(pvk.vk, vk, "[{label}] prepared vk");
+    assert_eq!(
+        pvk.alpha_g1_beta_g2,
+        E::pairing(vk.alpha_g1, vk.beta_g2).0,
+        "[{label}] prepared e(alpha, beta)"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_vk_roundtrip() {
+        roundtrip::<Bn254>("Bn254");
+        roundtrip::<Bls12_381>("Bls12-381");
+    }
+
+    /// `n_public == 0`: IC holds only the constant term.
+    fn zero_public<E>(label: &str)
+    where
+        E: Pairing + CurveTag,
+        E::G1Affine: FromXy,
+        E::G2Affine: FromXy,
+        <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+        <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    {
+        let vk = random_vk::<E>(&mut test_rng(), 0);
+        let path = format!("target/test-output/import_vk/{label}/zero_public.json");
+        let json = export_vk::<E, _>(&vk, 0, &path).unwrap();
+        assert_eq!(json.n_public, 0);
+        assert_eq!(json.ic.len(), 1, "[{label}] IC = [constant term]");
+        assert_eq!(import_vk::<E, _>(&path).unwrap(), vk, "[{label}]");
+    }
+
+    #[test]
+    fn test_export_vk_zero_public_inputs() {
+        zero_public::<Bn254>("Bn254");
+        zero_public::<Bls12_381>("Bls12-381");
+    }
+
+    #[test]
+    fn test_export_vk_streaming_matches_export_vk() {
+        let vk = random_vk::<Bls12_381>(&mut test_rng(), 200);
+        let dir = "target/test-output/import_vk/streaming";
+        export_vk::<Bls12_381, _>(&vk, 200, format!("{dir}/collected.json")).unwrap();
+        ark_snarkjs::export_vk_streaming::<Bls12_381, _>(&vk, 200, format!("{dir}/streamed.json"))
+            .unwrap();
+
+        let collected = std::fs::read(format!("{dir}/collected.json")).unwrap();
+        let streamed = std::fs::read(format!("{dir}/streamed.json")).unwrap();
+        assert_eq!(streamed, collected);
+        assert_eq!(
+            import_vk::<Bls12_381, _>(format!("{dir}/streamed.json")).unwrap(),
+            vk
+        );
+
+        // An identity IC point is written as ["0", "0"], like export_vk
+        let mut unused = vk.clone();
+        unused.gamma_abc_g1[7] = ark_bls12_381::G1Affine::zero();
+        ark_snarkjs::export_vk_streaming::<Bls12_381, _>(
+            &unused,
+            200,
+            format!("{dir}/unused.json"),
+        )
+        .unwrap();
+        assert_eq!(
+            import_vk::<Bls12_381, _>(format!("{dir}/unused.json")).unwrap(),
+            unused
+        );
+
+        // An identity alpha is still a typed error, and no file is written
+        let mut bad = vk;
+        bad.alpha_g1 = ark_bls12_381::G1Affine::zero();
+        let bad_path = format!("{dir}/infinity.json");
+        let _ = std::fs::remove_file(&bad_path);
+        assert!(matches!(
+            ark_snarkjs::export_vk_streaming::<Bls12_381, _>(&bad, 200, &bad_path),
+            Err(SnarkjsError::PointAtInfinity)
+        ));
+        assert!(!std::path::Path::new(&bad_path).exists());
+    }
+
+    #[test]
+    fn test_import_vk_streaming() {
+        let mut vk = random_vk::<Bls12_381>(&mut test_rng(), 300);
+        vk.gamma_abc_g1[5] = ark_bls12_381::G1Affine::zero();
+        let dir = "target/test-output/import_vk/streaming_import";
+        let path = format!("{dir}/verification_key.json");
+        ark_snarkjs::export_vk_streaming::<Bls12_381, _>(&vk, 300, &path).unwrap();
+        assert_eq!(import_vk_streaming::<Bls12_381, _>(&path).unwrap(), vk);
+
+        // Same result as import_vk on any snarkjs file: key order, projective
+        // points and extra entries do not matter
+        let write = |name: &str, json: &serde_json::Value| -> String {
+            let path = format!("{dir}/{name}.json");
+            std::fs::write(&path, json.to_string()).unwrap();
+            path
+        };
+        let vk = random_vk::<Bn254>(&mut test_rng(), 2);
+        let mut json = serde_json::json!({ "IC": [] });
+        let exported = ark_snarkjs::vk_to_snarkjs_value::<Bn254>(&vk, 2).unwrap();
+        for (key, value) in exported.as_object().unwrap() {
+            json[key] = value.clone();
+        }
+        json["IC"][0].as_array_mut().unwrap().push("1".into());
+        json["vkHash"] = "ignored".into();
+        let path = write("reordered", &json);
+        assert_eq!(import_vk_streaming::<Bn254, _>(&path).unwrap(), vk);
+        assert_eq!(import_vk::<Bn254, _>(&path).unwrap(), vk);
+
+        // The same typed errors
+        let mut bad = json.clone();
+        bad["IC"][1][0] = "1".into();
+        assert!(matches!(
+            import_vk_streaming::<Bn254, _>(write("off_curve", &bad)),
+            Err(SnarkjsError::NotOnCurve)
+        ));
+        let mut bad = json.clone();
+        bad["IC"].as_array_mut().unwrap().pop();
+        assert!(matches!(
+            import_vk_streaming::<Bn254, _>(write("truncated", &bad)),
+            Err(SnarkjsError::IcLengthMismatch {
+                n_public: 2,
+                ic_len: 2
+            })
+        ));
+        let mut bad = json.clone();
+        bad["curve"] = "bls12381".into();
+        assert!(matches!(
+            import_vk_streaming::<Bn254, _>(write("curve", &bad)),
+            Err(SnarkjsError::CurveMismatch { .. })
+        ));
+        let mut bad = json.clone();
+        bad.as_object_mut().unwrap().remove("vk_delta_2");
+        assert!(matches!(
+            import_vk_streaming::<Bn254, _>(write("missing", &bad)),
+            Err(SnarkjsError::Serde(_))
+        ));
+
+        // Past the limit IC entries are only counted
+        let path = write("limit", &json);
+        assert_eq!(
+            import_vk_streaming_with_limit::<Bn254, _>(&path, 2).unwrap(),
+            vk
+        );
+        assert!(matches!(
+            import_vk_streaming_with_limit::<Bn254, _>(&path, 1),
+            Err(SnarkjsError::TooManyPublicInputs {
+                n_public: 2,
+                max: 1
+            })
+        ));
+        let mut bad = json;
+        bad["nPublic"] = 1.into();
+        assert!(matches!(
+            import_vk_streaming_with_limit::<Bn254, _>(write("long_ic", &bad), 1),
+            Err(SnarkjsError::IcLengthMismatch {
+                n_public: 1,
+                ic_len: 3
+            })
+        ));
+
+        // In snarkjs key order the header is checked before IC is reached:
+        // the rest of these files is not even valid JSON
+        let path = format!("{dir}/truncated.json");
+        let header = |protocol: &str, curve: &str, n_public: usize| {
+            let text = format!(
+                r#"{{"protocol": "{protocol}", "curve": "{curve}", "nPublic": {n_public}, "IC": [["1", "2"], ["bad""#
+            );
+            std::fs::write(&path, text).unwrap();
+            path.as_str()
+        };
+        assert!(matches!(
+            import_vk_streaming::<Bn254, _>(header("groth16", "bls12381", 2)),
+            Err(SnarkjsError::CurveMismatch { .. })
+        ));
+        assert!(matches!(
+            import_vk_streaming::<Bn254, _>(header("plonk", "bn128", 2)),
+            Err(SnarkjsError::ProtocolMismatch(_))
+        ));
+        assert!(matches!(
+            import_vk_streaming_with_limit::<Bn254, _>(header("groth16", "bn128", 2), 1),
+            Err(SnarkjsError::TooManyPublicInputs {
+                n_public: 2,
+                max: 1
+            })
+        ));
+        // A known nPublic bounds IC: the second entry is not converted
+        assert!(matches!(
+            import_vk_streaming::<Bn254, _>({
+                let text = r#"{"nPublic": 0, "IC": [["1", "2"], ["bad"], ["bad"]]}"#;
+                std::fs::write(&path, text).unwrap();
+                &path
+            }),
+            Err(SnarkjsError::IcLengthMismatch {
+                n_public: 0,
+                ic_len: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_import_vk_rejects_untrusted_g2() {
+        let vk = random_vk::<Bn254>(&mut test_rng(), 1);
+        let mut json = vk_to_snarkjs_value::<Bn254>(&vk, 1).unwrap();
+
+        // On the twist but outside the prime-order subgroup
+        let p = (1u64..)
+            .find_map(|x| ark_bn254::G2Affine::get_point_from_x_unchecked(x.into(), false))
+            .unwrap();
+        json["vk_delta_2"] = serde_json::json!([
+            [p.x.c0.to_string(), p.x.c1.to_string()],
+            [p.y.c0.to_string(), p.y.c1.to_string()]
+        ]);
+        assert!(matches!(
+            import_vk_from_str::<Bn254>(&json.to_string()),
+            Err(SnarkjsError::NotInSubgroup)
+        ));
+
+        json["vk_beta_2"][1][0] = "1".into();
+        assert!(matches!(
+            import_vk_from_str::<Bn254>(&json.to_string()),
+            Err(SnarkjsError::NotOnCurve)
+        ));
+    }
+
+    #[test]
+    fn test_import_vk_truncated_ic() {
+        let vk = random_vk::<Bn254>(&mut test_rng(), 2);
+        let path = "target/test-output/import_vk/truncated/verification_key.json";
+        let mut json =
+            serde_json::to_value(ark_snarkjs::vk_to_snarkjs::<Bn254>(&vk, 2).unwrap()).unwrap();
+        json["IC"].as_array_mut().unwrap().pop();
+        std::fs::create_dir_all("target/test-output/import_vk/truncated").unwrap();
+        std::fs::write(path, json.to_string()).unwrap();
+
+        let err = import_vk::<Bn254, _>(path).unwrap_err();
+        assert!(matches!(
+            err,
+            SnarkjsError::IcLengthMismatch {
+                n_public: 2,
+                ic_len: 2
+            }
+        ));
+        assert!(err.to_string().contains("requires 3"));
+    }
+
+    #[test]
+    fn test_import_vk_n_public_limit() {
+        let vk = random_vk::<Bn254>(&mut test_rng(), 2);
+        let json = ark_snarkjs::vk_to_snarkjs::<Bn254>(&vk, 2)
+            .unwrap()
+            .to_string();
+        let path = "target/test-output/import_vk/limit/verification_key.json";
+        std::fs::create_dir_all("target/test-output/import_vk/limit").unwrap();
+        std::fs::write(path, &json).unwrap();
+
+        // Configurable: the same key passes or fails depending on the limit
+        assert_eq!(import_vk_with_limit::<Bn254, _>(path, 2).unwrap(), vk);
+        assert!(matches!(
+            import_vk_from_str_with_limit::<Bn254>(&json, 1),
+            Err(SnarkjsError::TooManyPublicInputs {
+                n_public: 2,
+                max: 1
+            })
+        ));
+
+        // An absurd nPublic is an error, not an overflow or an allocation
+        let mut huge: serde_json::Value = serde_json::from_str(&json).unwrap();
+        huge["nPublic"] = usize::MAX.into();
+        let huge = huge.to_string();
+        assert!(matches!(
+            import_vk_from_str::<Bn254>(&huge),
+            Err(SnarkjsError::TooManyPublicInputs {
+                n_public: usize::MAX,
+                max: MAX_N_PUBLIC
+            })
+        ));
+        let err = import_vk_from_str_with_limit::<Bn254>(&huge, usize::MAX).unwrap_err();
+        assert!(matches!(
+            err,
+            SnarkjsError::IcLengthMismatch {
+                n_public: usize::MAX,
+                ic_len: 3
+            }
+        ));
+        assert!(err.to_string().contains("IC has 3 points"));
+    }
+
+    #[test]
+    fn test_import_vk_snarkjs_projective_points() {
+        // snarkjs writes vk points with the projective coordinate appended
+        let g1 = ark_bn254::G1Affine::generator();
+        let g2 = ark_bn254::G2Affine::generator();
+        let p1 = ark_snarkjs::g1_xy(&g1).unwrap();
+        let p2 = ark_snarkjs::g2_xyxy(&g2).unwrap();
+        let json = serde_json::json!({
+            "protocol": "groth16",
+            "curve": "bn128",
+            "nPublic": 1,
+            "vk_alpha_1": [p1[0], p1[1], "1"],
+            "vk_beta_2": [p2[0], p2[1], ["1", "0"]],
+            "vk_gamma_2": [p2[0], p2[1], ["1", "0"]],
+            "vk_delta_2": [p2[0], p2[1], ["1", "0"]],
+            "IC": [[p1[0], p1[1], "1"], [p1[0], p1[1], "1"]]
+        });
+        let path = "target/test-output/import_vk/snarkjs/verification_key.json";
+        std::fs::create_dir_all("target/test-output/import_vk/snarkjs").unwrap();
+        std::fs::write(path, json.to_string()).unwrap();
+
+        let vk = import_vk::<Bn254, _>(path).unwrap();
+        assert_eq!(vk.alpha_g1, g1);
+        assert_eq!(vk.delta_g2, g2);
+        assert_eq!(vk.gamma_abc_g1, vec![g1, g1]);
+    }
+
+    fn alphabeta_check<E>(label: &str)
+    where
+        E: Pairing + CurveTag,
+        E::G1Affine: FromXy,
+        E::G2Affine: FromXy,
+        <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+        <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+    {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        let vk = random_vk::<E>(&mut rng, 2);
+        let options = ExportOptions {
+            alphabeta: true,
+            ..Default::default()
+        };
+        let path = format!("target/test-output/import_vk/{label}/alphabeta.json");
+        let json = export_vk_with_options::<E, _>(&vk, 2, &path, &options).unwrap();
+        assert!(json.vk_alphabeta_12.is_some(), "[{label}]");
+        assert_eq!(import_vk_verified::<E, _>(&path).unwrap(), vk, "[{label}]");
+
+        // alpha replaced, stored e(alpha, beta) left as is
+        let mut value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let other = random_vk::<E>(&mut rng, 2);
+        value["vk_alpha_1"] = vk_to_snarkjs_value::<E>(&other, 2).unwrap()["vk_alpha_1"].clone();
+        assert!(
+            matches!(
+                import_vk_from_str_verified::<E>(&value.to_string()),
+                Err(SnarkjsError::AlphaBetaMismatch)
+            ),
+            "[{label}]"
+        );
+        // Plain import does not look at it
+        assert!(import_vk_from_str::<E>(&value.to_string()).is_ok());
+
+        // The trusting import takes the stored e(alpha, beta) instead of
+        // pairing; the default one computes it, and both agree here
+        let pvk = import_pvk_trusting_alphabeta::<E, _>(&path).unwrap();
+        let computed = prepare_verifying_key(&vk);
+        assert_eq!(pvk.vk, computed.vk, "[{label}]");
+        assert_eq!(pvk.alpha_g1_beta_g2, computed.alpha_g1_beta_g2, "[{label}]");
+        let pvk = import_pvk::<E, _>(&path).unwrap();
+        assert_eq!(pvk.alpha_g1_beta_g2, computed.alpha_g1_beta_g2, "[{label}]");
+        let verified = import_pvk_verified::<E, _>(&path).unwrap();
+        assert_eq!(verified.vk, computed.vk, "[{label}]");
+        assert_eq!(
+            verified.alpha_g1_beta_g2, computed.alpha_g1_beta_g2,
+            "[{label}]"
+        );
+
+        // A corrupted vk_alphabeta_12 is outside the order-r subgroup
+        let mut value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        value["vk_alphabeta_12"][0][0][0] = "1".into();
+        assert!(
+            matches!(
+                import_pvk_from_str_trusting_alphabeta::<E>(&value.to_string()),
+                Err(SnarkjsError::AlphaBetaMismatch)
+            ),
+            "[{label}]"
+        );
+        // Zero is not in GT either
+        for c in value["vk_alphabeta_12"].as_array_mut().unwrap() {
+            for fp2 in c.as_array_mut().unwrap() {
+                *fp2 = serde_json::json!(["0", "0"]);
+            }
+        }
+        assert!(matches!(
+            import_pvk_from_str_trusting_alphabeta::<E>(&value.to_string()),
+            Err(SnarkjsError::AlphaBetaMismatch)
+        ));
+
+        // A subgroup element that is not e(alpha, beta) passes the trusting
+        // import's cheap check but not the default or the verified import
+        let other = random_vk::<E>(&mut rng, 2);
+        let other_path = format!("target/test-output/import_vk/{label}/alphabeta_other.json");
+        export_vk_with_options::<E, _>(&other, 2, &other_path, &options).unwrap();
+        let other_value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&other_path).unwrap()).unwrap();
+        let mut value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        value["vk_alphabeta_12"] = other_value["vk_alphabeta_12"].clone();
+        assert!(import_pvk_from_str_trusting_alphabeta::<E>(&value.to_string()).is_ok());
+        assert!(
+            matches!(
+                import_pvk_from_str::<E>(&value.to_string()),
+                Err(SnarkjsError::AlphaBetaMismatch)
+            ),
+            "[{label}]"
+        );
+        assert!(
+            matches!(
+                import_pvk_from_str_verified::<E>(&value.to_string()),
+                Err(SnarkjsError::AlphaBetaMismatch)
+            ),
+            "[{label}]"
+        );
+
+        // Without vk_alphabeta_12 there is nothing to check against, and
+        // both prepared imports compute the pairing
+        let plain = vk_to_snarkjs_value::<E>(&vk, 2).unwrap().to_string();
+        assert_eq!(
+            import_pvk_from_str::<E>(&plain).unwrap().alpha_g1_beta_g2,
+            computed.alpha_g1_beta_g2,
+            "[{label}]"
+        );
+        assert_eq!(
+            import_pvk_from_str_trusting_alphabeta::<E>(&plain)
+                .unwrap()
+                .alpha_g1_beta_g2,
+            computed.alpha_g1_beta_g2,
+            "[{label}]"
+        );
+        assert!(matches!(
+            import_vk_from_str_verified::<E>(&plain),
+            Err(SnarkjsError::InvalidShape {
+                field: "vk_alphabeta_12"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_import_vk_verified_alphabeta() {
+        alphabeta_check::<Bn254>("Bn254");
+        alphabeta_check::<Bls12_381>("Bls12-381");
+
+        // Key written by snarkjs itself
+        let path = format!(
+            "{}/tests/data/snarkjs/verification_key.json",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let vk = import_vk_verified::<Bn254, _>(&path).unwrap();
+        assert_eq!(vk.gamma_abc_g1.len(), 2);
+    }
+}
@@ -0,0 +1,137 @@
+// export_verify_manifest: the three snarkjs files plus a manifest.json with
+// their sha256, the curve, nPublic and vkHash
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::CurveGroup;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_snarkjs::{
+    CurveId, ManifestJson, SnarkjsError, export_verify_manifest, import_proof,
+    import_proof_with_checksum, import_vk, import_vk_with_checksum, vk_hash_hex,
+};
+use ark_std::UniformRand;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use sha2::{Digest, Sha256};
+
+use ark_bls12_381::{Bls12_381, Fr, G1Projective, G2Projective};
+
+/// Random proof and verifying key with two public inputs.
+fn random_proof_vk(rng: &mut StdRng) -> (Proof<Bls12_381>, VerifyingKey<Bls12_381>) {
+    let proof = Proof::<Bls12_381> {
+        a: G1Projective::rand(rng).into_affine(),
+        b: G2Projective::rand(rng).into_affine(),
+        c: G1Projective::rand(rng).into_affine(),
+    };
+    let vk = VerifyingKey::<Bls12_381> {
+        alpha_g1: G1Projective::rand(rng).into_affine(),
+        beta_g2: G2Projective::rand(rng).into_affine(),
+        gamma_g2: G2Projective::rand(rng).into_affine(),
+        delta_g2: G2Projective::rand(rng).into_affine(),
+        gamma_abc_g1: (0..3)
+            .map(|_| G1Projective::rand(rng).into_affine())
+            .collect(),
+    };
+    (proof, vk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_manifest() {
+        let mut rng = StdRng::seed_from_u64(567);
+        let (proof, vk) = random_proof_vk(&mut rng);
+        let public = [Fr::from(1u64), Fr::rand(&mut rng)];
+        let dir = "target/test-output/manifest";
+
+        let manifest = export_verify_manifest::<Bls12_381, _>(&proof, &public, &vk, dir).unwrap();
+        assert_eq!(manifest.curve, CurveId::Bls12381);
+        assert_eq!(manifest.n_public, 2);
+        assert_eq!(manifest.vk_hash, vk_hash_hex::<Bls12_381>(&vk, 2).unwrap());
+
+        // The manifest on disk matches, and every listed hash matches its file
+        let written: ManifestJson =
+            serde_json::from_slice(&std::fs::read(format!("{dir}/manifest.json")).unwrap())
+                .unwrap();
+        assert_eq!(written, manifest);
+        let names: Vec<&str> = manifest.files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["proof.json", "public.json", "verification_key.json"]
+        );
+        for file in &manifest.files {
+            let bytes = std::fs::read(format!("{dir}/{}", file.name)).unwrap();
+            let digest: String = Sha256::digest(&bytes)
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect();
+            assert_eq!(file.sha256, digest, "{}", file.name);
+        }
+
+        // The public inputs must match the key, checked before writing
+        let bad_dir = "target/test-output/manifest_bad";
+        let _ = std::fs::remove_dir_all(bad_dir);
+        assert!(matches!(
+            export_verify_manifest::<Bls12_381, _>(&proof, &public[..1], &vk, bad_dir),
+            Err(SnarkjsError::PublicCountMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(!std::path::Path::new(bad_dir).exists());
+
+        let (proof2, public2) = import_proof::<Bls12_381, _>(format!("{dir}/proof.json")).unwrap();
+        assert_eq!((proof2, public2), (proof, public.to_vec()));
+        let vk2 = import_vk::<Bls12_381, _>(format!("{dir}/verification_key.json")).unwrap();
+        assert_eq!(vk2, vk);
+    }
+
+    #[test]
+    fn test_import_with_checksum() {
+        let mut rng = StdRng::seed_from_u64(578);
+        let (proof, vk) = random_proof_vk(&mut rng);
+        let public = [Fr::rand(&mut rng), Fr::rand(&mut rng)];
+        let dir = "target/test-output/manifest_checksum";
+
+        let manifest = export_verify_manifest::<Bls12_381, _>(&proof, &public, &vk, dir).unwrap();
+        let (proof_sha, vk_sha) = (&manifest.files[0].sha256, &manifest.files[2].sha256);
+        let (proof_path, vk_path) = (
+            format!("{dir}/proof.json"),
+            format!("{dir}/verification_key.json"),
+        );
+
+        let (proof2, public2) =
+            import_proof_with_checksum::<Bls12_381, _>(&proof_path, proof_sha).unwrap();
+        assert_eq!((proof2, public2), (proof, public.to_vec()));
+        let upper = format!("0x{}", vk_sha.to_uppercase());
+        assert_eq!(
+            import_vk_with_checksum::<Bls12_381, _>(&vk_path, &upper).unwrap(),
+            vk
+        );
+
+        // A truncated file is reported as a checksum failure, not a parse error
+        let bytes = std::fs::read(&vk_path).unwrap();
+        std::fs::write(&vk_path, &bytes[..bytes.len() / 2]).unwrap();
+        match import_vk_with_checksum::<Bls12_381, _>(&vk_path, vk_sha) {
+            Err(SnarkjsError::ChecksumMismatch { expected, found }) => {
+                assert_eq!(&expected, vk_sha);
+                assert_ne!(&found, vk_sha);
+            }
+            other => panic!("expected ChecksumMismatch, got {other:?}"),
+        }
+        assert!(matches!(
+            import_proof_with_checksum::<Bls12_381, _>(&proof_path, vk_sha),
+            Err(SnarkjsError::ChecksumMismatch { .. })
+        ));
+    }
+}
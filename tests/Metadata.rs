@@ -0,0 +1,263 @@
+// vk_metadata: nPublic, IC length and curve of a verifying key; the optional
+// arkSnarkjsVersion tag and FileVersion on import; JSON size estimates
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::{AffineRepr, CurveGroup, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::Proof;
+use ark_groth16::VerifyingKey;
+use ark_snarkjs::{
+    AsFp2, CRATE_VERSION, CurveId, CurveTag, ExportOptions, FileVersion, ProofJson, VkJson,
+    VkMetadata, export_proof, export_proof_with_options, export_vk, export_vk_with_options,
+    file_version_from_str, import_proof_with_version, import_vk_with_version, proof_size_estimate,
+    vk_metadata, vk_size_estimate,
+};
+use ark_std::UniformRand;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+
+/// Verifying key made of generators with `n_public` public inputs.
+fn generator_vk<E: Pairing>(n_public: usize) -> VerifyingKey<E> {
+    VerifyingKey {
+        alpha_g1: E::G1Affine::generator(),
+        beta_g2: E::G2Affine::generator(),
+        gamma_g2: E::G2Affine::generator(),
+        delta_g2: E::G2Affine::generator(),
+        gamma_abc_g1: vec![E::G1Affine::generator(); n_public + 1],
+    }
+}
+
+/// Check both estimates for `E`: exact for files whose coordinates all have
+/// the modulus' decimal length, an upper bound for random points.
+fn check_size_estimates<E>(dir: &str)
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let digits = <<E::G1Affine as AffineRepr>::BaseField as PrimeField>::MODULUS
+        .to_string()
+        .len();
+    let fq = || "9".repeat(digits);
+    let (one, zero) = ("1".to_string(), "0".to_string());
+
+    let proof = ProofJson {
+        pi_a: [fq(), fq(), one.clone()],
+        pi_b: [[fq(), fq()], [fq(), fq()], [one.clone(), zero]],
+        pi_c: [fq(), fq(), one],
+        protocol: "groth16",
+        curve: E::ID,
+        publicSignals: vec![],
+        ark_snarkjs_version: None,
+    };
+    assert_eq!(proof.to_string().len(), proof_size_estimate::<E>());
+
+    for n_public in [0, 1, 9, 10, 250] {
+        let vk = VkJson {
+            protocol: "groth16",
+            curve: E::ID,
+            n_public,
+            vk_alpha_1: [fq(), fq()],
+            vk_beta_2: [[fq(), fq()], [fq(), fq()]],
+            vk_gamma_2: [[fq(), fq()], [fq(), fq()]],
+            vk_delta_2: [[fq(), fq()], [fq(), fq()]],
+            vk_alphabeta_12: None,
+            ic: vec![[fq(), fq()]; n_public + 1],
+            vk_hash: None,
+            field_modulus: None,
+            ark_snarkjs_version: None,
+        };
+        let json = serde_json::to_string_pretty(&vk).unwrap();
+        assert_eq!(
+            json.len(),
+            vk_size_estimate::<E>(n_public).unwrap(),
+            "{n_public}"
+        );
+    }
+
+    let rng = &mut ark_std::test_rng();
+    let g1 = |rng: &mut _| E::G1::rand(rng).into_affine();
+    for _ in 0..8 {
+        let proof = Proof::<E> {
+            a: g1(rng),
+            b: E::G2::rand(rng).into_affine(),
+            c: g1(rng),
+        };
+        let path = format!("{dir}/proof.json");
+        export_proof::<E, _>(&proof, &[], &path).unwrap();
+        assert!(std::fs::metadata(&path).unwrap().len() as usize <= proof_size_estimate::<E>());
+
+        let vk = VerifyingKey::<E> {
+            alpha_g1: g1(rng),
+            beta_g2: E::G2::rand(rng).into_affine(),
+            gamma_g2: E::G2::rand(rng).into_affine(),
+            delta_g2: E::G2::rand(rng).into_affine(),
+            gamma_abc_g1: (0..5).map(|_| g1(rng)).collect(),
+        };
+        let path = format!("{dir}/verification_key.json");
+        export_vk::<E, _>(&vk, 4, &path).unwrap();
+        assert!(
+            std::fs::metadata(&path).unwrap().len() as usize <= vk_size_estimate::<E>(4).unwrap()
+        );
+    }
+
+    // Real exports: the estimate is off by exactly the digits each
+    // coordinate is short of the modulus' length
+    for n_public in [0, 1, 9, 10, 250] {
+        let vk = VerifyingKey::<E> {
+            alpha_g1: g1(rng),
+            beta_g2: E::G2::rand(rng).into_affine(),
+            gamma_g2: E::G2::rand(rng).into_affine(),
+            delta_g2: E::G2::rand(rng).into_affine(),
+            gamma_abc_g1: (0..=n_public).map(|_| g1(rng)).collect(),
+        };
+        let path = format!("{dir}/verification_key_{n_public}.json");
+        let json = export_vk::<E, _>(&vk, n_public, &path).unwrap();
+        let coords = json
+            .vk_alpha_1
+            .iter()
+            .chain(
+                [&json.vk_beta_2, &json.vk_gamma_2, &json.vk_delta_2]
+                    .into_iter()
+                    .flatten()
+                    .flatten(),
+            )
+            .chain(json.ic.iter().flatten());
+        let shortfall: usize = coords.map(|c| digits - c.len()).sum();
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().len() as usize,
+            vk_size_estimate::<E>(n_public).unwrap() - shortfall,
+            "{n_public}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vk_metadata() {
+        let meta = vk_metadata::<Bn254>(&generator_vk(3));
+        assert_eq!(
+            meta,
+            VkMetadata {
+                n_public: 3,
+                ic_len: 4,
+                curve: CurveId::Bn128,
+            }
+        );
+
+        let meta = vk_metadata::<Bls12_381>(&generator_vk(0));
+        assert_eq!((meta.n_public, meta.ic_len), (0, 1));
+        assert_eq!(meta.curve, CurveId::Bls12381);
+
+        let mut empty = generator_vk::<Bn254>(0);
+        empty.gamma_abc_g1.clear();
+        assert_eq!(vk_metadata::<Bn254>(&empty).n_public, 0);
+    }
+
+    #[test]
+    fn test_vk_metadata_serde() {
+        let meta = vk_metadata::<Bn254>(&generator_vk(2));
+        let json = serde_json::to_string(&meta).unwrap();
+        assert_eq!(json, r#"{"nPublic":2,"icLen":3,"curve":"bn128"}"#);
+        assert_eq!(serde_json::from_str::<VkMetadata>(&json).unwrap(), meta);
+    }
+
+    #[test]
+    fn test_version_tag_roundtrip() {
+        let dir = "target/test-output/metadata";
+        let vk = generator_vk::<Bn254>(1);
+        let options = ExportOptions::builder().version_tag(true).build();
+
+        let json =
+            export_vk_with_options(&vk, 1, format!("{dir}/vk_tagged.json"), &options).unwrap();
+        assert_eq!(json.ark_snarkjs_version, Some(CRATE_VERSION));
+        let (imported, version) =
+            import_vk_with_version::<Bn254, _>(format!("{dir}/vk_tagged.json")).unwrap();
+        assert_eq!(imported, vk);
+        assert_eq!(
+            version,
+            FileVersion {
+                written_by: Some(CRATE_VERSION.to_string()),
+                newer: false,
+            }
+        );
+
+        let proof = Proof::<Bn254> {
+            a: <Bn254 as Pairing>::G1Affine::generator(),
+            b: <Bn254 as Pairing>::G2Affine::generator(),
+            c: <Bn254 as Pairing>::G1Affine::generator(),
+        };
+        let public = [ark_bn254::Fr::from(9u64)];
+        export_proof_with_options(
+            &proof,
+            &public,
+            format!("{dir}/proof_tagged.json"),
+            &options,
+        )
+        .unwrap();
+        let (_, signals, version) =
+            import_proof_with_version::<Bn254, _>(format!("{dir}/proof_tagged.json")).unwrap();
+        assert_eq!(signals, public);
+        assert_eq!(version.written_by.as_deref(), Some(CRATE_VERSION));
+
+        // Off by default: no tag, nothing to warn about
+        export_vk(&vk, 1, format!("{dir}/vk_plain.json")).unwrap();
+        let text = std::fs::read_to_string(format!("{dir}/vk_plain.json")).unwrap();
+        assert!(!text.contains("arkSnarkjsVersion"));
+        let (_, version) =
+            import_vk_with_version::<Bn254, _>(format!("{dir}/vk_plain.json")).unwrap();
+        assert_eq!(
+            version,
+            FileVersion {
+                written_by: None,
+                newer: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_file_version_newer() {
+        let newer = |v: &str| {
+            file_version_from_str(&format!(r#"{{"arkSnarkjsVersion": "{v}"}}"#))
+                .unwrap()
+                .newer
+        };
+        assert!(!newer("0.0.1"));
+        assert!(!newer(CRATE_VERSION));
+        assert!(newer("999.0.0"));
+        assert!(newer("999.0.0-rc.1"));
+        // Unparsable versions cannot be vouched for
+        assert!(newer("next"));
+    }
+
+    #[test]
+    fn test_size_estimates() {
+        check_size_estimates::<Bn254>("target/test-output/size_estimate/bn254");
+        check_size_estimates::<Bls12_381>("target/test-output/size_estimate/bls12_381");
+
+        // Dominated by IC: one more public input adds one IC entry
+        let size = |n| vk_size_estimate::<Bn254>(n).unwrap();
+        let per_input = size(11) - size(10);
+        assert_eq!(size(21) - size(20), per_input);
+        assert!(vk_size_estimate::<Bls12_381>(1).unwrap() > size(1));
+
+        // Computed without touching memory per entry, and overflow is reported
+        assert!(vk_size_estimate::<Bn254>(1 << 40).is_some());
+        assert_eq!(vk_size_estimate::<Bn254>(usize::MAX), None);
+        assert_eq!(vk_size_estimate::<Bn254>(usize::MAX / per_input), None);
+    }
+}
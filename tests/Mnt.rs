@@ -0,0 +1,142 @@
+// Groth16 export on MNT4-753 (Fp2 G2) and rejection of MNT6-753 (Fp3 G2)
+// Run with: cargo test --features mnt4_753,mnt6_753 --test Mnt
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+#![cfg(any(feature = "mnt4_753", feature = "mnt6_753"))]
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_ff::{One, PrimeField};
+use ark_groth16::Groth16;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+/// Simple circuit: check that x * y = z (where z is a public input).
+#[derive(Clone)]
+struct MulCircuit<F: PrimeField> {
+    x: Option<F>,
+    y: Option<F>,
+    z: F, // public input
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for MulCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let x = FpVar::<F>::new_witness(cs.clone(), || {
+            self.x.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let y = FpVar::<F>::new_witness(cs.clone(), || {
+            self.y.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let z = FpVar::<F>::new_input(cs, || Ok(self.z))?;
+        (&x * &y).enforce_equal(&z)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "mnt4_753")]
+    #[test]
+    fn test_mul_groth16_mnt4_753() {
+        use ark_mnt4_753::{Fr, MNT4_753};
+
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        let empty = MulCircuit::<Fr> {
+            x: None,
+            y: None,
+            z: Fr::one(),
+        };
+        let (pk, vk) = Groth16::<MNT4_753>::setup(empty, &mut rng).unwrap();
+
+        let (x, y) = (Fr::from(641u64), Fr::from(6_700_417u64));
+        let circuit = MulCircuit {
+            x: Some(x),
+            y: Some(y),
+            z: x * y,
+        };
+        let proof = Groth16::<MNT4_753>::prove(&pk, circuit, &mut rng).unwrap();
+        let public_inputs = [x * y];
+        assert!(Groth16::<MNT4_753>::verify(&vk, &public_inputs, &proof).unwrap());
+
+        let proof_json = ark_snarkjs::export_proof::<MNT4_753, _>(
+            &proof,
+            &public_inputs,
+            "target/test-output/mnt4/proof.json",
+        )
+        .unwrap();
+        let vk_json = ark_snarkjs::export_vk::<MNT4_753, _>(
+            &vk,
+            public_inputs.len(),
+            "target/test-output/mnt4/verification_key.json",
+        )
+        .unwrap();
+        assert_eq!(proof_json.curve, ark_snarkjs::CurveId::Mnt4753);
+        assert_eq!(vk_json.ic.len(), 2);
+
+        let (imported, _) =
+            ark_snarkjs::import_proof::<MNT4_753, _>("target/test-output/mnt4/proof.json").unwrap();
+        assert_eq!(imported, proof);
+    }
+
+    #[cfg(feature = "mnt6_753")]
+    #[test]
+    fn test_mnt6_753_g2_unsupported() {
+        use ark_ec::{AffineRepr, pairing::Pairing};
+        use ark_mnt6_753::MNT6_753;
+        use ark_snarkjs::SnarkjsError;
+
+        let g1 = <MNT6_753 as Pairing>::G1Affine::generator();
+        let g2 = <MNT6_753 as Pairing>::G2Affine::generator();
+        let vk = ark_groth16::VerifyingKey::<MNT6_753> {
+            alpha_g1: g1,
+            beta_g2: g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            gamma_abc_g1: vec![g1],
+        };
+        let proof = ark_groth16::Proof::<MNT6_753> {
+            a: g1,
+            b: g2,
+            c: g1,
+        };
+
+        assert!(matches!(
+            ark_snarkjs::export_vk::<MNT6_753, _>(&vk, 0, "target/test-output/mnt6/vk.json"),
+            Err(SnarkjsError::UnsupportedG2)
+        ));
+        assert!(matches!(
+            ark_snarkjs::proof_to_snarkjs::<MNT6_753>(&proof, &[]),
+            Err(SnarkjsError::UnsupportedG2)
+        ));
+        // The validate-only checks report the same error
+        assert!(matches!(
+            ark_snarkjs::validate_proof::<MNT6_753>(&proof, &[]),
+            Err(SnarkjsError::UnsupportedG2)
+        ));
+        assert!(matches!(
+            ark_snarkjs::validate_vk::<MNT6_753>(&vk, 0),
+            Err(SnarkjsError::UnsupportedG2)
+        ));
+        assert!(matches!(
+            ark_snarkjs::vk_hash::<MNT6_753>(&vk, 0),
+            Err(SnarkjsError::UnsupportedG2)
+        ));
+        // The Fp3 accessors report the same error instead of panicking
+        use ark_snarkjs::AsFp2;
+        let (x, _) = g2.xy().unwrap();
+        assert!(matches!(x.c0_c1(), Err(SnarkjsError::UnsupportedG2)));
+        assert!(!std::path::Path::new("target/test-output/mnt6/vk.json").exists());
+    }
+}
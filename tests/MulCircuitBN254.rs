@@ -114,6 +114,42 @@ mod tests {
             "target/test-output/mulbn254/verification_key.json",
         );
 
+        // The key embedded in the proving key exports to the same file
+        ark_snarkjs::export_vk_from_pk::<Bn254, _>(
+            &pk,
+            public_inputs.len(),
+            "target/test-output/mulbn254/verification_key_from_pk.json",
+        )
+        .unwrap();
+        assert_eq!(
+            std::fs::read("target/test-output/mulbn254/verification_key_from_pk.json").unwrap(),
+            std::fs::read("target/test-output/mulbn254/verification_key.json").unwrap()
+        );
+
+        // In-memory JSON values for embedding in larger documents
+        let proof_value =
+            ark_snarkjs::proof_to_snarkjs_value::<Bn254>(&proof, &public_inputs).unwrap();
+        assert_eq!(proof_value["curve"], "bn128");
+        assert_eq!(proof_value["publicSignals"][0], z_f.to_string());
+        let vk_value = ark_snarkjs::vk_to_snarkjs_value::<Bn254>(&vk, public_inputs.len()).unwrap();
+        assert_eq!(vk_value["IC"].as_array().unwrap().len(), 2);
+
+        // Verified export refuses a proof for the wrong public input
+        let verified_path = "target/test-output/mulbn254/proof_verified.json";
+        let _ = std::fs::remove_file(verified_path);
+        assert!(matches!(
+            ark_snarkjs::export_proof_verified::<Bn254, _>(
+                &pvk,
+                &proof,
+                &[z_f + z_f],
+                verified_path
+            ),
+            Err(ark_snarkjs::SnarkjsError::ProofDoesNotVerify)
+        ));
+        assert!(!std::path::Path::new(verified_path).exists());
+        ark_snarkjs::export_proof_verified::<Bn254, _>(&pvk, &proof, &public_inputs, verified_path)
+            .unwrap();
+
         println!("[Bn254] Done.");
     }
 }
@@ -0,0 +1,212 @@
+// Every public function that reads or writes a file accepts the path as
+// &str, String, PathBuf or &Path (Bn254, x * y = z)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use std::path::{Path, PathBuf};
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_groth16::{Groth16, prepare_verifying_key};
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_serialize::CanonicalSerialize;
+use ark_snarkjs::{
+    ExportOptions, export_bundle, export_gnark_proof, export_gnark_vk, export_proof,
+    export_proof_batch, export_proof_from_bytes, export_proof_from_strings, export_proof_verified,
+    export_proof_with_options, export_proofs_array, export_public_labeled,
+    export_shared_public_batch, export_verify_manifest, export_vk, export_vk_from_pk,
+    export_vk_streaming, export_vk_with_hash, export_vk_with_labels, export_vk_with_options,
+    export_witness, import_bundle, import_proof, import_proofs_array, import_pvk, import_vk,
+    import_vk_streaming, import_vk_with_checksum, verify_snarkjs,
+};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+
+use ark_bn254::{Bn254, Fr};
+
+/// x * y = z (z is public).
+struct MulCircuit {
+    x: Option<Fr>,
+    y: Option<Fr>,
+    z: Fr,
+}
+
+impl ConstraintSynthesizer<Fr> for MulCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let x = FpVar::new_witness(cs.clone(), || {
+            self.x.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let y = FpVar::new_witness(cs.clone(), || {
+            self.y.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let z = FpVar::new_input(cs, || Ok(self.z))?;
+        (&x * &y).enforce_equal(&z)
+    }
+}
+
+/// Run `$body` four times with `$p` bound to `$path` as `&str`, `String`,
+/// `PathBuf` and `&Path`.
+macro_rules! with_each_path_type {
+    ($path:expr, |$p:ident| $body:expr) => {{
+        let path: String = $path;
+        {
+            let $p: &str = &path;
+            $body;
+        }
+        {
+            let $p: String = path.clone();
+            $body;
+        }
+        {
+            let $p: PathBuf = PathBuf::from(&path);
+            $body;
+        }
+        {
+            let $p: &Path = Path::new(&path);
+            $body;
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_argument_types() {
+        let dir = "target/test-output/path_args";
+        let mut rng = StdRng::seed_from_u64(7);
+        let (pk, vk) = Groth16::<Bn254>::setup(
+            MulCircuit {
+                x: None,
+                y: None,
+                z: Fr::from(1u64),
+            },
+            &mut rng,
+        )
+        .unwrap();
+        let public = vec![Fr::from(12u64)];
+        let circuit = MulCircuit {
+            x: Some(Fr::from(3u64)),
+            y: Some(Fr::from(4u64)),
+            z: public[0],
+        };
+        let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+        let pvk = prepare_verifying_key(&vk);
+        let proofs = vec![(proof.clone(), public.clone())];
+        let options = ExportOptions::default();
+
+        // Writers
+        with_each_path_type!(format!("{dir}/proof.json"), |p| export_proof(
+            &proof, &public, p
+        )
+        .unwrap());
+        with_each_path_type!(format!("{dir}/proof.json"), |p| {
+            export_proof_with_options(&proof, &public, p, &options).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/proof.json"), |p| {
+            export_proof_from_strings::<Bn254, _, _>(&proof, &["12"], p).unwrap()
+        });
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        with_each_path_type!(format!("{dir}/proof.json"), |p| {
+            export_proof_from_bytes::<Bn254, _>(&bytes, &public, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/proof.json"), |p| {
+            export_proof_verified(&pvk, &proof, &public, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/verification_key.json"), |p| {
+            export_vk(&vk, 1, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/verification_key.json"), |p| {
+            export_vk_with_options(&vk, 1, p, &options).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/verification_key.json"), |p| {
+            export_vk_streaming(&vk, 1, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/verification_key.json"), |p| {
+            export_vk_with_hash(&vk, 1, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/labeled/verification_key.json"), |p| {
+            export_vk_with_labels(&vk, 1, p, &["one", "z"]).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/verification_key.json"), |p| {
+            export_vk_from_pk(&pk, 1, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/bundle.json"), |p| {
+            export_bundle(&proof, &public, &vk, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/batch"), |p| {
+            export_proof_batch(&vk, &proofs, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/shared_public"), |p| {
+            export_shared_public_batch(&vk, std::slice::from_ref(&proof), &public, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/proofs.json"), |p| {
+            export_proofs_array(&proofs, p).unwrap()
+        });
+        let mut manifests = Vec::new();
+        with_each_path_type!(format!("{dir}/manifest"), |p| {
+            manifests.push(export_verify_manifest(&proof, &public, &vk, p).unwrap())
+        });
+        with_each_path_type!(format!("{dir}/public_labeled.json"), |p| {
+            export_public_labeled(&public, &["z"], p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/witness.json"), |p| {
+            export_witness(&[Fr::from(1u64), public[0]], p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/gnark_proof.json"), |p| {
+            export_gnark_proof(&proof, p).unwrap()
+        });
+        with_each_path_type!(format!("{dir}/gnark_vk.json"), |p| {
+            export_gnark_vk(&pk, p).unwrap()
+        });
+
+        // Readers
+        with_each_path_type!(format!("{dir}/proof.json"), |p| {
+            assert_eq!(import_proof::<Bn254, _>(p).unwrap().0, proof)
+        });
+        with_each_path_type!(format!("{dir}/verification_key.json"), |p| {
+            assert_eq!(import_vk::<Bn254, _>(p).unwrap(), vk)
+        });
+        with_each_path_type!(format!("{dir}/verification_key.json"), |p| {
+            assert_eq!(import_vk_streaming::<Bn254, _>(p).unwrap(), vk)
+        });
+        with_each_path_type!(format!("{dir}/verification_key.json"), |p| {
+            assert_eq!(import_pvk::<Bn254, _>(p).unwrap().vk, vk)
+        });
+        let sha = manifests[3].files[2].sha256.clone();
+        with_each_path_type!(format!("{dir}/manifest/verification_key.json"), |p| {
+            assert_eq!(import_vk_with_checksum::<Bn254, _>(p, &sha).unwrap(), vk)
+        });
+        with_each_path_type!(format!("{dir}/bundle.json"), |p| {
+            assert_eq!(import_bundle::<Bn254, _>(p).unwrap().0, proof)
+        });
+        with_each_path_type!(format!("{dir}/proofs.json"), |p| {
+            assert_eq!(import_proofs_array::<Bn254, _>(p).unwrap(), proofs)
+        });
+
+        // verify_snarkjs takes three paths, each of its own type
+        let m = format!("{dir}/manifest");
+        assert!(
+            verify_snarkjs::<Bn254>(
+                format!("{m}/verification_key.json"),
+                PathBuf::from(format!("{m}/proof.json")),
+                Path::new(&format!("{m}/public.json")),
+            )
+            .unwrap()
+        );
+        with_each_path_type!(format!("{m}/verification_key.json"), |p| assert!(
+            verify_snarkjs::<Bn254>(p, format!("{m}/proof.json"), format!("{m}/public.json"))
+                .unwrap()
+        ));
+    }
+}
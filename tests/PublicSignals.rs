@@ -0,0 +1,305 @@
+// PublicSignals: decimal-string serialization and circuit-order preservation,
+// proof export from decimal-string public inputs, and reordering into circom's
+// [outputs, inputs] order, and public inputs read from a constraint system
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_ec::AffineRepr;
+use ark_groth16::{Groth16, Proof, VerifyingKey};
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError, SynthesisMode,
+};
+use ark_snarkjs::{
+    ExportOptions, PublicSignals, SnarkjsError, export_proof, export_proof_from_strings,
+    export_proof_with_options, export_public_labeled, export_vk, export_vk_with_labels,
+    export_vk_with_options, public_inputs_from_cs, verify_snarkjs,
+};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+
+/// `c = a * b` with all three public, allocated in the arkworks order
+/// `[a, b, c]`; circom would list the output first, `[c, a, b]`.
+struct MulInputsFirst {
+    ab: Option<(Fr, Fr)>,
+}
+
+impl ConstraintSynthesizer<Fr> for MulInputsFirst {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let ab = self.ab.ok_or(SynthesisError::AssignmentMissing);
+        let a = FpVar::new_input(cs.clone(), || ab.map(|(a, _)| a))?;
+        let b = FpVar::new_input(cs.clone(), || ab.map(|(_, b)| b))?;
+        let c = FpVar::new_input(cs, || ab.map(|(a, b)| a * b))?;
+        (&a * &b).enforce_equal(&c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_signals_serde_roundtrip() {
+        let values = vec![Fr::from(3u64), -Fr::from(1u64), Fr::from(0u64)];
+        let signals = PublicSignals::from(&values);
+
+        let json = serde_json::to_value(&signals).unwrap();
+        assert_eq!(json[0], "3");
+        assert_eq!(
+            json[1],
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616"
+        );
+        assert_eq!(json[2], "0");
+
+        let back: PublicSignals<Fr> = serde_json::from_value(json).unwrap();
+        assert_eq!(back, signals);
+        assert_eq!(back.into_inner(), values);
+
+        // `0x` hex is accepted on parse, other notations are rejected
+        let hex: PublicSignals<Fr> = serde_json::from_str(r#"["0x01", "2"]"#).unwrap();
+        assert_eq!(hex.into_inner(), vec![Fr::from(1u64), Fr::from(2u64)]);
+        assert!(serde_json::from_str::<PublicSignals<Fr>>(r#"["1e3"]"#).is_err());
+    }
+
+    #[test]
+    fn test_export_proof_from_strings() {
+        let proof = Proof::<Bn254> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::generator(),
+        };
+        let dir = "target/test-output/public_signals";
+        let strings = ["33".to_string(), "0".to_string()];
+        let from_strings = export_proof_from_strings::<Bn254, _, _>(
+            &proof,
+            &strings,
+            format!("{dir}/strings.json"),
+        )
+        .unwrap();
+        let from_fields = export_proof::<Bn254, _>(
+            &proof,
+            &[Fr::from(33u64), Fr::from(0u64)],
+            format!("{dir}/fields.json"),
+        )
+        .unwrap();
+        assert_eq!(from_strings.publicSignals, from_fields.publicSignals);
+
+        // Out of range (the modulus itself) and non-decimal input never reach the file
+        let path = format!("{dir}/rejected.json");
+        for bad in [
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+            "0x21",
+        ] {
+            assert!(matches!(
+                export_proof_from_strings::<Bn254, _, _>(&proof, &[bad], &path),
+                Err(SnarkjsError::InvalidFieldElement(_))
+            ));
+        }
+        assert!(!std::path::Path::new(&path).exists());
+    }
+
+    #[test]
+    fn test_public_signals_check_len() {
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: G1Affine::generator(),
+            beta_g2: G2Affine::generator(),
+            gamma_g2: G2Affine::generator(),
+            delta_g2: G2Affine::generator(),
+            gamma_abc_g1: vec![G1Affine::generator(); 3],
+        };
+        let two = PublicSignals::from(&[Fr::from(1u64), Fr::from(2u64)]);
+        two.check_len(&vk).unwrap();
+        assert_eq!(two.len(), 2);
+
+        let one = PublicSignals::from(vec![Fr::from(1u64)]);
+        assert!(matches!(
+            one.check_len(&vk),
+            Err(SnarkjsError::PublicCountMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_export_public_labeled() {
+        let dir = "target/test-output/public_signals";
+        let public = [Fr::from(33u64), Fr::from(7u64), -Fr::from(1u64)];
+        let path = format!("{dir}/labeled.json");
+        export_public_labeled(&public, &["out", "nullifier", "a"], &path).unwrap();
+
+        // Keys keep the label order rather than being sorted
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.find("\"out\"").unwrap() < text.find("\"a\"").unwrap());
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["out"], "33");
+        assert_eq!(json["nullifier"], "7");
+        assert_eq!(
+            json["a"],
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616"
+        );
+
+        assert!(matches!(
+            export_public_labeled(&public, &["out"], format!("{dir}/short.json")),
+            Err(SnarkjsError::InvalidShape { field: "labels" })
+        ));
+        // A repeated label would silently drop a signal from the object
+        let duplicate = format!("{dir}/duplicate.json");
+        let _ = std::fs::remove_file(&duplicate);
+        assert!(matches!(
+            export_public_labeled(&public, &["out", "nullifier", "out"], &duplicate),
+            Err(SnarkjsError::InvalidShape { field: "labels" })
+        ));
+        assert!(!std::path::Path::new(&duplicate).exists());
+    }
+
+    #[test]
+    fn test_export_vk_with_labels() {
+        let mut rng = StdRng::seed_from_u64(618);
+        let (_, vk) = Groth16::<Bn254>::setup(MulInputsFirst { ab: None }, &mut rng).unwrap();
+        let dir = "target/test-output/public_signals/ic_labels";
+        let _ = std::fs::remove_dir_all(dir);
+        let path = format!("{dir}/verification_key.json");
+        export_vk_with_labels(&vk, 3, &path, &["one", "a", "b", "c"]).unwrap();
+
+        // The key is plain export_vk output; the labels sit next to it
+        let plain = format!("{dir}/plain.json");
+        export_vk(&vk, 3, &plain).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            std::fs::read_to_string(&plain).unwrap()
+        );
+        let text = std::fs::read_to_string(format!("{dir}/ic_labels.json")).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "0": "one", "1": "a", "2": "b", "3": "c" })
+        );
+        assert!(text.find("\"0\"").unwrap() < text.find("\"3\"").unwrap());
+
+        // One distinct label per IC entry, checked before anything is written
+        let bad_dir = "target/test-output/public_signals/ic_labels_bad";
+        let _ = std::fs::remove_dir_all(bad_dir);
+        for labels in [
+            &["a", "b", "c"][..],
+            &["one", "a", "b", "c", "d"],
+            &["one", "a", "b", "a"],
+        ] {
+            assert!(matches!(
+                export_vk_with_labels(&vk, 3, format!("{bad_dir}/vk.json"), labels),
+                Err(SnarkjsError::InvalidShape { field: "labels" })
+            ));
+        }
+        assert!(!std::path::Path::new(bad_dir).exists());
+    }
+
+    #[test]
+    fn test_public_order_circom() {
+        let dir = "target/test-output/public_signals/circom_order";
+        let mut rng = StdRng::seed_from_u64(601);
+        let (pk, vk) = Groth16::<Bn254>::setup(MulInputsFirst { ab: None }, &mut rng).unwrap();
+        let (a, b) = (Fr::from(3u64), Fr::from(7u64));
+        let circuit = MulInputsFirst { ab: Some((a, b)) };
+        let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+
+        // arkworks order [a, b, c] -> circom order [c, a, b]
+        let options = ExportOptions::builder().public_order([2, 0, 1]).build();
+        let json = export_proof_with_options(
+            &proof,
+            &[a, b, a * b],
+            format!("{dir}/proof.json"),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(json.publicSignals, ["21", "3", "7"]);
+        let vk_json =
+            export_vk_with_options(&vk, 3, format!("{dir}/verification_key.json"), &options)
+                .unwrap();
+        let plain = ark_snarkjs::vk_to_snarkjs::<Bn254>(&vk, 3).unwrap();
+        assert_eq!(vk_json.ic[0], plain.ic[0]);
+        assert_eq!(
+            vk_json.ic[1..],
+            [
+                plain.ic[3].clone(),
+                plain.ic[1].clone(),
+                plain.ic[2].clone()
+            ]
+        );
+
+        // Proof and key were reordered together, so they still verify
+        std::fs::write(format!("{dir}/public.json"), r#"["21", "3", "7"]"#).unwrap();
+        assert!(
+            verify_snarkjs::<Bn254>(
+                format!("{dir}/verification_key.json"),
+                format!("{dir}/proof.json"),
+                format!("{dir}/public.json"),
+            )
+            .unwrap()
+        );
+
+        for bad in [vec![0, 1], vec![0, 1, 1], vec![0, 1, 3]] {
+            let options = ExportOptions::builder().public_order(bad).build();
+            assert!(matches!(
+                export_proof_with_options(
+                    &proof,
+                    &[a, b, a * b],
+                    format!("{dir}/bad.json"),
+                    &options
+                ),
+                Err(SnarkjsError::InvalidPublicOrder { len: 3 })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_public_inputs_from_cs() {
+        let ab = Some((Fr::from(3u64), Fr::from(7u64)));
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        MulInputsFirst { ab }
+            .generate_constraints(cs.clone())
+            .unwrap();
+        cs.finalize();
+        assert!(cs.is_satisfied().unwrap());
+        let public = public_inputs_from_cs(&cs);
+        assert_eq!(public, [Fr::from(3u64), Fr::from(7u64), Fr::from(21u64)]);
+
+        // Exported straight from the cs, the proof verifies with snarkjs' files
+        let mut rng = StdRng::seed_from_u64(613);
+        let (pk, vk) = Groth16::<Bn254>::setup(MulInputsFirst { ab: None }, &mut rng).unwrap();
+        let proof = Groth16::<Bn254>::prove(&pk, MulInputsFirst { ab }, &mut rng).unwrap();
+        let dir = "target/test-output/public_from_cs";
+        export_proof(&proof, &public, format!("{dir}/proof.json")).unwrap();
+        export_vk(&vk, public.len(), format!("{dir}/verification_key.json")).unwrap();
+        let signals = serde_json::to_string(&PublicSignals::from(&public)).unwrap();
+        std::fs::write(format!("{dir}/public.json"), signals).unwrap();
+        assert!(
+            verify_snarkjs::<Bn254>(
+                format!("{dir}/verification_key.json"),
+                format!("{dir}/proof.json"),
+                format!("{dir}/public.json"),
+            )
+            .unwrap()
+        );
+
+        // No assignments to read in setup mode or without a system
+        let setup = ConstraintSystem::<Fr>::new_ref();
+        setup.set_mode(SynthesisMode::Setup);
+        MulInputsFirst { ab: None }
+            .generate_constraints(setup.clone())
+            .unwrap();
+        assert_eq!(setup.num_instance_variables(), 4);
+        assert!(public_inputs_from_cs(&setup).is_empty());
+        assert!(public_inputs_from_cs::<Fr>(&ConstraintSystemRef::None).is_empty());
+    }
+}
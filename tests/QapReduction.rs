@@ -0,0 +1,80 @@
+// Export and import work for any R1CS-to-QAP reduction: Groth16<E, QAP>
+// shares Proof<E> and VerifyingKey<E>, so nothing here may depend on QAP
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_groth16::{Groth16, r1cs_to_qap::LibsnarkReduction};
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snarkjs::{
+    export_proof, export_proof_verified, export_vk, import_proof, import_pvk, import_vk,
+};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+
+use ark_bn254::{Bn254, Fr};
+
+type LibsnarkGroth16 = Groth16<Bn254, LibsnarkReduction>;
+
+/// x * y = z (z is public).
+struct MulCircuit {
+    xy: Option<(Fr, Fr)>,
+}
+
+impl ConstraintSynthesizer<Fr> for MulCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let x = FpVar::new_witness(cs.clone(), || {
+            self.xy
+                .map(|(x, _)| x)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let y = FpVar::new_witness(cs.clone(), || {
+            self.xy
+                .map(|(_, y)| y)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let z = FpVar::new_input(cs, || {
+            self.xy
+                .map(|(x, y)| x * y)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        (&x * &y).enforce_equal(&z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_libsnark_reduction_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(566);
+        let (pk, vk) = LibsnarkGroth16::setup(MulCircuit { xy: None }, &mut rng).unwrap();
+        let (x, y) = (Fr::from(5u64), Fr::from(9u64));
+        let proof = LibsnarkGroth16::prove(&pk, MulCircuit { xy: Some((x, y)) }, &mut rng).unwrap();
+        let public = [x * y];
+
+        let dir = "target/test-output/qap/libsnark";
+        export_proof::<Bn254, _>(&proof, &public, format!("{dir}/proof.json")).unwrap();
+        export_vk::<Bn254, _>(&vk, 1, format!("{dir}/verification_key.json")).unwrap();
+
+        let (proof2, public2) = import_proof::<Bn254, _>(format!("{dir}/proof.json")).unwrap();
+        let vk2 = import_vk::<Bn254, _>(format!("{dir}/verification_key.json")).unwrap();
+        assert_eq!((&proof2, &vk2), (&proof, &vk));
+        assert!(LibsnarkGroth16::verify(&vk2, &public2, &proof2).unwrap());
+
+        // Verification does not depend on the reduction either
+        let pvk = import_pvk::<Bn254, _>(format!("{dir}/verification_key.json")).unwrap();
+        export_proof_verified::<Bn254, _>(&pvk, &proof, &public, format!("{dir}/verified.json"))
+            .unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+// rapidsnark layout: proof.json without publicSignals and a fully projective
+// verification_key.json, checked against files written by snarkjs (which
+// rapidsnark's verifier reads)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::AffineRepr;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_snarkjs::{
+    SnarkjsError, export_rapidsnark, import_proof, import_vk, rapidsnark_proof, rapidsnark_vk,
+};
+
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+
+fn reference(name: &str) -> serde_json::Value {
+    let path = format!("{}/tests/data/snarkjs/{name}", env!("CARGO_MANIFEST_DIR"));
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+/// The key of tests/data/snarkjs: generators, with `IC = [g1, -g1]`.
+fn reference_vk() -> VerifyingKey<Bn254> {
+    let (g1, g2) = (G1Affine::generator(), G2Affine::generator());
+    VerifyingKey {
+        alpha_g1: g1,
+        beta_g2: g2,
+        gamma_g2: g2,
+        delta_g2: g2,
+        gamma_abc_g1: vec![g1, -g1],
+    }
+}
+
+/// The proof of tests/data/snarkjs: `(g1, g2, -g1)`.
+fn reference_proof() -> Proof<Bn254> {
+    Proof {
+        a: G1Affine::generator(),
+        b: G2Affine::generator(),
+        c: -G1Affine::generator(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rapidsnark_layout_matches_snarkjs_files() {
+        let vk = serde_json::to_value(rapidsnark_vk(&reference_vk()).unwrap()).unwrap();
+        assert_eq!(vk, reference("verification_key.json"));
+
+        let proof = serde_json::to_value(rapidsnark_proof(&reference_proof()).unwrap()).unwrap();
+        assert_eq!(proof, reference("proof.json"));
+        assert!(proof.get("publicSignals").is_none());
+    }
+
+    #[test]
+    fn test_export_rapidsnark() {
+        let dir = "target/test-output/rapidsnark";
+        let (vk, proof) = (reference_vk(), reference_proof());
+        export_rapidsnark(&proof, &[Fr::from(3u64)], &vk, dir).unwrap();
+
+        assert_eq!(
+            import_vk::<Bn254, _>(format!("{dir}/verification_key.json")).unwrap(),
+            vk
+        );
+        let (imported, _) = import_proof::<Bn254, _>(format!("{dir}/proof.json")).unwrap();
+        assert_eq!(imported, proof);
+        let public = std::fs::read_to_string(format!("{dir}/public.json")).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<String>>(&public).unwrap(),
+            vec!["3"]
+        );
+
+        assert!(matches!(
+            export_rapidsnark(&proof, &[], &vk, format!("{dir}/short")),
+            Err(SnarkjsError::PublicCountMismatch {
+                expected: 1,
+                found: 0
+            })
+        ));
+
+        let mut empty = vk;
+        empty.gamma_abc_g1.clear();
+        assert!(matches!(
+            rapidsnark_vk(&empty),
+            Err(SnarkjsError::IcLengthMismatch { .. })
+        ));
+    }
+}
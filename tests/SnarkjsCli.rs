@@ -0,0 +1,118 @@
+// Opt-in end-to-end check against the real snarkjs CLI: exported files from
+// the x * y = z circuit must pass `snarkjs groth16 verify`.
+// Run with: RUN_SNARKJS_TESTS=1 cargo test --test SnarkjsCli
+// (needs `snarkjs` on PATH, e.g. `npm install -g snarkjs`)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_ec::{AffineRepr, pairing::Pairing};
+use ark_ff::PrimeField;
+use ark_groth16::Groth16;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snarkjs::{AsFp2, CurveTag, PublicSignals, export_proof, export_vk};
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+use std::process::Command;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+
+/// Simple circuit: check that x * y = z (where z is a public input).
+struct MulCircuit<F: PrimeField> {
+    xy: Option<(F, F)>,
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for MulCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let x = FpVar::new_witness(cs.clone(), || {
+            self.xy
+                .map(|(x, _)| x)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let y = FpVar::new_witness(cs.clone(), || {
+            self.xy
+                .map(|(_, y)| y)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let z = FpVar::new_input(cs, || {
+            self.xy
+                .map(|(x, y)| x * y)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        (&x * &y).enforce_equal(&z)
+    }
+}
+
+/// Whether the opt-in environment variable is set.
+fn enabled() -> bool {
+    std::env::var_os("RUN_SNARKJS_TESTS").is_some_and(|v| !v.is_empty() && v != "0")
+}
+
+/// Prove x * y = z on `E`, export the three snarkjs files and run
+/// `snarkjs groth16 verify` on them.
+fn snarkjs_verifies<E>(label: &str)
+where
+    E: Pairing + CurveTag,
+    <E::G1Affine as AffineRepr>::BaseField: PrimeField,
+    <E::G2Affine as AffineRepr>::BaseField: AsFp2,
+{
+    let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+    let (pk, vk) = Groth16::<E>::setup(MulCircuit { xy: None }, &mut rng).unwrap();
+    let (x, y) = (
+        E::ScalarField::from(641u64),
+        E::ScalarField::from(6_700_417u64),
+    );
+    let proof = Groth16::<E>::prove(&pk, MulCircuit { xy: Some((x, y)) }, &mut rng).unwrap();
+    let public = [x * y];
+
+    let dir = format!("target/test-output/snarkjs_cli/{label}");
+    let (vk_path, public_path, proof_path) = (
+        format!("{dir}/verification_key.json"),
+        format!("{dir}/public.json"),
+        format!("{dir}/proof.json"),
+    );
+    export_vk::<E, _>(&vk, public.len(), &vk_path).unwrap();
+    export_proof::<E, _>(&proof, &public, &proof_path).unwrap();
+    std::fs::write(
+        &public_path,
+        serde_json::to_string_pretty(&PublicSignals::from(&public)).unwrap(),
+    )
+    .unwrap();
+
+    let output = Command::new("snarkjs")
+        .args(["groth16", "verify", &vk_path, &public_path, &proof_path])
+        .output()
+        .expect("failed to run snarkjs; is it installed and on PATH?");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success() && stdout.contains("OK"),
+        "[{label}] snarkjs rejected the exported proof:\n{stdout}{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snarkjs_groth16_verify() {
+        if !enabled() {
+            eprintln!("skipping: set RUN_SNARKJS_TESTS=1 to run against the snarkjs CLI");
+            return;
+        }
+        snarkjs_verifies::<Bn254>("Bn254");
+        snarkjs_verifies::<Bls12_381>("Bls12-381");
+    }
+}
@@ -0,0 +1,468 @@
+// Tests for the shared snarkjs helpers (curve ids, field/point conversion)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::short_weierstrass::Affine;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+use ark_snarkjs::SnarkjsError;
+use ark_snarkjs::snarkjs_common::{
+    CurveId, CurveTag, Endianness, G1Coords, curve_name_from_str, dec_to_f, f_to_dec, f_to_hex,
+    g1_from_dec, g1_xy, g2_from_compressed, g2_from_dec, g2_xyxy, is_supported,
+    parse_field_checked, parse_fields, supported_curves,
+};
+use ark_std::UniformRand;
+use ark_std::rand::{RngCore, SeedableRng};
+use ark_std::test_rng;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curve_name_roundtrip() {
+        assert_eq!(curve_name_from_str(Bn254::NAME), Some(CurveId::Bn128));
+        assert_eq!(
+            curve_name_from_str(Bls12_381::NAME),
+            Some(CurveId::Bls12381)
+        );
+        assert_eq!(CurveId::Bn128.name(), "bn128");
+        assert_eq!(CurveId::Bls12381.name(), "bls12381");
+        assert_eq!(curve_name_from_str("secp256k1"), None);
+    }
+
+    #[test]
+    fn test_curve_id_serde() {
+        assert_eq!(Bn254::ID, CurveId::Bn128);
+        assert_eq!(serde_json::to_string(&Bn254::ID).unwrap(), r#""bn128""#);
+        assert_eq!(
+            serde_json::to_string(&Bls12_381::ID).unwrap(),
+            r#""bls12381""#
+        );
+
+        // snarkjs names and arkworks spellings both deserialize
+        for (s, id) in [
+            ("bn128", CurveId::Bn128),
+            ("bn254", CurveId::Bn128),
+            ("bls12381", CurveId::Bls12381),
+            ("bls12-381", CurveId::Bls12381),
+        ] {
+            let parsed: CurveId = serde_json::from_value(s.into()).unwrap();
+            assert_eq!(parsed, id, "{s}");
+            assert_eq!(s.parse::<CurveId>().unwrap(), id, "{s}");
+        }
+        assert!(serde_json::from_str::<CurveId>(r#""secp256k1""#).is_err());
+        assert!(matches!(
+            "BN128".parse::<CurveId>(),
+            Err(ark_snarkjs::SnarkjsError::UnknownCurve(_))
+        ));
+    }
+
+    #[test]
+    fn test_supported_curves() {
+        assert!(supported_curves().contains(&Bn254::NAME));
+        assert!(supported_curves().contains(&Bls12_381::NAME));
+        assert!(is_supported("bn128"));
+        assert!(!is_supported("bn254"), "only snarkjs names are listed");
+        assert_eq!(is_supported("mnt4753"), cfg!(feature = "mnt4_753"));
+    }
+
+    #[test]
+    fn test_point_parse_roundtrip() {
+        let g1 = ark_bn254::G1Affine::generator();
+        assert_eq!(
+            g1_from_dec::<ark_bn254::G1Affine>(&g1_xy(&g1).unwrap()).unwrap(),
+            g1
+        );
+        let g2 = ark_bls12_381::G2Affine::generator();
+        assert_eq!(
+            g2_from_dec::<ark_bls12_381::G2Affine>(&g2_xyxy(&g2).unwrap()).unwrap(),
+            g2
+        );
+
+        // The identity has no affine coordinates
+        assert!(matches!(
+            g1_xy(&ark_bn254::G1Affine::zero()),
+            Err(SnarkjsError::PointAtInfinity)
+        ));
+        assert!(matches!(
+            g2_xyxy(&ark_bls12_381::G2Affine::zero()),
+            Err(SnarkjsError::PointAtInfinity)
+        ));
+    }
+
+    #[test]
+    fn test_g2_component_order() {
+        const { assert!(!Bn254::G2_SWAPPED && !Bls12_381::G2_SWAPPED) };
+
+        let g2 = ark_bn254::G2Affine::generator();
+        let [x, y] = g2_xyxy(&g2).unwrap();
+        assert_eq!(x, [f_to_dec(&g2.x.c0), f_to_dec(&g2.x.c1)]);
+        assert_eq!(y, [f_to_dec(&g2.y.c0), f_to_dec(&g2.y.c1)]);
+    }
+
+    /// Golden values: the G2 generators exactly as snarkjs writes them
+    /// (`curve.G2.toObject(curve.G2.g)` in ffjavascript), `[c0, c1]` per
+    /// coordinate. A swap here would break every exported key downstream.
+    #[test]
+    fn test_g2_generator_matches_snarkjs() {
+        let bn254 = [
+            [
+                "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+                "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+            ],
+            [
+                "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+                "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+            ],
+        ];
+        assert_eq!(g2_xyxy(&ark_bn254::G2Affine::generator()).unwrap(), bn254);
+
+        let bls12_381 = [
+            [
+                "352701069587466618187139116011060144890029952792775240219908644239793785735715026873347600343865175952761926303160",
+                "3059144344244213709971259814753781636986470325476647558659373206291635324768958432433509563104347017837885763365758",
+            ],
+            [
+                "1985150602287291935568054521177171638300868978215655730859378665066344726373823718423869104263333984641494340347905",
+                "927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582",
+            ],
+        ];
+        assert_eq!(
+            g2_xyxy(&ark_bls12_381::G2Affine::generator()).unwrap(),
+            bls12_381
+        );
+
+        // And back: the same strings parse to the generator
+        let owned = |p: [[&str; 2]; 2]| p.map(|c| c.map(String::from));
+        assert_eq!(
+            g2_from_dec::<ark_bn254::G2Affine>(&owned(bn254)).unwrap(),
+            ark_bn254::G2Affine::generator()
+        );
+        assert_eq!(
+            g2_from_dec::<ark_bls12_381::G2Affine>(&owned(bls12_381)).unwrap(),
+            ark_bls12_381::G2Affine::generator()
+        );
+    }
+
+    /// BLS12-381's base field (381 bits) is wider than its scalar field (255
+    /// bits): both coordinates of the G1 generator are above `2^255`, and
+    /// `g1_xy` must write them in full (snarkjs `curve.G1.g`).
+    #[test]
+    fn test_bls12_381_g1_generator_full_width() {
+        let g1 = ark_bls12_381::G1Affine::generator();
+        let golden = [
+            "3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507",
+            "1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569",
+        ];
+        assert_eq!(g1_xy(&g1).unwrap(), golden);
+
+        // Wider than any scalar: 2^255 has 77 digits, neither fits Fr
+        let two_255 =
+            "57896044618658097711785492504343953926634992332820282019728792003956564819968";
+        for coord in golden {
+            assert!(coord.len() > two_255.len());
+            assert!(matches!(
+                parse_field_checked::<ark_bls12_381::Fr>(coord),
+                Err(SnarkjsError::InvalidFieldElement(_))
+            ));
+        }
+        assert_eq!(
+            g1_from_dec::<ark_bls12_381::G1Affine>(&golden.map(String::from)).unwrap(),
+            g1
+        );
+
+        // Full width holds for any point, not only the generator
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        for _ in 0..16 {
+            let p = ark_bls12_381::G1Projective::rand(&mut rng).into_affine();
+            let (x, y) = p.xy().unwrap();
+            assert_eq!(
+                g1_xy(&p).unwrap(),
+                [x.into_bigint().to_string(), y.into_bigint().to_string()]
+            );
+        }
+    }
+
+    #[test]
+    fn test_g1_coords_either_shape() {
+        let g1 = ark_bn254::G1Affine::generator();
+        let [x, y] = g1_xy(&g1).unwrap();
+
+        let affine: G1Coords = serde_json::from_str(&format!(r#"["{x}","{y}"]"#)).unwrap();
+        let projective: G1Coords = serde_json::from_str(&format!(r#"["{x}","{y}","1"]"#)).unwrap();
+        assert_eq!(affine, G1Coords::Affine([x.clone(), y.clone()]));
+        assert_eq!(affine.to_point::<ark_bn254::G1Affine>().unwrap(), g1);
+        assert_eq!(projective.to_point::<ark_bn254::G1Affine>().unwrap(), g1);
+
+        // z = 0 is the point at infinity, as snarkjs writes it
+        let inf: G1Coords = serde_json::from_str(r#"["0","1","0"]"#).unwrap();
+        assert!(inf.to_point::<ark_bn254::G1Affine>().unwrap().is_zero());
+
+        assert!(serde_json::from_str::<G1Coords>(r#"["1"]"#).is_err());
+        assert!(serde_json::from_str::<G1Coords>(r#"["1","2","3","4"]"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_fields_reports_index() {
+        let mut values: Vec<String> = (0..100u64).map(|i| i.to_string()).collect();
+        let parsed = parse_fields::<ark_bn254::Fr>(&values).unwrap();
+        assert_eq!(parsed[42], ark_bn254::Fr::from(42u64));
+
+        values[57] = "12a".to_string();
+        values[80] = "-1".to_string();
+        match parse_fields::<ark_bn254::Fr>(&values) {
+            Err(SnarkjsError::InvalidFieldElementAt { index, value }) => {
+                assert_eq!((index, value.as_str()), (57, "12a"));
+            }
+            other => panic!("expected InvalidFieldElementAt, got {other:?}"),
+        }
+    }
+
+    /// First point on the curve with x = 1, 2, ...; for curves with a
+    /// cofactor it is (with overwhelming probability) outside the subgroup.
+    fn on_curve_point<P: ark_ec::short_weierstrass::SWCurveConfig>() -> Affine<P> {
+        (1u64..)
+            .find_map(|x| Affine::<P>::get_point_from_x_unchecked(P::BaseField::from(x), false))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_point_parse_rejects_wrong_subgroup() {
+        // BN254 G2 and BLS12-381 G1 both have a non-trivial cofactor
+        let p = on_curve_point::<ark_bn254::g2::Config>();
+        assert!(p.is_on_curve() && !p.is_in_correct_subgroup_assuming_on_curve());
+        let xy = [
+            [f_to_dec(&p.x.c0), f_to_dec(&p.x.c1)],
+            [f_to_dec(&p.y.c0), f_to_dec(&p.y.c1)],
+        ];
+        assert!(matches!(
+            g2_from_dec::<ark_bn254::G2Affine>(&xy),
+            Err(SnarkjsError::NotInSubgroup)
+        ));
+
+        let p = on_curve_point::<ark_bls12_381::g1::Config>();
+        assert!(p.is_on_curve() && !p.is_in_correct_subgroup_assuming_on_curve());
+        assert!(matches!(
+            g1_from_dec::<ark_bls12_381::G1Affine>(&[f_to_dec(&p.x), f_to_dec(&p.y)]),
+            Err(SnarkjsError::NotInSubgroup)
+        ));
+
+        // Subgroup points still parse
+        let g = ark_bn254::G2Affine::generator();
+        assert_eq!(
+            g2_from_dec::<ark_bn254::G2Affine>(&g2_xyxy(&g).unwrap()).unwrap(),
+            g
+        );
+    }
+
+    #[test]
+    fn test_point_parse_rejects_invalid() {
+        // (1, 1) does not satisfy y^2 = x^3 + 3
+        let off_curve = ["1".to_string(), "1".to_string()];
+        assert!(matches!(
+            g1_from_dec::<ark_bn254::G1Affine>(&off_curve),
+            Err(SnarkjsError::NotOnCurve)
+        ));
+
+        // G2: x = 1 with y = 1 is off the twist
+        let one = ["1".to_string(), "0".to_string()];
+        assert!(matches!(
+            g2_from_dec::<ark_bn254::G2Affine>(&[one.clone(), one]),
+            Err(SnarkjsError::NotOnCurve)
+        ));
+
+        for bad in [
+            "",
+            "-1",
+            "0x10",
+            "1 ",
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        ] {
+            assert!(matches!(
+                parse_field_checked::<ark_bn254::Fr>(bad),
+                Err(SnarkjsError::InvalidFieldElement(_))
+            ));
+        }
+    }
+
+    /// `dec_to_f(f_to_dec(x)) == x` for random elements of `F`.
+    fn dec_roundtrip<F: PrimeField>(rng: &mut impl RngCore) {
+        for _ in 0..256 {
+            let x = F::rand(rng);
+            assert_eq!(dec_to_f::<F>(&f_to_dec(&x)).unwrap(), x);
+        }
+        // Small values read as plain integers, not Montgomery form
+        assert_eq!(dec_to_f::<F>("7").unwrap(), F::from(7u64));
+        assert_eq!(f_to_dec(&F::from(7u64)), "7");
+    }
+
+    /// Edge values: `0`, `1` and `r - 1` print as plain decimals.
+    fn dec_edges<F: PrimeField>(minus_one: &str) {
+        assert_eq!(f_to_dec(&F::zero()), "0");
+        assert_eq!(f_to_dec(&F::one()), "1");
+        assert_eq!(f_to_dec(&-F::one()), minus_one);
+        assert_eq!(dec_to_f::<F>("0").unwrap(), F::zero());
+        assert_eq!(dec_to_f::<F>(minus_one).unwrap(), -F::one());
+    }
+
+    #[test]
+    fn test_f_to_dec_edge_values() {
+        dec_edges::<ark_bn254::Fr>(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616",
+        );
+        dec_edges::<ark_bls12_381::Fr>(
+            "52435875175126190479447740508185965837690552500527637822603658699938581184512",
+        );
+    }
+
+    #[test]
+    fn test_dec_to_f_inverts_f_to_dec() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        dec_roundtrip::<ark_bn254::Fr>(&mut rng);
+        dec_roundtrip::<ark_bn254::Fq>(&mut rng);
+        dec_roundtrip::<ark_bls12_381::Fr>(&mut rng);
+        dec_roundtrip::<ark_bls12_381::Fq>(&mut rng);
+    }
+
+    /// BN254 base field modulus `q` (larger than the scalar modulus `r`).
+    const BN254_Q: &str =
+        "21888242871839275222246405745257275088696311157297823662689037894645226208583";
+    /// BLS12-381 base field modulus `p` (381 bits, vs. 255 for the scalar field).
+    const BLS12_381_P: &str = "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787";
+
+    /// `q - 1` from the decimal modulus, without going through the field.
+    fn minus_one(modulus: &str) -> String {
+        (modulus.parse::<num_bigint::BigUint>().unwrap() - 1u32).to_string()
+    }
+
+    #[test]
+    fn test_f_to_dec_base_fields() {
+        // Largest base field elements: full width, nothing truncated or reduced
+        // modulo the (smaller) scalar field
+        assert_eq!(f_to_dec(&-ark_bn254::Fq::from(1u64)), minus_one(BN254_Q));
+        assert_eq!(
+            f_to_dec(&-ark_bls12_381::Fq::from(1u64)),
+            minus_one(BLS12_381_P)
+        );
+        assert_eq!(f_to_dec(&-ark_bls12_381::Fq::from(1u64)).len(), 115);
+        assert!(
+            f_to_dec(&-ark_bls12_381::Fq::from(1u64))
+                .parse::<num_bigint::BigUint>()
+                .unwrap()
+                > f_to_dec(&-ark_bls12_381::Fr::from(1u64))
+                    .parse::<num_bigint::BigUint>()
+                    .unwrap()
+        );
+
+        // BN254 generators: G1 = (1, 2), -G1 = (1, q - 2); G2 as in EIP-197
+        assert_eq!(
+            g1_xy(&ark_bn254::G1Affine::generator()).unwrap(),
+            ["1", "2"]
+        );
+        let neg_g1 = -ark_bn254::G1Affine::generator();
+        assert_eq!(
+            g1_xy(&neg_g1).unwrap()[1],
+            (BN254_Q.parse::<num_bigint::BigUint>().unwrap() - 2u32).to_string()
+        );
+        assert_eq!(
+            g2_xyxy(&ark_bn254::G2Affine::generator()).unwrap(),
+            [
+                [
+                    "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+                    "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+                ],
+                [
+                    "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+                    "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+                ],
+            ]
+        );
+
+        // BLS12-381 G1 generator: 381-bit coordinates
+        assert_eq!(
+            g1_xy(&ark_bls12_381::G1Affine::generator()).unwrap(),
+            [
+                "3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507",
+                "1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_f_to_hex_endianness() {
+        let x = ark_bn254::Fr::from(0x0102u64);
+        let mut be = String::from("0x");
+        be.push_str(&"00".repeat(30));
+        be.push_str("0102");
+        let mut le = String::from("0x0201");
+        le.push_str(&"00".repeat(30));
+
+        assert_eq!(f_to_hex(&x, Endianness::Big), be);
+        assert_eq!(f_to_hex(&x, Endianness::Little), le);
+        assert_eq!(f_to_hex(&x, Endianness::default()), be);
+
+        // Full width of the field: 48 bytes for the BLS12-381 base field
+        let one = ark_bls12_381::Fq::from(1u64);
+        assert_eq!(f_to_hex(&one, Endianness::Big).len(), 2 + 96);
+        assert!(f_to_hex(&one, Endianness::Big).ends_with("01"));
+        assert!(f_to_hex(&one, Endianness::Little).starts_with("0x01"));
+    }
+
+    #[test]
+    fn test_g2_from_compressed() {
+        use ark_ec::CurveGroup;
+        use ark_serialize::CanonicalSerialize;
+        use ark_std::UniformRand;
+
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
+        for _ in 0..8 {
+            let p = ark_bn254::G2Projective::rand(&mut rng).into_affine();
+            let x = [f_to_dec(&p.x.c0), f_to_dec(&p.x.c1)];
+
+            // arkworks' compressed BN254 encoding: x, sign in the top bit
+            let mut bytes = Vec::new();
+            p.serialize_compressed(&mut bytes).unwrap();
+            let y_is_largest = bytes[bytes.len() - 1] >> 7 == 1;
+
+            let q: ark_bn254::G2Affine = g2_from_compressed(&x, y_is_largest).unwrap();
+            assert_eq!(q, p);
+            let neg: ark_bn254::G2Affine = g2_from_compressed(&x, !y_is_largest).unwrap();
+            assert_eq!(neg, -p);
+        }
+
+        // Same on BLS12-381
+        let p = ark_bls12_381::G2Projective::rand(&mut rng).into_affine();
+        let x = [f_to_dec(&p.x.c0), f_to_dec(&p.x.c1)];
+        let q: ark_bls12_381::G2Affine = g2_from_compressed(&x, p.y > -p.y).unwrap();
+        assert_eq!(q, p);
+
+        // Not every x has a point above it
+        let mut found_invalid = false;
+        for i in 0u64..64 {
+            let x = [i.to_string(), "1".to_string()];
+            match g2_from_compressed::<ark_bn254::g2::Config>(&x, false) {
+                Err(SnarkjsError::NotOnCurve) => found_invalid = true,
+                Ok(_) | Err(SnarkjsError::NotInSubgroup) => {}
+                Err(e) => panic!("unexpected error {e:?}"),
+            }
+        }
+        assert!(found_invalid);
+        assert!(matches!(
+            g2_from_compressed::<ark_bn254::g2::Config>(&["x".into(), "0".into()], false),
+            Err(SnarkjsError::InvalidFieldElement(_))
+        ));
+    }
+}
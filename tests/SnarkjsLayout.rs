@@ -0,0 +1,155 @@
+// Key order of exported files against snarkjs reference files
+// (tests/data/snarkjs, in the layout written by snarkjs with
+// `JSON.stringify(.., null, 1)` for a vk/proof built from the generators)
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::AffineRepr;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_snarkjs::{ExportOptions, export_proof_with_options, export_vk_with_options};
+
+use ark_bn254::{Bn254, G1Affine, G2Affine};
+
+fn reference(name: &str) -> String {
+    let path = format!("{}/tests/data/snarkjs/{name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(path).unwrap()
+}
+
+/// Top-level object keys of a JSON document, in file order.
+fn top_level_keys(json: &str) -> Vec<String> {
+    let (mut keys, mut depth, mut in_str, mut escaped) = (Vec::new(), 0, false, false);
+    let mut current = String::new();
+    let mut last_str = None;
+    for c in json.chars() {
+        if in_str {
+            match (escaped, c) {
+                (true, _) => escaped = false,
+                (false, '\\') => escaped = true,
+                (false, '"') => {
+                    in_str = false;
+                    last_str = Some(std::mem::take(&mut current));
+                    continue;
+                }
+                _ => {}
+            }
+            current.push(c);
+            continue;
+        }
+        match c {
+            '"' => in_str = true,
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth -= 1,
+            ':' if depth == 1 => keys.extend(last_str.take()),
+            _ if c.is_whitespace() => continue,
+            _ => {}
+        }
+        if c != '"' && c != ':' {
+            last_str = None;
+        }
+    }
+    keys
+}
+
+/// Keys of `ours` must appear in the same relative order as in `reference`
+/// (keys that only one side has are ignored).
+fn assert_same_order(ours: &[String], reference: &[String]) {
+    let common = |a: &[String], b: &[String]| -> Vec<String> {
+        a.iter().filter(|k| b.contains(k)).cloned().collect()
+    };
+    assert_eq!(common(ours, reference), common(reference, ours));
+    assert!(
+        common(ours, reference).len() >= 5,
+        "too few keys in common: {ours:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vk_key_order_matches_snarkjs() {
+        let (g1, g2) = (G1Affine::generator(), G2Affine::generator());
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: g1,
+            beta_g2: g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            gamma_abc_g1: vec![g1, -g1],
+        };
+        let path = "target/test-output/layout/verification_key.json";
+        export_vk_with_options::<Bn254, _>(&vk, 1, path, &ExportOptions::with_indent_width(1))
+            .unwrap();
+
+        let ours = top_level_keys(&std::fs::read_to_string(path).unwrap());
+        let theirs = top_level_keys(&reference("verification_key.json"));
+        assert_eq!(
+            theirs,
+            [
+                "protocol",
+                "curve",
+                "nPublic",
+                "vk_alpha_1",
+                "vk_beta_2",
+                "vk_gamma_2",
+                "vk_delta_2",
+                "vk_alphabeta_12",
+                "IC"
+            ]
+        );
+        assert_same_order(&ours, &theirs);
+        assert!(ours.contains(&"nPublic".to_string()));
+
+        // With vk_alphabeta_12 the keys are exactly those of snarkjs
+        let options = ExportOptions {
+            alphabeta: true,
+            ..ExportOptions::with_indent_width(1)
+        };
+        export_vk_with_options::<Bn254, _>(&vk, 1, path, &options).unwrap();
+        let written = std::fs::read_to_string(path).unwrap();
+        assert_eq!(top_level_keys(&written), theirs);
+        let ours: serde_json::Value = serde_json::from_str(&written).unwrap();
+        let reference: serde_json::Value =
+            serde_json::from_str(&reference("verification_key.json")).unwrap();
+        assert_eq!(ours["vk_alphabeta_12"], reference["vk_alphabeta_12"]);
+    }
+
+    #[test]
+    fn test_proof_key_order_matches_snarkjs() {
+        let (g1, g2) = (G1Affine::generator(), G2Affine::generator());
+        let proof = Proof::<Bn254> {
+            a: g1,
+            b: g2,
+            c: -g1,
+        };
+        let path = "target/test-output/layout/proof.json";
+        export_proof_with_options::<Bn254, _>(
+            &proof,
+            &[],
+            path,
+            &ExportOptions::with_indent_width(1),
+        )
+        .unwrap();
+
+        let ours = std::fs::read_to_string(path).unwrap();
+        let theirs = reference("proof.json");
+        assert_eq!(
+            top_level_keys(&theirs),
+            ["pi_a", "pi_b", "pi_c", "protocol", "curve"]
+        );
+        assert_same_order(&top_level_keys(&ours), &top_level_keys(&theirs));
+
+        // Apart from the extra publicSignals, the file is byte-identical
+        let trimmed = ours.replace(",\n \"publicSignals\": []", "");
+        assert_eq!(trimmed, theirs);
+    }
+}
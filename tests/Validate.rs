@@ -0,0 +1,68 @@
+// validate_proof / validate_vk: the export checks without writing a file
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::AffineRepr;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_snarkjs::{SnarkjsError, validate_proof, validate_vk};
+
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+
+fn proof() -> Proof<Bn254> {
+    Proof {
+        a: G1Affine::generator(),
+        b: G2Affine::generator(),
+        c: G1Affine::generator(),
+    }
+}
+
+fn vk() -> VerifyingKey<Bn254> {
+    VerifyingKey {
+        alpha_g1: G1Affine::generator(),
+        beta_g2: G2Affine::generator(),
+        gamma_g2: G2Affine::generator(),
+        delta_g2: G2Affine::generator(),
+        gamma_abc_g1: vec![G1Affine::generator(); 2],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_proof() {
+        validate_proof::<Bn254>(&proof(), &[Fr::from(5u64)]).unwrap();
+
+        // Unlike key points, proof points may be the identity (z = 0)
+        let mut zero = proof();
+        zero.b = G2Affine::zero();
+        validate_proof::<Bn254>(&zero, &[]).unwrap();
+    }
+
+    #[test]
+    fn test_validate_vk() {
+        validate_vk::<Bn254>(&vk(), 1).unwrap();
+
+        // An unused input's IC entry may be the identity, alpha may not
+        let mut unused = vk();
+        unused.gamma_abc_g1[1] = G1Affine::zero();
+        validate_vk::<Bn254>(&unused, 1).unwrap();
+
+        let mut bad = vk();
+        bad.alpha_g1 = G1Affine::zero();
+        assert!(matches!(
+            validate_vk::<Bn254>(&bad, 1),
+            Err(SnarkjsError::PointAtInfinity)
+        ));
+    }
+}
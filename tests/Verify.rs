@@ -0,0 +1,293 @@
+// verify_snarkjs: Groth16 verification from the three snarkjs files, with the
+// number of public inputs cross-checked first; verify_from_json against
+// ark_groth16's verifier; verify_detailed naming the check that failed
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_groth16::{Groth16, prepare_verifying_key};
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snarkjs::{
+    CurveId, SnarkjsError, VerifyResult, export_verify_manifest, proof_to_snarkjs, verify_detailed,
+    verify_from_json, verify_snarkjs, verify_snarkjs_from_str, vk_to_snarkjs,
+};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+
+use ark_bn254::{Bn254, Fr};
+
+/// x * y = z (z is public).
+struct MulCircuit {
+    xy: Option<(Fr, Fr)>,
+}
+
+impl ConstraintSynthesizer<Fr> for MulCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let x = FpVar::new_witness(cs.clone(), || {
+            self.xy
+                .map(|(x, _)| x)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let y = FpVar::new_witness(cs.clone(), || {
+            self.xy
+                .map(|(_, y)| y)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let z = FpVar::new_input(cs, || {
+            self.xy
+                .map(|(x, y)| x * y)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        (&x * &y).enforce_equal(&z)
+    }
+}
+
+/// Prove 3 * 5 = 15 and write the snarkjs files into `dir`.
+fn write_files(dir: &str) {
+    let mut rng = StdRng::seed_from_u64(588);
+    let (pk, vk) = Groth16::<Bn254>::setup(MulCircuit { xy: None }, &mut rng).unwrap();
+    let (x, y) = (Fr::from(3u64), Fr::from(5u64));
+    let proof = Groth16::<Bn254>::prove(&pk, MulCircuit { xy: Some((x, y)) }, &mut rng).unwrap();
+    export_verify_manifest::<Bn254, _>(&proof, &[x * y], &vk, dir).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_snarkjs() {
+        let dir = "target/test-output/verify";
+        write_files(dir);
+        let read = |name: &str| std::fs::read_to_string(format!("{dir}/{name}")).unwrap();
+        let (vk, proof) = (read("verification_key.json"), read("proof.json"));
+
+        let ok = verify_snarkjs::<Bn254>(
+            format!("{dir}/verification_key.json"),
+            format!("{dir}/proof.json"),
+            format!("{dir}/public.json"),
+        )
+        .unwrap();
+        assert!(ok);
+
+        // Wrong value, right count: a plain verification failure
+        assert!(!verify_snarkjs_from_str::<Bn254>(&vk, &proof, r#"["16"]"#).unwrap());
+
+        // snarkjs' own proof.json has no publicSignals
+        let mut bare: serde_json::Value = serde_json::from_str(&proof).unwrap();
+        bare.as_object_mut().unwrap().remove("publicSignals");
+        assert!(verify_snarkjs_from_str::<Bn254>(&vk, &bare.to_string(), r#"["15"]"#).unwrap());
+    }
+
+    #[test]
+    fn test_verify_snarkjs_inconsistent_counts() {
+        let dir = "target/test-output/verify_counts";
+        write_files(dir);
+        let read = |name: &str| std::fs::read_to_string(format!("{dir}/{name}")).unwrap();
+        let (vk, proof) = (read("verification_key.json"), read("proof.json"));
+
+        let err = verify_snarkjs_from_str::<Bn254>(&vk, &proof, r#"["15", "1"]"#).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                SnarkjsError::InconsistentPublicCount {
+                    vk: 1,
+                    proof: Some(1),
+                    public: 2
+                }
+            ),
+            "{err:?}"
+        );
+        assert_eq!(
+            err.to_string(),
+            "inconsistent number of public inputs: vk nPublic = 1, \
+             proof publicSignals = 1, public signals = 2"
+        );
+
+        // The proof's own signals disagree with public.json
+        let mut extra: serde_json::Value = serde_json::from_str(&proof).unwrap();
+        extra["publicSignals"] = serde_json::json!(["15", "0"]);
+        assert!(matches!(
+            verify_snarkjs_from_str::<Bn254>(&vk, &extra.to_string(), r#"["15"]"#),
+            Err(SnarkjsError::InconsistentPublicCount {
+                vk: 1,
+                proof: Some(2),
+                public: 1
+            })
+        ));
+
+        let mut bare: serde_json::Value = serde_json::from_str(&proof).unwrap();
+        bare.as_object_mut().unwrap().remove("publicSignals");
+        assert!(matches!(
+            verify_snarkjs_from_str::<Bn254>(&vk, &bare.to_string(), "[]"),
+            Err(SnarkjsError::InconsistentPublicCount {
+                vk: 1,
+                proof: None,
+                public: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn test_verify_from_json_agrees_with_ark_groth16() {
+        let mut rng = StdRng::seed_from_u64(600);
+        let (pk, vk) = Groth16::<Bn254>::setup(MulCircuit { xy: None }, &mut rng).unwrap();
+        let pvk = prepare_verifying_key(&vk);
+        let vk_json = vk_to_snarkjs::<Bn254>(&vk, 1).unwrap();
+
+        for i in 1..4u64 {
+            let (x, y) = (Fr::from(i), Fr::from(i + 6));
+            let proof =
+                Groth16::<Bn254>::prove(&pk, MulCircuit { xy: Some((x, y)) }, &mut rng).unwrap();
+            let other =
+                Groth16::<Bn254>::prove(&pk, MulCircuit { xy: Some((y, y)) }, &mut rng).unwrap();
+
+            // Valid, wrong public input, and a proof for another statement
+            for (proof, public) in [
+                (&proof, x * y),
+                (&proof, x * y + Fr::from(1u64)),
+                (&other, x * y),
+            ] {
+                let expected = Groth16::<Bn254>::verify_proof(&pvk, proof, &[public]).unwrap();
+                let json = proof_to_snarkjs::<Bn254>(proof, &[public]).unwrap();
+                assert_eq!(
+                    verify_from_json::<Bn254>(&vk_json, &json).unwrap(),
+                    expected
+                );
+            }
+            let json = proof_to_snarkjs::<Bn254>(&proof, &[x * y]).unwrap();
+            assert!(verify_from_json::<Bn254>(&vk_json, &json).unwrap());
+        }
+
+        // One public signal too many
+        let proof = Groth16::<Bn254>::prove(
+            &pk,
+            MulCircuit {
+                xy: Some((Fr::from(2u64), Fr::from(2u64))),
+            },
+            &mut rng,
+        )
+        .unwrap();
+        let json = proof_to_snarkjs::<Bn254>(&proof, &[Fr::from(4u64), Fr::from(0u64)]).unwrap();
+        assert!(matches!(
+            verify_from_json::<Bn254>(&vk_json, &json),
+            Err(SnarkjsError::InconsistentPublicCount {
+                vk: 1,
+                proof: Some(2),
+                public: 2
+            })
+        ));
+
+        // A truncated IC is reported against the key's own nPublic
+        let mut short_vk = vk_to_snarkjs::<Bn254>(&vk, 1).unwrap();
+        short_vk.ic.pop();
+        let json = proof_to_snarkjs::<Bn254>(&proof, &[Fr::from(4u64)]).unwrap();
+        assert!(matches!(
+            verify_from_json::<Bn254>(&short_vk, &json),
+            Err(SnarkjsError::IcLengthMismatch {
+                n_public: 1,
+                ic_len: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_verify_detailed() {
+        let mut rng = StdRng::seed_from_u64(617);
+        let (pk, vk) = Groth16::<Bn254>::setup(MulCircuit { xy: None }, &mut rng).unwrap();
+        let (x, y) = (Fr::from(4u64), Fr::from(6u64));
+        let proof =
+            Groth16::<Bn254>::prove(&pk, MulCircuit { xy: Some((x, y)) }, &mut rng).unwrap();
+        let vk_json = || vk_to_snarkjs::<Bn254>(&vk, 1).unwrap();
+        let proof_json = || proof_to_snarkjs::<Bn254>(&proof, &[x * y]).unwrap();
+        let public = [x * y];
+
+        let result = verify_detailed::<Bn254>(&vk_json(), &proof_json(), &public);
+        assert!(matches!(result, VerifyResult::Valid) && result.is_valid());
+
+        // A wrong public input only fails the pairing equation
+        let result = verify_detailed::<Bn254>(&vk_json(), &proof_json(), &[x]);
+        assert!(matches!(result, VerifyResult::PairingCheckFailed));
+        assert!(!result.is_valid());
+        assert_eq!(result.to_string(), "pairing check failed");
+
+        // Counts are compared before any point is parsed
+        let result = verify_detailed::<Bn254>(&vk_json(), &proof_json(), &[x, y]);
+        assert!(matches!(
+            result,
+            VerifyResult::PublicCountMismatch {
+                vk: 1,
+                proof: Some(1),
+                public: 2
+            }
+        ));
+        assert_eq!(
+            result.to_string(),
+            "public input count: verifying key has 1, proof has 1, 2 given"
+        );
+        let without_signals = proof_to_snarkjs::<Bn254>(&proof, &[]).unwrap();
+        assert!(verify_detailed::<Bn254>(&vk_json(), &without_signals, &public).is_valid());
+
+        // Broken points are named
+        let mut bad = proof_json();
+        bad.pi_a[1] = "1".into();
+        let result = verify_detailed::<Bn254>(&vk_json(), &bad, &public);
+        assert!(matches!(result, VerifyResult::NotOnCurve { field: "pi_a" }));
+        assert_eq!(result.to_string(), "pi_a: point is not on the curve");
+        let mut bad = vk_json();
+        bad.ic[1][0] = "1".into();
+        assert!(matches!(
+            verify_detailed::<Bn254>(&bad, &proof_json(), &public),
+            VerifyResult::NotOnCurve { field: "IC" }
+        ));
+        let mut bad = proof_json();
+        bad.pi_c[0] = "0x1g".into();
+        assert!(matches!(
+            verify_detailed::<Bn254>(&vk_json(), &bad, &public),
+            VerifyResult::Invalid {
+                field: "pi_c",
+                error: SnarkjsError::InvalidFieldElement(_)
+            }
+        ));
+        let mut bad = vk_json();
+        bad.ic.pop();
+        assert!(matches!(
+            verify_detailed::<Bn254>(&bad, &proof_json(), &public),
+            VerifyResult::Invalid {
+                field: "IC",
+                error: SnarkjsError::IcLengthMismatch {
+                    n_public: 1,
+                    ic_len: 1
+                }
+            }
+        ));
+
+        let mut bad = vk_json();
+        bad.curve = CurveId::Bls12381;
+        assert!(matches!(
+            verify_detailed::<Bn254>(&bad, &proof_json(), &public),
+            VerifyResult::CurveMismatch { found } if found == "bls12381"
+        ));
+
+        // verify_from_json keeps its errors
+        let mut bad = proof_json();
+        bad.pi_b[0][0] = "1".into();
+        assert!(matches!(
+            verify_detailed::<Bn254>(&vk_json(), &bad, &public),
+            VerifyResult::NotOnCurve { field: "pi_b" }
+        ));
+        assert!(matches!(
+            verify_from_json::<Bn254>(&vk_json(), &bad),
+            Err(SnarkjsError::NotOnCurve)
+        ));
+    }
+}
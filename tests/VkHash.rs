@@ -0,0 +1,156 @@
+// vk_hash: crate-specific keccak256 over the verifying key fields
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::VerifyingKey;
+use ark_snarkjs::vk_hash::{vk_equal, vk_hash, vk_hash_hex, vk_json_equal};
+use ark_snarkjs::{ExportOptions, SnarkjsError, export_vk_with_options, vk_to_snarkjs};
+use sha3::{Digest, Keccak256};
+
+use ark_bn254::{Bn254, G1Affine, G2Affine};
+
+/// Deterministic verifying key built from multiples of the generators.
+fn sample_vk() -> VerifyingKey<Bn254> {
+    let g1 = G1Affine::generator();
+    let g2 = G2Affine::generator();
+    VerifyingKey {
+        alpha_g1: (g1 * ark_bn254::Fr::from(2u64)).into_affine(),
+        beta_g2: (g2 * ark_bn254::Fr::from(3u64)).into_affine(),
+        gamma_g2: g2,
+        delta_g2: (g2 * ark_bn254::Fr::from(5u64)).into_affine(),
+        gamma_abc_g1: vec![g1, (g1 * ark_bn254::Fr::from(7u64)).into_affine()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vk_hash_layout() {
+        let vk = sample_vk();
+
+        // vk_hash is this crate's own encoding (snarkjs defines none), so pin
+        // the documented byte layout by rebuilding it by hand
+        let mut bytes = vec![0u8; 31];
+        bytes.push(1); // nPublic = 1
+        let g1 = |p: &G1Affine, out: &mut Vec<u8>| {
+            out.extend(p.x.into_bigint().to_bytes_be());
+            out.extend(p.y.into_bigint().to_bytes_be());
+        };
+        let g2 = |p: &G2Affine, out: &mut Vec<u8>| {
+            for c in [p.x.c0, p.x.c1, p.y.c0, p.y.c1] {
+                out.extend(c.into_bigint().to_bytes_be());
+            }
+        };
+        g1(&vk.alpha_g1, &mut bytes);
+        g2(&vk.beta_g2, &mut bytes);
+        g2(&vk.gamma_g2, &mut bytes);
+        g2(&vk.delta_g2, &mut bytes);
+        for p in &vk.gamma_abc_g1 {
+            g1(p, &mut bytes);
+        }
+        let expected: [u8; 32] = Keccak256::digest(&bytes).into();
+
+        assert_eq!(vk_hash::<Bn254>(&vk, 1).unwrap(), expected);
+        assert_ne!(vk_hash::<Bn254>(&vk, 2).unwrap(), expected);
+
+        let hex = vk_hash_hex::<Bn254>(&vk, 1).unwrap();
+        assert_eq!(hex.len(), 66);
+        assert!(hex.starts_with("0x"));
+    }
+
+    #[test]
+    fn test_vk_hash_point_at_infinity() {
+        let mut vk = sample_vk();
+        vk.delta_g2 = G2Affine::zero();
+        assert!(matches!(
+            vk_hash::<Bn254>(&vk, 1),
+            Err(SnarkjsError::PointAtInfinity)
+        ));
+        assert!(matches!(
+            vk_hash_hex::<Bn254>(&vk, 1),
+            Err(SnarkjsError::PointAtInfinity)
+        ));
+
+        let mut vk = sample_vk();
+        vk.alpha_g1 = G1Affine::zero();
+        assert!(matches!(
+            vk_hash::<Bn254>(&vk, 1),
+            Err(SnarkjsError::PointAtInfinity)
+        ));
+
+        // An identity IC entry is allowed, as in the exported JSON
+        let mut vk = sample_vk();
+        vk.gamma_abc_g1[1] = G1Affine::zero();
+        assert!(vk_hash::<Bn254>(&vk, 1).is_ok());
+    }
+
+    #[test]
+    fn test_export_vk_with_hash() {
+        let vk = sample_vk();
+        let json = ark_snarkjs::export_vk::export_vk_with_hash::<Bn254, _>(
+            &vk,
+            1,
+            "target/test-output/vk_hash/verification_key.json",
+        )
+        .unwrap();
+        assert_eq!(json.vk_hash, Some(vk_hash_hex::<Bn254>(&vk, 1).unwrap()));
+
+        // The plain export must not carry the extra field
+        let plain =
+            serde_json::to_value(ark_snarkjs::vk_to_snarkjs::<Bn254>(&vk, 1).unwrap()).unwrap();
+        assert!(plain.get("vkHash").is_none());
+    }
+
+    #[test]
+    fn test_vk_equal() {
+        let vk = sample_vk();
+        assert!(vk_equal(&vk, &sample_vk()));
+
+        let mut other = sample_vk();
+        other.gamma_abc_g1.pop();
+        assert!(!vk_equal(&vk, &other));
+        let mut other = sample_vk();
+        other.delta_g2 = other.gamma_g2;
+        assert!(!vk_equal(&vk, &other));
+    }
+
+    #[test]
+    fn test_vk_json_equal_ignores_formatting_and_derived_fields() {
+        let vk = sample_vk();
+        let plain = vk_to_snarkjs::<Bn254>(&vk, 1).unwrap();
+
+        // Different indent and an extra fieldModulus entry: same key
+        let options = ExportOptions {
+            indent: "\t".into(),
+            field_modulus: true,
+            ..Default::default()
+        };
+        let path = "target/test-output/vk_hash/verification_key.json";
+        let extended = export_vk_with_options::<Bn254, _>(&vk, 1, path, &options).unwrap();
+        assert!(extended.field_modulus.is_some());
+        assert!(vk_json_equal(&plain, &extended));
+
+        let mut other = sample_vk();
+        other.alpha_g1 = G1Affine::generator();
+        assert!(!vk_json_equal(
+            &plain,
+            &vk_to_snarkjs::<Bn254>(&other, 1).unwrap()
+        ));
+        let mut fewer = vk_to_snarkjs::<Bn254>(&vk, 1).unwrap();
+        fewer.n_public = 0;
+        assert!(!vk_json_equal(&plain, &fewer));
+    }
+}
@@ -0,0 +1,89 @@
+// verify_bn254 (the wasm-bindgen entry point) run natively on snarkjs JSON
+// Run with: cargo test --features wasm --test Wasm
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+#![cfg(feature = "wasm")]
+
+use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_groth16::Groth16;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_snarkjs::{PublicSignals, proof_to_snarkjs_value, verify_bn254, vk_to_snarkjs_value};
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+
+use ark_bn254::{Bn254, Fr};
+
+/// x * y = z (z is public).
+struct MulCircuit {
+    xy: Option<(Fr, Fr)>,
+}
+
+impl ConstraintSynthesizer<Fr> for MulCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let x = FpVar::new_witness(cs.clone(), || {
+            self.xy
+                .map(|(x, _)| x)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let y = FpVar::new_witness(cs.clone(), || {
+            self.xy
+                .map(|(_, y)| y)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let z = FpVar::new_input(cs, || {
+            self.xy
+                .map(|(x, y)| x * y)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        (&x * &y).enforce_equal(&z)
+    }
+}
+
+/// The three snarkjs documents (vk, proof, public) for 3 * 11 = 33.
+fn snarkjs_files() -> (String, String, String) {
+    let mut rng = StdRng::seed_from_u64(7);
+    let (pk, vk) = Groth16::<Bn254>::setup(MulCircuit { xy: None }, &mut rng).unwrap();
+    let (x, y) = (Fr::from(3u64), Fr::from(11u64));
+    let proof = Groth16::<Bn254>::prove(&pk, MulCircuit { xy: Some((x, y)) }, &mut rng).unwrap();
+    let public = [x * y];
+
+    (
+        vk_to_snarkjs_value::<Bn254>(&vk, 1).unwrap().to_string(),
+        proof_to_snarkjs_value::<Bn254>(&proof, &public)
+            .unwrap()
+            .to_string(),
+        serde_json::to_string(&PublicSignals::from(&public)).unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_bn254_accepts_valid_proof() {
+        let (vk, proof, public) = snarkjs_files();
+        assert_eq!(public, r#"["33"]"#);
+        assert!(verify_bn254(&vk, &proof, &public));
+        assert!(verify_bn254(&vk, &proof, r#"["0x21"]"#));
+    }
+
+    #[test]
+    fn test_verify_bn254_rejects() {
+        let (vk, proof, _) = snarkjs_files();
+        // wrong public input, wrong count, malformed JSON
+        assert!(!verify_bn254(&vk, &proof, r#"["34"]"#));
+        assert!(!verify_bn254(&vk, &proof, r#"["33", "1"]"#));
+        assert!(!verify_bn254(&vk, "{", r#"["33"]"#));
+        assert!(!verify_bn254("", &proof, r#"["33"]"#));
+    }
+}
@@ -0,0 +1,147 @@
+// Full assignment ([1, public..., private...]) exported like snarkjs' `wtns export json`
+
+#![warn(unused)]
+#![deny(
+    trivial_casts,
+    trivial_numeric_casts,
+    variant_size_differences,
+    stable_features,
+    non_shorthand_field_patterns,
+    renamed_and_removed_lints,
+    unsafe_code
+)]
+
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError,
+};
+use ark_snarkjs::{SnarkjsError, export_witness, import_wtns, wtns_from_bytes};
+
+use ark_bn254::Fr;
+
+/// x * y = z (z is public).
+struct MulCircuit {
+    x: Fr,
+    y: Fr,
+}
+
+impl ConstraintSynthesizer<Fr> for MulCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let x = FpVar::new_witness(cs.clone(), || Ok(self.x))?;
+        let y = FpVar::new_witness(cs.clone(), || Ok(self.y))?;
+        let z = FpVar::new_input(cs, || Ok(self.x * self.y))?;
+        (&x * &y).enforce_equal(&z)
+    }
+}
+
+/// Minimal `.wtns` (version 2) as written by circom's witness generator.
+fn wtns_bytes(witness: &[Fr]) -> Vec<u8> {
+    let prime = Fr::MODULUS.to_bytes_le();
+    let n8 = prime.len();
+
+    let mut header = (n8 as u32).to_le_bytes().to_vec();
+    header.extend_from_slice(&prime);
+    header.extend_from_slice(&(witness.len() as u32).to_le_bytes());
+
+    let mut data = Vec::new();
+    for w in witness {
+        data.extend_from_slice(&w.into_bigint().to_bytes_le());
+    }
+
+    let mut out = b"wtns".to_vec();
+    out.extend_from_slice(&2u32.to_le_bytes());
+    out.extend_from_slice(&2u32.to_le_bytes());
+    for (kind, body) in [(1u32, header), (2u32, data)] {
+        out.extend_from_slice(&kind.to_le_bytes());
+        out.extend_from_slice(&(body.len() as u64).to_le_bytes());
+        out.extend_from_slice(&body);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_witness_from_constraint_system() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        MulCircuit {
+            x: Fr::from(3u64),
+            y: -Fr::from(1u64),
+        }
+        .generate_constraints(cs.clone())
+        .unwrap();
+        cs.finalize();
+        assert!(cs.is_satisfied().unwrap());
+
+        // instance_assignment already starts with the constant one
+        let cs = cs.borrow().unwrap();
+        let assignment = [
+            cs.instance_assignment.as_slice(),
+            cs.witness_assignment.as_slice(),
+        ]
+        .concat();
+
+        let path = "target/test-output/witness/witness.json";
+        let json = export_witness(&assignment, path).unwrap();
+        let minus_one =
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616";
+        let minus_three =
+            "21888242871839275222246405745257275088548364400416034343698204186575808495614";
+        assert_eq!(json[..4], ["1", minus_three, "3", minus_one]);
+
+        let raw: Vec<String> =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(raw, json);
+    }
+
+    #[test]
+    fn test_import_wtns_roundtrip() {
+        let witness = [
+            Fr::from(1u64),
+            -Fr::from(3u64),
+            Fr::from(3u64),
+            -Fr::from(1u64),
+        ];
+        let path = "target/test-output/witness/witness.wtns";
+        std::fs::create_dir_all("target/test-output/witness").unwrap();
+        std::fs::write(path, wtns_bytes(&witness)).unwrap();
+
+        let parsed: Vec<Fr> = import_wtns(path).unwrap();
+        assert_eq!(parsed, witness);
+    }
+
+    #[test]
+    fn test_wtns_rejects_other_field() {
+        let bytes = wtns_bytes(&[Fr::from(1u64)]);
+        let err = wtns_from_bytes::<ark_bls12_381::Fr>(&bytes).unwrap_err();
+        assert!(matches!(err, SnarkjsError::InvalidWtns(_)));
+    }
+
+    #[test]
+    fn test_wtns_rejects_malformed_input() {
+        let bytes = wtns_bytes(&[Fr::from(1u64), Fr::from(2u64)]);
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'x';
+        assert!(matches!(
+            wtns_from_bytes::<Fr>(&bad_magic),
+            Err(SnarkjsError::InvalidWtns(_))
+        ));
+        assert!(matches!(
+            wtns_from_bytes::<Fr>(&bytes[..bytes.len() - 1]),
+            Err(SnarkjsError::InvalidWtns(_))
+        ));
+
+        // Last element replaced by the modulus itself
+        let mut out_of_range = bytes.clone();
+        let n = out_of_range.len();
+        out_of_range[n - 32..].copy_from_slice(&Fr::MODULUS.to_bytes_le());
+        assert!(matches!(
+            wtns_from_bytes::<Fr>(&out_of_range),
+            Err(SnarkjsError::InvalidFieldElement(_))
+        ));
+    }
+}
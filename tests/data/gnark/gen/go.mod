@@ -0,0 +1,8 @@
+module github.com/mysteryon88/ark-snarkjs/tests/data/gnark/gen
+
+go 1.22
+
+require (
+	github.com/consensys/gnark v0.11.0
+	github.com/consensys/gnark-crypto v0.14.0
+)
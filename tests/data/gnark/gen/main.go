@@ -0,0 +1,57 @@
+// Writes tests/data/gnark/{proof,vk}.json with gnark's own encoding/json
+// output for a BN254 Groth16 proof of x * y == z (z public).
+//
+//	cd tests/data/gnark/gen && go mod tidy && go run . ..
+package main
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+
+	"github.com/consensys/gnark-crypto/ecc"
+	"github.com/consensys/gnark/backend/groth16"
+	"github.com/consensys/gnark/frontend"
+	"github.com/consensys/gnark/frontend/cs/r1cs"
+)
+
+type MulCircuit struct {
+	X, Y frontend.Variable
+	Z    frontend.Variable `gnark:",public"`
+}
+
+func (c *MulCircuit) Define(api frontend.API) error {
+	api.AssertIsEqual(api.Mul(c.X, c.Y), c.Z)
+	return nil
+}
+
+func main() {
+	out := os.Args[1]
+
+	ccs, err := frontend.Compile(ecc.BN254.ScalarField(), r1cs.NewBuilder, &MulCircuit{})
+	check(err)
+	pk, vk, err := groth16.Setup(ccs)
+	check(err)
+	w, err := frontend.NewWitness(&MulCircuit{X: 3, Y: 5, Z: 15}, ecc.BN254.ScalarField())
+	check(err)
+	proof, err := groth16.Prove(ccs, pk, w)
+	check(err)
+	public, err := w.Public()
+	check(err)
+	check(groth16.Verify(proof, vk, public))
+
+	write(filepath.Join(out, "proof.json"), proof)
+	write(filepath.Join(out, "vk.json"), vk)
+}
+
+func write(path string, v any) {
+	b, err := json.Marshal(v)
+	check(err)
+	check(os.WriteFile(path, b, 0o644))
+}
+
+func check(err error) {
+	if err != nil {
+		panic(err)
+	}
+}